@@ -2,8 +2,12 @@
 The [`Clock`] type.
 
 A clock is a service that returns a [`Timestamp`] representing the current point in time. Clock readings are not guaranteed to be monotonic. They may move forwards or backwards arbitrarily, but for diagnostics to be useful, a clock should strive for accuracy.
+
+A clock may also support monotonic readings through [`Clock::monotonic`]. These can't be converted into a point in time, but can be compared to measure the time between them, even if the clock's [`Timestamp`]s are adjusted in the meantime.
 */
 
+use core::time::Duration;
+
 use crate::{empty::Empty, timestamp::Timestamp};
 
 /**
@@ -16,12 +20,27 @@ pub trait Clock {
     This method may return `None` if the clock couldn't be read for any reason. That may involve the clock not actually supporting reading now, time moving backwards, or any other reason that could result in an inaccurate reading.
     */
     fn now(&self) -> Option<Timestamp>;
+
+    /**
+    Read the current monotonic time, as the time elapsed since some fixed point.
+
+    Monotonic readings never move backwards, and aren't affected by adjustments to the time returned by [`Clock::now`]. The fixed point they're measured from is arbitrary, so readings are only meaningful when compared to other readings from the same clock.
+
+    This method may return `None` if the clock doesn't support monotonic readings. This is the default.
+    */
+    fn monotonic(&self) -> Option<Duration> {
+        None
+    }
 }
 
 impl<'a, T: Clock + ?Sized> Clock for &'a T {
     fn now(&self) -> Option<Timestamp> {
         (**self).now()
     }
+
+    fn monotonic(&self) -> Option<Duration> {
+        (**self).monotonic()
+    }
 }
 
 impl<'a, T: Clock> Clock for Option<T> {
@@ -32,6 +51,14 @@ impl<'a, T: Clock> Clock for Option<T> {
             Empty.now()
         }
     }
+
+    fn monotonic(&self) -> Option<Duration> {
+        if let Some(time) = self {
+            time.monotonic()
+        } else {
+            Empty.monotonic()
+        }
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -39,6 +66,10 @@ impl<'a, T: Clock + ?Sized + 'a> Clock for alloc::boxed::Box<T> {
     fn now(&self) -> Option<Timestamp> {
         (**self).now()
     }
+
+    fn monotonic(&self) -> Option<Duration> {
+        (**self).monotonic()
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -46,6 +77,10 @@ impl<'a, T: Clock + ?Sized + 'a> Clock for alloc::sync::Arc<T> {
     fn now(&self) -> Option<Timestamp> {
         (**self).now()
     }
+
+    fn monotonic(&self) -> Option<Duration> {
+        (**self).monotonic()
+    }
 }
 
 impl Clock for Empty {
@@ -55,10 +90,14 @@ impl Clock for Empty {
 }
 
 mod internal {
+    use core::time::Duration;
+
     use super::Timestamp;
 
     pub trait DispatchClock {
         fn dispatch_now(&self) -> Option<Timestamp>;
+
+        fn dispatch_monotonic(&self) -> Option<Duration>;
     }
 
     pub trait SealedClock {
@@ -85,16 +124,28 @@ impl<T: Clock> internal::DispatchClock for T {
     fn dispatch_now(&self) -> Option<Timestamp> {
         self.now()
     }
+
+    fn dispatch_monotonic(&self) -> Option<Duration> {
+        self.monotonic()
+    }
 }
 
 impl<'a> Clock for dyn ErasedClock + 'a {
     fn now(&self) -> Option<Timestamp> {
         self.erase_clock().0.dispatch_now()
     }
+
+    fn monotonic(&self) -> Option<Duration> {
+        self.erase_clock().0.dispatch_monotonic()
+    }
 }
 
 impl<'a> Clock for dyn ErasedClock + Send + Sync + 'a {
     fn now(&self) -> Option<Timestamp> {
         (self as &(dyn ErasedClock + 'a)).now()
     }
+
+    fn monotonic(&self) -> Option<Duration> {
+        (self as &(dyn ErasedClock + 'a)).monotonic()
+    }
 }
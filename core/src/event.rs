@@ -253,3 +253,167 @@ impl<'a, P: Props> ToEvent for Event<'a, P> {
         self.by_ref()
     }
 }
+
+#[cfg(feature = "alloc")]
+mod alloc_support {
+    use super::*;
+
+    use crate::{
+        props::OwnedProps,
+        str::{Str, ToStr},
+        value::Value,
+    };
+
+    /**
+    An [`Event`] that owns all of its data.
+
+    Owned events can be created from any other [`Event`] through [`Event::to_owned`]. Because they don't borrow from their surrounding environment, owned events can be buffered or sent across threads to be processed later.
+    */
+    #[derive(Clone)]
+    pub struct OwnedEvent {
+        module: Path<'static>,
+        extent: Option<Extent>,
+        tpl: Template<'static>,
+        props: OwnedProps,
+    }
+
+    impl<'a, P: Props> Event<'a, P> {
+        /**
+        Get an owned event from this one.
+
+        The properties of the event are de-duplicated and collected through [`OwnedProps::collect_shared`], so the resulting event is cheap to clone.
+        */
+        pub fn to_owned(&self) -> OwnedEvent {
+            OwnedEvent {
+                module: self.module.to_owned(),
+                extent: self.extent.clone(),
+                tpl: self.tpl.to_owned(),
+                props: OwnedProps::collect_shared(&self.props),
+            }
+        }
+    }
+
+    impl OwnedEvent {
+        /**
+        Get a reference to the module that produced the event.
+        */
+        pub fn module(&self) -> &Path<'static> {
+            &self.module
+        }
+
+        /**
+        Get a reference to the extent of the event, if there is one.
+        */
+        pub fn extent(&self) -> Option<&Extent> {
+            self.extent.as_ref()
+        }
+
+        /**
+        Get a reference to the template of the event.
+        */
+        pub fn tpl(&self) -> &Template<'static> {
+            &self.tpl
+        }
+
+        /**
+        Get a reference to the properties of the event.
+        */
+        pub fn props(&self) -> &OwnedProps {
+            &self.props
+        }
+
+        /**
+        Get a lazily-evaluated formatting of the event's template.
+        */
+        pub fn msg(&self) -> Render<'_, &OwnedProps> {
+            self.tpl.render(&self.props)
+        }
+
+        /**
+        Get a new event, borrowing data from this one.
+        */
+        pub fn by_ref<'b>(&'b self) -> Event<'b, &'b OwnedProps> {
+            Event {
+                module: self.module.by_ref(),
+                extent: self.extent.clone(),
+                tpl: self.tpl.by_ref(),
+                props: &self.props,
+            }
+        }
+    }
+
+    impl ToEvent for OwnedEvent {
+        type Props<'a> = &'a OwnedProps;
+
+        fn to_event<'a>(&'a self) -> Event<'a, Self::Props<'a>> {
+            self.by_ref()
+        }
+    }
+
+    impl Props for OwnedEvent {
+        fn for_each<'kv, F: FnMut(Str<'kv>, Value<'kv>) -> ControlFlow<()>>(
+            &'kv self,
+            for_each: F,
+        ) -> ControlFlow<()> {
+            self.props.for_each(for_each)
+        }
+
+        fn get<'v, K: ToStr>(&'v self, key: K) -> Option<Value<'v>> {
+            self.props.get(key)
+        }
+
+        fn is_unique(&self) -> bool {
+            true
+        }
+    }
+
+    impl<'a, P: Props> From<Event<'a, P>> for OwnedEvent {
+        fn from(evt: Event<'a, P>) -> Self {
+            evt.to_owned()
+        }
+    }
+
+    impl fmt::Debug for OwnedEvent {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(&self.by_ref(), f)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use alloc_support::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_owned() {
+        use crate::template::Part;
+
+        let owned = {
+            let module = alloc::string::String::from("a::b");
+            let parts = [Part::text("Hello, "), Part::hole("user")];
+            let user = alloc::string::String::from("world");
+
+            let evt = Event::new(
+                Path::new_ref(&module),
+                Timestamp::from_unix(core::time::Duration::from_secs(1)),
+                Template::new_ref(&parts),
+                [("user", &*user), ("user", "duplicate")],
+            );
+
+            evt.to_owned()
+        };
+
+        assert_eq!(Path::new("a::b"), *owned.module());
+        assert_eq!(
+            Timestamp::from_unix(core::time::Duration::from_secs(1)),
+            owned.extent().map(|extent| *extent.as_point())
+        );
+        assert_eq!("Hello, world", owned.msg().to_string());
+        assert_eq!(1, owned.props().len());
+        assert_eq!("world", owned.get("user").unwrap().to_string());
+    }
+}
@@ -4,7 +4,10 @@ The [`Filter`] type.
 Filters reduce the burden of diagnostics by limiting the volume of data generated. A typical filter will only match events with a certain level or higher, or it may exclude all events for a particularly noisy module.
 */
 
-use core::time::Duration;
+use core::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
 
 use crate::{
     and::And,
@@ -12,7 +15,9 @@ use crate::{
     empty::Empty,
     event::{Event, ToEvent},
     or::Or,
-    props::ErasedProps,
+    props::{ErasedProps, Props},
+    rng::Rng,
+    well_known::{KEY_ERR, KEY_SAMPLE_RATE},
 };
 
 /**
@@ -196,6 +201,685 @@ pub fn always() -> Empty {
     Empty
 }
 
+/**
+Create a [`Filter`] that keeps a random proportion of events.
+
+The `ratio` is the probability of keeping any given event, between `0.0` (keep none) and `1.0` (keep all). The decision is made using values from `rng`, which will typically be the same [`Rng`] given to the runtime. If `rng` doesn't produce a value then the event is kept.
+
+See [`SampleFilter`] for details.
+*/
+pub fn sample_ratio<R: Rng>(ratio: f64, rng: R) -> SampleFilter<R> {
+    SampleFilter::new(SampleStrategy::Ratio(ratio), rng)
+}
+
+/**
+Create a [`Filter`] that keeps 1 in every `n` events.
+
+If `n` is `0` then every event is kept.
+
+See [`SampleFilter`] for details.
+*/
+pub fn sample_every(n: usize) -> SampleFilter {
+    SampleFilter::new(SampleStrategy::Every(n.max(1)), Empty)
+}
+
+/**
+A [`Filter`] that keeps a sample of events.
+
+Sampling can be skipped for important events through [`SampleFilter::always_keep_err`] and [`SampleFilter::always_keep_when`].
+
+Filters can't change the events they match, so a sample filter installed in a runtime won't tell consumers that events were sampled. Use [`SampleFilter::wrap_sampled`] to wrap an [`Emitter`] instead. Events that are kept by sampling will then carry the [`KEY_SAMPLE_RATE`] well-known property, which is the number of events each one represents, so consumers can re-weight counts.
+
+Use [`sample_ratio`] or [`sample_every`] to create a sample filter.
+*/
+pub struct SampleFilter<R = Empty, K = Empty> {
+    strategy: SampleStrategy,
+    rng: R,
+    counter: AtomicUsize,
+    keep_err: bool,
+    keep_when: Option<K>,
+}
+
+#[derive(Clone, Copy)]
+enum SampleStrategy {
+    Ratio(f64),
+    Every(usize),
+}
+
+enum Sample {
+    Keep,
+    Sampled,
+    Drop,
+}
+
+impl<R> SampleFilter<R> {
+    fn new(strategy: SampleStrategy, rng: R) -> Self {
+        SampleFilter {
+            strategy,
+            rng,
+            counter: AtomicUsize::new(0),
+            keep_err: false,
+            keep_when: None,
+        }
+    }
+}
+
+impl<R, K> SampleFilter<R, K> {
+    /**
+    Always keep events that carry the [`KEY_ERR`] well-known property, regardless of sampling.
+    */
+    pub fn always_keep_err(mut self) -> Self {
+        self.keep_err = true;
+        self
+    }
+
+    /**
+    Always keep events that match the given [`Filter`], regardless of sampling.
+
+    This can be used to keep all events above a certain level, like warnings and errors.
+    */
+    pub fn always_keep_when<U: Filter>(self, filter: U) -> SampleFilter<R, U> {
+        SampleFilter {
+            strategy: self.strategy,
+            rng: self.rng,
+            counter: self.counter,
+            keep_err: self.keep_err,
+            keep_when: Some(filter),
+        }
+    }
+
+    /**
+    Wrap an [`Emitter`], only emitting events that are kept by the filter.
+
+    Events that were kept by sampling will carry the [`KEY_SAMPLE_RATE`] well-known property.
+    */
+    pub fn wrap_sampled<E>(self, emitter: E) -> SampledEmitter<R, K, E> {
+        SampledEmitter {
+            filter: self,
+            emitter,
+        }
+    }
+
+    /**
+    The number of events each one kept by sampling represents.
+    */
+    pub fn sample_rate(&self) -> f64 {
+        match self.strategy {
+            SampleStrategy::Ratio(ratio) => 1.0 / ratio.clamp(0.0, 1.0),
+            SampleStrategy::Every(n) => n as f64,
+        }
+    }
+}
+
+impl<R: Rng, K: Filter> SampleFilter<R, K> {
+    fn sample(&self, evt: &Event<impl Props>) -> Sample {
+        if self.keep_err && evt.props().get(KEY_ERR).is_some() {
+            return Sample::Keep;
+        }
+
+        if let Some(ref keep_when) = self.keep_when {
+            if keep_when.matches(evt) {
+                return Sample::Keep;
+            }
+        }
+
+        let sampled = match self.strategy {
+            SampleStrategy::Ratio(ratio) => {
+                if ratio >= 1.0 {
+                    true
+                } else if ratio > 0.0 {
+                    self.rng
+                        .gen_u64()
+                        .map(|r| r <= (ratio * u64::MAX as f64) as u64)
+                        .unwrap_or(true)
+                } else {
+                    false
+                }
+            }
+            SampleStrategy::Every(n) => {
+                let i = self
+                    .counter
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |i| {
+                        Some(if i + 1 >= n { 0 } else { i + 1 })
+                    })
+                    .unwrap_or(0);
+
+                i == 0
+            }
+        };
+
+        if sampled {
+            Sample::Sampled
+        } else {
+            Sample::Drop
+        }
+    }
+}
+
+impl<R: Rng, K: Filter> Filter for SampleFilter<R, K> {
+    fn matches<E: ToEvent>(&self, evt: E) -> bool {
+        !matches!(self.sample(&evt.to_event()), Sample::Drop)
+    }
+}
+
+/**
+An [`Emitter`] that only emits a sample of events.
+
+Events that were kept by sampling carry the [`KEY_SAMPLE_RATE`] well-known property.
+
+Use [`SampleFilter::wrap_sampled`] to create a sampled emitter.
+*/
+pub struct SampledEmitter<R, K, E> {
+    filter: SampleFilter<R, K>,
+    emitter: E,
+}
+
+impl<R: Rng, K: Filter, E: Emitter> Emitter for SampledEmitter<R, K, E> {
+    fn emit<T: ToEvent>(&self, evt: T) {
+        let evt = evt.to_event();
+
+        match self.filter.sample(&evt) {
+            Sample::Keep => self.emitter.emit(evt),
+            Sample::Sampled => {
+                let sample_rate = self.filter.sample_rate();
+
+                self.emitter
+                    .emit(evt.map_props(|props| (KEY_SAMPLE_RATE, sample_rate).and_props(props)))
+            }
+            Sample::Drop => (),
+        }
+    }
+
+    fn blocking_flush(&self, timeout: Duration) -> bool {
+        self.emitter.blocking_flush(timeout)
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_support {
+    use super::*;
+
+    use alloc::{
+        boxed::Box,
+        string::{String, ToString},
+        sync::Arc,
+        vec::Vec,
+    };
+    use core::{
+        hash::{Hash, Hasher},
+        marker::PhantomData,
+        ptr,
+        sync::atomic::{AtomicBool, AtomicPtr, AtomicU64, AtomicUsize, Ordering},
+    };
+    use std::{
+        collections::{hash_map::DefaultHasher, HashMap},
+        sync::Mutex,
+    };
+
+    use crate::{
+        path::Path,
+        template::{Part, Template},
+        timestamp::Timestamp,
+        value::{ToValue, Value},
+        well_known::KEY_LVL,
+    };
+
+    /**
+    Create a [`Filter`] that can be swapped at runtime.
+
+    The returned [`ReloadableFilter`] should be installed in the runtime, and the [`ReloadableFilterHandle`] kept to later change the filter through [`ReloadableFilterHandle::set`].
+    */
+    pub fn reloadable<F: Filter>(filter: F) -> (ReloadableFilter<F>, ReloadableFilterHandle<F>) {
+        let shared = Arc::new(Shared {
+            current: AtomicPtr::new(Box::into_raw(Box::new(filter))),
+            readers: AtomicUsize::new(0),
+            has_retired: AtomicBool::new(false),
+            retired: Mutex::new(Vec::new()),
+            _marker: PhantomData,
+        });
+
+        (
+            ReloadableFilter {
+                shared: shared.clone(),
+            },
+            ReloadableFilterHandle { shared },
+        )
+    }
+
+    /**
+    A [`Filter`] that can be swapped at runtime through a [`ReloadableFilterHandle`].
+
+    Evaluating the filter doesn't acquire any locks. To keep it that way, filters that have been replaced can't be dropped while other threads may still be evaluating them. The filter counts the number of evaluations in progress, and replaced filters are dropped once there are none. That happens when the filter is next replaced, or when the last in-progress evaluation finishes, so replaced filters don't accumulate even if the filter is reloaded often.
+
+    Use [`reloadable`] to create a reloadable filter.
+    */
+    pub struct ReloadableFilter<F> {
+        shared: Arc<Shared<F>>,
+    }
+
+    /**
+    A handle to a [`ReloadableFilter`] that can swap its underlying [`Filter`].
+
+    Handles can be cloned and shared across threads.
+    */
+    pub struct ReloadableFilterHandle<F> {
+        shared: Arc<Shared<F>>,
+    }
+
+    struct Shared<F> {
+        current: AtomicPtr<F>,
+        // The number of calls to `matches` in progress
+        readers: AtomicUsize,
+        has_retired: AtomicBool,
+        retired: Mutex<Vec<Box<F>>>,
+        // `Shared` hands out `&F` across threads
+        _marker: PhantomData<F>,
+    }
+
+    impl<F> ReloadableFilter<F> {
+        /**
+        Get a new handle to this filter.
+        */
+        pub fn handle(&self) -> ReloadableFilterHandle<F> {
+            ReloadableFilterHandle {
+                shared: self.shared.clone(),
+            }
+        }
+    }
+
+    impl<F> ReloadableFilterHandle<F> {
+        /**
+        Replace the filter.
+
+        Events evaluated after this method returns will be matched against `filter`.
+        */
+        pub fn set(&self, filter: F) {
+            let incoming = Box::into_raw(Box::new(filter));
+            let retired = self.shared.current.swap(incoming, Ordering::SeqCst);
+
+            let mut retired_filters = self
+                .shared
+                .retired
+                .lock()
+                .unwrap_or_else(|err| err.into_inner());
+
+            // SAFETY: `retired` was created through `Box::into_raw`
+            // It's kept alive so concurrent callers of `matches` can still use it
+            retired_filters.push(unsafe { Box::from_raw(retired) });
+            self.shared.has_retired.store(true, Ordering::SeqCst);
+
+            self.shared.reclaim(&mut retired_filters);
+        }
+    }
+
+    impl<F> Shared<F> {
+        fn reclaim(&self, retired: &mut Vec<Box<F>>) {
+            // Retired filters were swapped out before they were pushed, so
+            // any call to `matches` that starts after this point can't see them.
+            // If there are no calls in progress then nothing can be using them
+            if self.readers.load(Ordering::SeqCst) == 0 {
+                retired.clear();
+                self.has_retired.store(false, Ordering::SeqCst);
+            }
+        }
+    }
+
+    struct ReaderGuard<'a, F>(&'a Shared<F>);
+
+    impl<'a, F> ReaderGuard<'a, F> {
+        fn enter(shared: &'a Shared<F>) -> Self {
+            shared.readers.fetch_add(1, Ordering::SeqCst);
+
+            ReaderGuard(shared)
+        }
+    }
+
+    impl<'a, F> Drop for ReaderGuard<'a, F> {
+        fn drop(&mut self) {
+            let last = self.0.readers.fetch_sub(1, Ordering::SeqCst) == 1;
+
+            // The last reader out reclaims any retired filters
+            // This doesn't wait on the lock, so `matches` never blocks
+            if last && self.0.has_retired.load(Ordering::SeqCst) {
+                if let Ok(mut retired) = self.0.retired.try_lock() {
+                    self.0.reclaim(&mut retired);
+                }
+            }
+        }
+    }
+
+    impl<F> Clone for ReloadableFilterHandle<F> {
+        fn clone(&self) -> Self {
+            ReloadableFilterHandle {
+                shared: self.shared.clone(),
+            }
+        }
+    }
+
+    impl<F: Filter> Filter for ReloadableFilter<F> {
+        fn matches<E: ToEvent>(&self, evt: E) -> bool {
+            let _guard = ReaderGuard::enter(&self.shared);
+
+            // SAFETY: Filters aren't dropped while there are readers that could see them
+            let filter = unsafe { &*self.shared.current.load(Ordering::SeqCst) };
+
+            filter.matches(evt)
+        }
+    }
+
+    impl<F> Drop for Shared<F> {
+        fn drop(&mut self) {
+            let current = self.current.swap(ptr::null_mut(), Ordering::AcqRel);
+
+            // SAFETY: `current` was created through `Box::into_raw`
+            // There are no other references to `self`
+            drop(unsafe { Box::from_raw(current) });
+        }
+    }
+
+    /**
+    Create a [`Filter`] that limits the rate of events sharing the same template.
+
+    At most `max` events with the same template will match over any window of `per`. See [`RateLimitFilter`] for details.
+    */
+    pub fn rate_limit_by_tpl(max: u32, per: Duration) -> RateLimitFilter {
+        RateLimitFilter::new(RateLimitKey::Tpl, max, per)
+    }
+
+    /**
+    Create a [`Filter`] that limits the rate of events sharing the same module.
+
+    At most `max` events from the same module will match over any window of `per`. See [`RateLimitFilter`] for details.
+    */
+    pub fn rate_limit_by_module(max: u32, per: Duration) -> RateLimitFilter {
+        RateLimitFilter::new(RateLimitKey::Module, max, per)
+    }
+
+    /**
+    A [`Filter`] that limits the rate of matching events using a token bucket.
+
+    Each distinct key, either the template or the module of an event, gets its own bucket holding up to `max` tokens. A matching event consumes a token, and tokens are refilled evenly over `per`. Once the bucket is empty, events with that key are suppressed until it refills. Buckets are refilled based on the timestamp of the events themselves, so events without a timestamp are always matched. Keys are identified by a hash of the template parts or module, so the template of an event is only rendered the first time its bucket is created. The full key isn't compared, so in the unlikely event that two keys have the same hash they'll share a bucket.
+
+    The filter can be given an [`Emitter`] through [`RateLimitFilter::summarize_to`]. When events have been suppressed, a summary event carrying the number of suppressed events is emitted to it at most once per `per` for each key. Summaries are checked whenever an event with the same key arrives, and for all keys at most once per `per` whenever any event arrives. Buckets that have fully refilled and have no pending summary are evicted at the same time, so the filter only holds state for recently active keys. Any summaries still pending when the application exits can be emitted through [`RateLimitFilter::blocking_flush`]. The summary is emitted outside of any locks held by the filter, so it's fine to send it back through the same runtime the filter is installed in.
+
+    Rate limiting can be combined with other filters through [`Filter::and_when`]. Put the rate limiter last so only events that pass the other filters consume tokens.
+
+    Use [`rate_limit_by_tpl`] or [`rate_limit_by_module`] to create a rate limiting filter.
+    */
+    pub struct RateLimitFilter<E = Empty> {
+        key: RateLimitKey,
+        max: u32,
+        per: Duration,
+        summary: E,
+        next_sweep: AtomicU64,
+        shards: [Mutex<HashMap<u64, Bucket>>; RATE_LIMIT_SHARDS],
+    }
+
+    // Buckets are spread across shards so unrelated keys don't contend on the same lock
+    const RATE_LIMIT_SHARDS: usize = 16;
+
+    #[derive(Clone, Copy)]
+    enum RateLimitKey {
+        Tpl,
+        Module,
+    }
+
+    struct Bucket {
+        key: String,
+        module: Path<'static>,
+        tokens: f64,
+        last_refill: Timestamp,
+        last_summary: Timestamp,
+        suppressed: u64,
+    }
+
+    struct Summary {
+        key: String,
+        module: Path<'static>,
+        ts: Timestamp,
+        suppressed: u64,
+    }
+
+    impl Bucket {
+        fn take_summary(&mut self, ts: Timestamp) -> Summary {
+            self.last_summary = ts;
+
+            Summary {
+                key: self.key.clone(),
+                module: self.module.clone(),
+                ts,
+                suppressed: core::mem::take(&mut self.suppressed),
+            }
+        }
+    }
+
+    impl RateLimitFilter {
+        fn new(key: RateLimitKey, max: u32, per: Duration) -> Self {
+            RateLimitFilter {
+                key,
+                max,
+                per,
+                summary: Empty,
+                next_sweep: AtomicU64::new(0),
+                shards: core::array::from_fn(|_| Mutex::new(HashMap::new())),
+            }
+        }
+    }
+
+    impl<E> RateLimitFilter<E> {
+        /**
+        Emit a summary of suppressed events to the given [`Emitter`].
+
+        The summary event has the same module as the events that were suppressed, a `lvl` of `warn`, and the following properties:
+
+        - `rate_limit_key`: The template or module used to limit the rate of events.
+        - `rate_limit_suppressed`: The number of events suppressed since the last summary.
+        */
+        pub fn summarize_to<U: Emitter>(self, emitter: U) -> RateLimitFilter<U> {
+            RateLimitFilter {
+                key: self.key,
+                max: self.max,
+                per: self.per,
+                summary: emitter,
+                next_sweep: self.next_sweep,
+                shards: self.shards,
+            }
+        }
+
+        fn hash_key(&self, evt: &Event<impl crate::props::Props>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+
+            match self.key {
+                RateLimitKey::Tpl => evt.tpl().hash_parts(&mut hasher),
+                RateLimitKey::Module => evt.module().hash(&mut hasher),
+            }
+
+            hasher.finish()
+        }
+
+        fn render_key(&self, evt: &Event<impl crate::props::Props>) -> String {
+            match self.key {
+                RateLimitKey::Tpl => evt.tpl().render(Empty).braced().to_string(),
+                RateLimitKey::Module => evt.module().to_string(),
+            }
+        }
+
+        fn check(&self, evt: &Event<impl crate::props::Props>) -> (bool, Option<Summary>) {
+            let Some(ts) = evt.ts().copied() else {
+                return (true, None);
+            };
+
+            let max = self.max as f64;
+
+            let hash = self.hash_key(evt);
+
+            let mut buckets = self.shards[hash as usize % RATE_LIMIT_SHARDS]
+                .lock()
+                .unwrap_or_else(|err| err.into_inner());
+
+            let bucket = buckets.entry(hash).or_insert_with(|| Bucket {
+                key: self.render_key(evt),
+                module: evt.module().to_owned(),
+                tokens: max,
+                last_refill: ts,
+                last_summary: ts,
+                suppressed: 0,
+            });
+
+            if let Some(elapsed) = ts.duration_since(bucket.last_refill) {
+                let refill = if self.per.is_zero() {
+                    max
+                } else {
+                    elapsed.as_secs_f64() / self.per.as_secs_f64() * max
+                };
+
+                bucket.tokens = (bucket.tokens + refill).min(max);
+                bucket.last_refill = ts;
+            }
+
+            let matched = if bucket.tokens >= 1.0 {
+                bucket.tokens -= 1.0;
+                true
+            } else {
+                bucket.suppressed += 1;
+                false
+            };
+
+            let summary = if bucket.suppressed > 0 && self.is_due(ts, bucket.last_summary) {
+                Some(bucket.take_summary(ts))
+            } else {
+                None
+            };
+
+            (matched, summary)
+        }
+
+        fn is_due(&self, ts: Timestamp, since: Timestamp) -> bool {
+            ts.duration_since(since)
+                .map(|elapsed| elapsed >= self.per)
+                .unwrap_or(false)
+        }
+
+        fn sweep(&self, ts: Timestamp) -> Vec<Summary> {
+            let now = ts.to_unix().as_nanos() as u64;
+            let next_sweep = self.next_sweep.load(Ordering::Relaxed);
+
+            // Only one caller sweeps the buckets in each window
+            if now < next_sweep
+                || self
+                    .next_sweep
+                    .compare_exchange(
+                        next_sweep,
+                        now.saturating_add(self.per.max(Duration::from_millis(1)).as_nanos() as u64),
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_err()
+            {
+                return Vec::new();
+            }
+
+            let mut summaries = Vec::new();
+
+            for shard in &self.shards {
+                let mut buckets = shard.lock().unwrap_or_else(|err| err.into_inner());
+
+                buckets.retain(|_, bucket| {
+                    if bucket.suppressed > 0 && self.is_due(ts, bucket.last_summary) {
+                        summaries.push(bucket.take_summary(ts));
+                    }
+
+                    // A bucket that has fully refilled is the same as a new one
+                    bucket.suppressed > 0 || !self.is_due(ts, bucket.last_refill)
+                });
+            }
+
+            summaries
+        }
+
+        #[cfg(test)]
+        pub(super) fn buckets(&self) -> usize {
+            self.shards
+                .iter()
+                .map(|shard| shard.lock().unwrap_or_else(|err| err.into_inner()).len())
+                .sum()
+        }
+    }
+
+    impl<E: Emitter> RateLimitFilter<E> {
+        /**
+        Emit summaries for any suppressed events that haven't been reported yet, and then flush the summary emitter.
+
+        Summaries are otherwise only emitted as new events arrive, so call this method before flushing the runtime at the end of `main` to report the final counts. Install the filter in an [`Arc`] to keep a reference to it for flushing.
+
+        This method forwards the `timeout` to [`Emitter::blocking_flush`].
+        */
+        pub fn blocking_flush(&self, timeout: Duration) -> bool {
+            let mut summaries = Vec::new();
+
+            for shard in &self.shards {
+                let mut buckets = shard.lock().unwrap_or_else(|err| err.into_inner());
+
+                for bucket in buckets.values_mut() {
+                    if bucket.suppressed > 0 {
+                        summaries.push(bucket.take_summary(bucket.last_refill));
+                    }
+                }
+            }
+
+            for summary in summaries {
+                self.emit_summary(summary);
+            }
+
+            self.summary.blocking_flush(timeout)
+        }
+
+        fn emit_summary(&self, summary: Summary) {
+            // "suppressed {rate_limit_suppressed} events for {rate_limit_key}"
+            const TEMPLATE: &[Part<'static>] = &[
+                Part::text("suppressed "),
+                Part::hole("rate_limit_suppressed"),
+                Part::text(" events for "),
+                Part::hole("rate_limit_key"),
+            ];
+
+            self.summary.emit(Event::new(
+                summary.module,
+                summary.ts,
+                Template::new(TEMPLATE),
+                [
+                    (KEY_LVL, "warn".to_value()),
+                    ("rate_limit_key", Value::from(&*summary.key)),
+                    ("rate_limit_suppressed", summary.suppressed.to_value()),
+                ],
+            ));
+        }
+    }
+
+    impl<E: Emitter> Filter for RateLimitFilter<E> {
+        fn matches<T: ToEvent>(&self, evt: T) -> bool {
+            let evt = evt.to_event();
+
+            let (matched, summary) = self.check(&evt);
+
+            if let Some(summary) = summary {
+                self.emit_summary(summary);
+            }
+
+            if let Some(ts) = evt.ts() {
+                for summary in self.sweep(*ts) {
+                    self.emit_summary(summary);
+                }
+            }
+
+            matched
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use std_support::*;
+
 mod internal {
     use crate::{event::Event, props::ErasedProps};
 
@@ -242,3 +926,248 @@ impl<'a> Filter for dyn ErasedFilter + Send + Sync + 'a {
         (self as &(dyn ErasedFilter + 'a)).matches(evt)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn reloadable() {
+        use crate::{path::Path, template::Template};
+
+        let evt = Event::new(Path::new("a"), Empty, Template::literal("test"), Empty);
+
+        let yes: fn(&Event<&dyn ErasedProps>) -> bool = |_| true;
+        let no: fn(&Event<&dyn ErasedProps>) -> bool = |_| false;
+
+        let (filter, handle) = super::reloadable(yes);
+        let handle = handle.clone();
+
+        assert!(filter.matches(&evt));
+
+        handle.set(no);
+
+        assert!(!filter.matches(&evt));
+
+        drop(handle);
+        filter.handle().set(yes);
+
+        assert!(filter.matches(&evt));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn reloadable_reclaims_retired() {
+        use crate::{path::Path, template::Template};
+        use alloc::sync::Arc;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        struct Counted(Arc<AtomicUsize>);
+
+        impl Filter for Counted {
+            fn matches<E: ToEvent>(&self, _: E) -> bool {
+                true
+            }
+        }
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let evt = Event::new(Path::new("a"), Empty, Template::literal("test"), Empty);
+
+        let dropped = Arc::new(AtomicUsize::new(0));
+
+        let (filter, handle) = super::reloadable(Counted(dropped.clone()));
+
+        for i in 0..10 {
+            assert!(filter.matches(&evt));
+
+            handle.set(Counted(dropped.clone()));
+
+            // Without any evaluations in progress, replaced filters are dropped straight away
+            assert_eq!(i + 1, dropped.load(Ordering::Relaxed));
+        }
+
+        drop((filter, handle));
+
+        assert_eq!(11, dropped.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn sample_every() {
+        use crate::{path::Path, template::Template};
+
+        let evt = Event::new(Path::new("a"), Empty, Template::literal("test"), Empty);
+        let err = Event::new(
+            Path::new("a"),
+            Empty,
+            Template::literal("test"),
+            (KEY_ERR, true),
+        );
+
+        let filter = super::sample_every(3).always_keep_err();
+
+        assert!(filter.matches(&evt));
+        assert!(!filter.matches(&evt));
+        assert!(filter.matches(&err));
+        assert!(!filter.matches(&evt));
+        assert!(filter.matches(&evt));
+
+        assert_eq!(3.0, filter.sample_rate());
+    }
+
+    #[test]
+    fn sample_ratio() {
+        use crate::{path::Path, template::Template};
+
+        struct ConstRng(u64);
+
+        impl Rng for ConstRng {
+            fn fill<A: AsMut<[u8]>>(&self, _: A) -> Option<A> {
+                None
+            }
+
+            fn gen_u64(&self) -> Option<u64> {
+                Some(self.0)
+            }
+        }
+
+        let evt = Event::new(Path::new("a"), Empty, Template::literal("test"), Empty);
+
+        assert!(super::sample_ratio(0.5, ConstRng(u64::MAX / 4)).matches(&evt));
+        assert!(!super::sample_ratio(0.5, ConstRng(u64::MAX / 4 * 3)).matches(&evt));
+        assert!(!super::sample_ratio(0.0, ConstRng(0)).matches(&evt));
+        assert!(super::sample_ratio(1.0, ConstRng(u64::MAX)).matches(&evt));
+
+        assert!(super::sample_ratio(0.0, ConstRng(0))
+            .always_keep_when(super::always())
+            .matches(&evt));
+
+        assert_eq!(4.0, super::sample_ratio(0.25, Empty).sample_rate());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rate_limit() {
+        use crate::{path::Path, template::Template, timestamp::Timestamp};
+        use std::sync::Mutex;
+
+        struct Summaries(Mutex<Vec<String>>);
+
+        impl Emitter for &Summaries {
+            fn emit<E: ToEvent>(&self, evt: E) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(evt.to_event().msg().to_string());
+            }
+
+            fn blocking_flush(&self, _: Duration) -> bool {
+                true
+            }
+        }
+
+        let summaries = Summaries(Mutex::new(Vec::new()));
+
+        let filter = rate_limit_by_tpl(2, Duration::from_secs(1)).summarize_to(&summaries);
+
+        let evt = |tpl: &'static str, secs: f64| {
+            Event::new(
+                Path::new("a"),
+                Timestamp::from_unix(Duration::from_secs_f64(1_000.0 + secs)).unwrap(),
+                Template::literal(tpl),
+                Empty,
+            )
+        };
+
+        assert!(filter.matches(evt("a", 0.0)));
+        assert!(filter.matches(evt("a", 0.1)));
+        assert!(!filter.matches(evt("a", 0.2)));
+        assert!(!filter.matches(evt("a", 0.3)));
+
+        // Each template has its own bucket
+        assert!(filter.matches(evt("b", 0.3)));
+
+        // Half a window refills one token
+        assert!(filter.matches(evt("a", 0.8)));
+        assert!(!filter.matches(evt("a", 0.9)));
+
+        assert!(summaries.0.lock().unwrap().is_empty());
+
+        assert!(filter.matches(evt("a", 1.5)));
+
+        assert_eq!(
+            vec!["suppressed 3 events for a".to_owned()],
+            *summaries.0.lock().unwrap()
+        );
+
+        // Pending summaries for other keys are emitted by any later event
+        assert!(filter.matches(evt("c", 1.6)));
+        assert!(filter.matches(evt("c", 1.6)));
+        assert!(!filter.matches(evt("c", 1.6)));
+
+        assert!(filter.matches(evt("b", 3.0)));
+
+        assert_eq!(
+            vec![
+                "suppressed 3 events for a".to_owned(),
+                "suppressed 1 events for c".to_owned(),
+            ],
+            *summaries.0.lock().unwrap()
+        );
+
+        // Buckets that have fully refilled are evicted
+        assert_eq!(1, filter.buckets());
+
+        // Pending summaries are emitted on flush
+        assert!(filter.matches(evt("b", 3.1)));
+        assert!(!filter.matches(evt("b", 3.2)));
+
+        assert!(filter.blocking_flush(Duration::from_secs(1)));
+
+        assert_eq!(
+            vec![
+                "suppressed 3 events for a".to_owned(),
+                "suppressed 1 events for c".to_owned(),
+                "suppressed 1 events for b".to_owned(),
+            ],
+            *summaries.0.lock().unwrap()
+        );
+
+        assert!(filter.blocking_flush(Duration::from_secs(1)));
+        assert_eq!(3, summaries.0.lock().unwrap().len());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rate_limit_by_tpl_hashes_parts() {
+        use crate::{
+            path::Path,
+            template::{Part, Template},
+            timestamp::Timestamp,
+        };
+
+        static HOLE: [Part; 1] = [Part::hole("a")];
+
+        let filter = rate_limit_by_tpl(1, Duration::from_secs(1));
+
+        let evt = |tpl: Template<'static>| {
+            Event::new(
+                Path::new("a"),
+                Timestamp::from_unix(Duration::from_secs(1_000)).unwrap(),
+                tpl,
+                Empty,
+            )
+        };
+
+        assert!(filter.matches(evt(Template::new(&HOLE))));
+        assert!(!filter.matches(evt(Template::new(&HOLE))));
+
+        // A hole and text with the same content are different keys
+        assert!(filter.matches(evt(Template::literal("a"))));
+    }
+}
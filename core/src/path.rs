@@ -16,6 +16,8 @@ The path syntax is a subset of Rust's paths. The following are not valid paths:
 - `a::{b, c}`.
 
 Paths are used to represent the module on [`crate::event::Event`]s.
+
+A [`PathPattern`] can be used to match paths using wildcards, like `a::*::db` or `a::{b, c}`.
 */
 
 use core::{
@@ -137,6 +139,7 @@ The result of [`Path::segments`].
 
 This type is an iterator over the `::` separated fragments in a [`Path`].
 */
+#[derive(Clone)]
 pub struct Segments<'a> {
     inner: str::Split<'a, &'static str>,
 }
@@ -242,10 +245,291 @@ impl<'a> serde::Serialize for Path<'a> {
 
 #[cfg(feature = "alloc")]
 mod alloc_support {
-    use alloc::{borrow::Cow, boxed::Box};
+    use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+
+    use crate::{event::ToEvent, filter::Filter};
 
     use super::*;
 
+    /**
+    A pattern that matches [`Path`]s.
+
+    Patterns are paths where segments may also be wildcards:
+
+    - `*` matches exactly one segment. The pattern `a::*::db` matches `a::b::db`, but not `a::db` or `a::b::c::db`.
+    - `**` matches zero or more segments. The pattern `**::db` matches `db`, `a::db`, and `a::b::db`.
+    - `{b, c}` matches any one of the listed segments. The pattern `a::{b, c}` matches `a::b` and `a::c`.
+
+    Like [`Path::is_child_of`], patterns also match children of the paths they match. The pattern `a::*::db` matches `a::b::db::pool`.
+
+    Patterns are parsed from text with [`PathPattern::parse`]. Two patterns are equal if they match the same segments, regardless of whitespace or the order of alternatives, so `a::{b, c}` is equal to `a::{c,b}`. A [`Path`] converts into a literal pattern that only matches itself and its children, even if it contains characters like `*`.
+
+    A pattern can be used as a [`Filter`], which matches events where their [`crate::event::Event::module`] matches the pattern.
+    */
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    pub struct PathPattern {
+        segments: Vec<PatternSegment>,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    enum PatternSegment {
+        Literal(Box<str>),
+        One,
+        Many,
+        Alternatives(Vec<Box<str>>),
+    }
+
+    impl<'a> From<Path<'a>> for PathPattern {
+        fn from(value: Path<'a>) -> Self {
+            PathPattern::from(&value)
+        }
+    }
+
+    impl<'a, 'b> From<&'a Path<'b>> for PathPattern {
+        fn from(value: &'a Path<'b>) -> Self {
+            PathPattern {
+                segments: value
+                    .segments()
+                    .map(|segment| PatternSegment::Literal(segment.get().into()))
+                    .collect(),
+            }
+        }
+    }
+
+    impl<'a> From<&'a str> for PathPattern {
+        /**
+        Parse a pattern from text.
+
+        If the text isn't a valid pattern then it's treated as a literal [`Path`]. Use [`PathPattern::parse`] to detect invalid patterns.
+        */
+        fn from(value: &'a str) -> Self {
+            PathPattern::parse(value).unwrap_or_else(|_| PathPattern::from(Path::new_ref(value)))
+        }
+    }
+
+    impl str::FromStr for PathPattern {
+        type Err = ParsePathPatternError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            PathPattern::parse(s)
+        }
+    }
+
+    impl PathPattern {
+        /**
+        Parse a pattern from text.
+
+        Each segment of the pattern is either an identifier, like `db`, a wildcard, like `*` or `**`, or a set of alternative identifiers, like `{b, c}`. Identifiers may contain alphanumeric characters and `_`. Like [`Path`], a pattern may start with `::`.
+
+        If the pattern is invalid then this method will return an error.
+        */
+        pub fn parse(pattern: &str) -> Result<Self, ParsePathPatternError> {
+            fn is_valid_ident(ident: &str) -> bool {
+                !ident.is_empty() && ident.chars().all(|c| c.is_alphanumeric() || c == '_')
+            }
+
+            let mut segments = Vec::new();
+
+            let unrooted = match pattern.strip_prefix("::") {
+                Some(unrooted) => {
+                    segments.push(PatternSegment::Literal("".into()));
+                    unrooted
+                }
+                None => pattern,
+            };
+
+            if unrooted.is_empty() {
+                return Err(ParsePathPatternError {});
+            }
+
+            for segment in unrooted.split("::") {
+                segments.push(match segment {
+                    "*" => PatternSegment::One,
+                    "**" => PatternSegment::Many,
+                    segment => {
+                        if let Some(alternatives) = segment
+                            .strip_prefix('{')
+                            .and_then(|segment| segment.strip_suffix('}'))
+                        {
+                            let mut parsed = Vec::new();
+
+                            for alternative in alternatives.split(',') {
+                                let alternative = alternative.trim();
+
+                                if !is_valid_ident(alternative) {
+                                    return Err(ParsePathPatternError {});
+                                }
+
+                                parsed.push(Box::from(alternative));
+                            }
+
+                            parsed.sort();
+                            parsed.dedup();
+
+                            PatternSegment::Alternatives(parsed)
+                        } else if is_valid_ident(segment) {
+                            PatternSegment::Literal(segment.into())
+                        } else {
+                            return Err(ParsePathPatternError {});
+                        }
+                    }
+                });
+            }
+
+            Ok(PathPattern { segments })
+        }
+
+        /**
+        Whether the pattern is free of wildcards.
+
+        A literal pattern matches the same paths as [`Path::is_child_of`] on its [`PathPattern::to_literal_path`].
+        */
+        pub fn is_literal(&self) -> bool {
+            self.segments
+                .iter()
+                .all(|segment| matches!(segment, PatternSegment::Literal(_)))
+        }
+
+        /**
+        Get the pattern as a [`Path`], if it's free of wildcards.
+        */
+        pub fn to_literal_path(&self) -> Option<Path<'static>> {
+            let mut path = String::new();
+
+            for (i, segment) in self.segments.iter().enumerate() {
+                let PatternSegment::Literal(segment) = segment else {
+                    return None;
+                };
+
+                if i > 0 {
+                    path.push_str("::");
+                }
+
+                path.push_str(segment);
+            }
+
+            Some(Path::new_owned(path))
+        }
+
+        /**
+        The number of segments in the pattern that aren't `**`.
+
+        Patterns with more of these segments match more specific paths.
+        */
+        pub fn specificity(&self) -> usize {
+            self.segments
+                .iter()
+                .filter(|segment| !matches!(segment, PatternSegment::Many))
+                .count()
+        }
+
+        /**
+        Whether the pattern matches `path`, or one of its parents.
+        */
+        pub fn matches_path<'b>(&self, path: &Path<'b>) -> bool {
+            fn matches_segments(pattern: &[PatternSegment], mut path: Segments) -> bool {
+                let mut pattern = pattern.iter();
+
+                loop {
+                    let Some(pattern_segment) = pattern.next() else {
+                        // The pattern is exhausted, so the path is a match or a child of one
+                        return true;
+                    };
+
+                    let path_segment = match pattern_segment {
+                        PatternSegment::Many => loop {
+                            if matches_segments(pattern.as_slice(), path.clone()) {
+                                return true;
+                            }
+
+                            if path.next().is_none() {
+                                return false;
+                            }
+                        },
+                        _ => match path.next() {
+                            Some(path_segment) => path_segment,
+                            None => return false,
+                        },
+                    };
+
+                    let path_segment = path_segment.get();
+
+                    let matches = match pattern_segment {
+                        PatternSegment::Literal(literal) => **literal == *path_segment,
+                        PatternSegment::One | PatternSegment::Many => true,
+                        PatternSegment::Alternatives(alternatives) => alternatives
+                            .iter()
+                            .any(|alternative| **alternative == *path_segment),
+                    };
+
+                    if !matches {
+                        return false;
+                    }
+                }
+            }
+
+            matches_segments(&self.segments, path.segments())
+        }
+    }
+
+    impl Filter for PathPattern {
+        fn matches<E: ToEvent>(&self, evt: E) -> bool {
+            self.matches_path(evt.to_event().module())
+        }
+    }
+
+    impl fmt::Debug for PathPattern {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(&alloc::string::ToString::to_string(self), f)
+        }
+    }
+
+    impl fmt::Display for PathPattern {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (i, segment) in self.segments.iter().enumerate() {
+                if i > 0 {
+                    f.write_str("::")?;
+                }
+
+                match segment {
+                    PatternSegment::Literal(literal) => f.write_str(literal)?,
+                    PatternSegment::One => f.write_str("*")?,
+                    PatternSegment::Many => f.write_str("**")?,
+                    PatternSegment::Alternatives(alternatives) => {
+                        f.write_str("{")?;
+
+                        for (i, alternative) in alternatives.iter().enumerate() {
+                            if i > 0 {
+                                f.write_str(", ")?;
+                            }
+
+                            f.write_str(alternative)?;
+                        }
+
+                        f.write_str("}")?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    /**
+    An error attempting to parse a [`PathPattern`] from text.
+    */
+    #[derive(Debug)]
+    pub struct ParsePathPatternError {}
+
+    impl fmt::Display for ParsePathPatternError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "the input was not a valid path pattern")
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for ParsePathPatternError {}
+
     impl Path<'static> {
         /**
         Create a path from an owned raw value.
@@ -283,6 +567,9 @@ mod alloc_support {
     }
 }
 
+#[cfg(feature = "alloc")]
+pub use self::alloc_support::*;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,4 +588,107 @@ mod tests {
         assert!(a.is_child_of(&a));
         assert!(a_b.is_child_of(&a));
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn pattern_matches_path() {
+        for (pattern, path, matches) in [
+            ("a", "a", true),
+            ("a", "a::b", true),
+            ("a", "aa", false),
+            ("a::b", "a", false),
+            ("*", "a", true),
+            ("a::*", "a", false),
+            ("a::*", "a::b::c", true),
+            ("a::*::db", "a::b::db", true),
+            ("a::*::db", "a::db", false),
+            ("a::*::db", "a::b::c::db", false),
+            ("a::*::db", "a::b::db::pool", true),
+            ("**::db", "db", true),
+            ("**::db", "a::db", true),
+            ("**::db", "a::b::db::pool", true),
+            ("**::db", "a::dbs", false),
+            ("a::**::db", "a::db", true),
+            ("a::**::db", "b::db", false),
+            ("a::{b, c}", "a::b", true),
+            ("a::{b, c}", "a::c::d", true),
+            ("a::{b, c}", "a::d", false),
+        ] {
+            assert_eq!(
+                matches,
+                PathPattern::parse(pattern)
+                    .unwrap()
+                    .matches_path(&Path::new(path)),
+                "{} {}",
+                pattern,
+                path
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn pattern_is_literal() {
+        assert!(PathPattern::parse("a::b").unwrap().is_literal());
+
+        assert!(!PathPattern::parse("a::*").unwrap().is_literal());
+        assert!(!PathPattern::parse("**::b").unwrap().is_literal());
+        assert!(!PathPattern::parse("a::{b, c}").unwrap().is_literal());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn pattern_parse() {
+        for (pattern, expected) in [
+            ("a", Some("a")),
+            ("::c", Some("::c")),
+            ("a::*::db", Some("a::*::db")),
+            ("**::db", Some("**::db")),
+            ("a::{ c ,b}", Some("a::{b, c}")),
+            ("a::{b, b}", Some("a::{b}")),
+            ("", None),
+            ("::", None),
+            ("a::", None),
+            ("a::::b", None),
+            ("a:b", None),
+            ("a::{}", None),
+            ("a::{b,}", None),
+            ("a::{b, c", None),
+            ("a::b c", None),
+        ] {
+            assert_eq!(
+                expected.map(String::from),
+                PathPattern::parse(pattern)
+                    .ok()
+                    .map(|pattern| pattern.to_string()),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn pattern_eq_ignores_whitespace() {
+        assert_eq!(
+            PathPattern::parse("a::{b, c}").unwrap(),
+            PathPattern::parse("a::{c,b}").unwrap()
+        );
+        assert_ne!(
+            PathPattern::parse("a::{b, c}").unwrap(),
+            PathPattern::parse("a::{b, d}").unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn pattern_from_path_is_literal() {
+        let pattern = PathPattern::from(Path::new("a::*"));
+
+        assert!(pattern.is_literal());
+        assert_eq!(Some(Path::new("a::*")), pattern.to_literal_path());
+
+        assert!(pattern.matches_path(&Path::new("a::*::b")));
+        assert!(!pattern.matches_path(&Path::new("a::b")));
+    }
 }
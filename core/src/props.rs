@@ -287,6 +287,10 @@ impl<A: Props, B: Props> Props for And<A, B> {
 mod alloc_support {
     use super::*;
 
+    use core::fmt;
+
+    use crate::value::OwnedValue;
+
     /**
     The result of calling [`Props::dedup`].
 
@@ -334,6 +338,105 @@ mod alloc_support {
             true
         }
     }
+
+    /**
+    A set of [`Props`] that own their keys and values.
+
+    Owned props can be created from any other [`Props`] through [`OwnedProps::collect_owned`] or [`OwnedProps::collect_shared`]. Properties are de-duplicated while they're collected.
+    */
+    #[derive(Clone)]
+    pub struct OwnedProps {
+        props: alloc::boxed::Box<[(Str<'static>, OwnedValue)]>,
+    }
+
+    impl OwnedProps {
+        /**
+        Collect the properties in `props`, taking an owned copy of each key and value.
+
+        Keys are converted through [`Str::to_owned`], and values through [`Value::to_owned`].
+        */
+        pub fn collect_owned(props: impl Props) -> Self {
+            Self::collect(props, |k, v| (k.to_owned(), v.to_owned()))
+        }
+
+        /**
+        Collect the properties in `props`, taking a shared copy of each key and value.
+
+        Keys are converted through [`Str::to_shared`], and values through [`Value::to_shared`]. The resulting props are cheap to clone.
+        */
+        pub fn collect_shared(props: impl Props) -> Self {
+            Self::collect(props, |k, v| (k.to_shared(), v.to_shared()))
+        }
+
+        fn collect(
+            props: impl Props,
+            mut to_owned: impl FnMut(Str, Value) -> (Str<'static>, OwnedValue),
+        ) -> Self {
+            let mut owned = alloc::vec::Vec::new();
+
+            let _ = props.dedup().for_each(|k, v| {
+                owned.push(to_owned(k, v));
+
+                ControlFlow::Continue(())
+            });
+
+            OwnedProps {
+                props: owned.into_boxed_slice(),
+            }
+        }
+
+        /**
+        Get the number of properties in the collection.
+        */
+        pub fn len(&self) -> usize {
+            self.props.len()
+        }
+
+        /**
+        Whether the collection contains any properties.
+        */
+        pub fn is_empty(&self) -> bool {
+            self.props.is_empty()
+        }
+    }
+
+    impl Props for OwnedProps {
+        fn for_each<'kv, F: FnMut(Str<'kv>, Value<'kv>) -> ControlFlow<()>>(
+            &'kv self,
+            mut for_each: F,
+        ) -> ControlFlow<()> {
+            for (k, v) in &*self.props {
+                for_each(k.by_ref(), v.by_ref())?;
+            }
+
+            ControlFlow::Continue(())
+        }
+
+        fn get<'v, K: ToStr>(&'v self, key: K) -> Option<Value<'v>> {
+            let key = key.to_str();
+
+            self.props
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.by_ref())
+        }
+
+        fn is_unique(&self) -> bool {
+            true
+        }
+    }
+
+    impl fmt::Debug for OwnedProps {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut f = f.debug_map();
+
+            for (k, v) in &*self.props {
+                f.entry(&k.get(), v);
+            }
+
+            f.finish()
+        }
+    }
 }
 
 #[cfg(feature = "alloc")]
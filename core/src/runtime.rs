@@ -88,6 +88,7 @@ pub struct Runtime<TEmitter = Empty, TFilter = Empty, TCtxt = Empty, TClock = Em
     pub(crate) ctxt: TCtxt,
     pub(crate) clock: TClock,
     pub(crate) rng: TRng,
+    pub(crate) span_begin: bool,
 }
 
 impl Default for Runtime {
@@ -107,6 +108,7 @@ impl Runtime {
             ctxt: Empty,
             clock: Empty,
             rng: Empty,
+            span_begin: false,
         }
     }
 }
@@ -128,6 +130,7 @@ impl<TEmitter, TFilter, TCtxt, TClock, TRng> Runtime<TEmitter, TFilter, TCtxt, T
             ctxt,
             clock,
             rng,
+            span_begin: false,
         }
     }
 
@@ -158,6 +161,7 @@ impl<TEmitter, TFilter, TCtxt, TClock, TRng> Runtime<TEmitter, TFilter, TCtxt, T
             ctxt: self.ctxt,
             clock: self.clock,
             rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
@@ -188,6 +192,7 @@ impl<TEmitter, TFilter, TCtxt, TClock, TRng> Runtime<TEmitter, TFilter, TCtxt, T
             ctxt: self.ctxt,
             clock: self.clock,
             rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
@@ -218,6 +223,7 @@ impl<TEmitter, TFilter, TCtxt, TClock, TRng> Runtime<TEmitter, TFilter, TCtxt, T
             ctxt: ctxt(self.ctxt),
             clock: self.clock,
             rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
@@ -248,6 +254,7 @@ impl<TEmitter, TFilter, TCtxt, TClock, TRng> Runtime<TEmitter, TFilter, TCtxt, T
             ctxt: self.ctxt,
             clock: clock(self.clock),
             rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
@@ -278,8 +285,26 @@ impl<TEmitter, TFilter, TCtxt, TClock, TRng> Runtime<TEmitter, TFilter, TCtxt, T
             ctxt: self.ctxt,
             clock: self.clock,
             rng: id_gen(self.rng),
+            span_begin: self.span_begin,
         }
     }
+
+    /**
+    Whether span macros using this runtime emit an event when their span begins, as well as when it completes.
+
+    Span begin events have an event kind of [`crate::well_known::EVENT_KIND_SPAN_BEGIN`]. This is `false` unless set through [`Runtime::with_span_begin`]. Span macros can override it with their `begin` control parameter.
+    */
+    pub const fn span_begin(&self) -> bool {
+        self.span_begin
+    }
+
+    /**
+    Set whether span macros using this runtime emit an event when their span begins.
+    */
+    pub fn with_span_begin(mut self, span_begin: bool) -> Self {
+        self.span_begin = span_begin;
+        self
+    }
 }
 
 impl<TEmitter: Emitter, TFilter: Filter, TCtxt: Ctxt, TClock: Clock, TRng: Rng>
@@ -364,12 +389,25 @@ impl<T: InternalFilter, U: InternalEmitter> InternalEmitter
 {
 }
 
+impl<R: InternalRng, K: InternalFilter> InternalFilter for crate::filter::SampleFilter<R, K> {}
+
+impl<R: InternalRng, K: InternalFilter, E: InternalEmitter> InternalEmitter
+    for crate::filter::SampledEmitter<R, K, E>
+{
+}
+
 #[cfg(feature = "alloc")]
 impl<'a, T: ?Sized + InternalFilter> InternalFilter for alloc::boxed::Box<T> {}
 
 #[cfg(feature = "alloc")]
 impl<'a, T: ?Sized + InternalFilter> InternalFilter for alloc::sync::Arc<T> {}
 
+#[cfg(feature = "std")]
+impl<T: InternalFilter> InternalFilter for crate::filter::ReloadableFilter<T> {}
+
+#[cfg(feature = "std")]
+impl<E: InternalEmitter> InternalFilter for crate::filter::RateLimitFilter<E> {}
+
 /**
 A marker trait for a [`Ctxt`] that does not emit any diagnostics of its own.
 */
@@ -469,6 +507,10 @@ impl<T: Clock> Clock for AssertInternal<T> {
     fn now(&self) -> Option<Timestamp> {
         self.0.now()
     }
+
+    fn monotonic(&self) -> Option<core::time::Duration> {
+        self.0.monotonic()
+    }
 }
 
 impl<T: Rng> Rng for AssertInternal<T> {
@@ -695,7 +737,8 @@ mod std_support {
                         value.ctxt().as_super() as *const _,
                         value.clock().as_super() as *const _,
                         value.rng().as_super() as *const _,
-                    );
+                    )
+                    .with_span_begin(value.span_begin());
 
                     AmbientSync { value, runtime }
                 })
@@ -703,13 +746,16 @@ mod std_support {
 
             let rt = self.0.get()?;
 
-            Some(Runtime::build(
-                rt.value.emitter().as_any().downcast_ref()?,
-                rt.value.filter().as_any().downcast_ref()?,
-                rt.value.ctxt().as_any().downcast_ref()?,
-                rt.value.clock().as_any().downcast_ref()?,
-                rt.value.rng().as_any().downcast_ref()?,
-            ))
+            Some(
+                Runtime::build(
+                    rt.value.emitter().as_any().downcast_ref()?,
+                    rt.value.filter().as_any().downcast_ref()?,
+                    rt.value.ctxt().as_any().downcast_ref()?,
+                    rt.value.clock().as_any().downcast_ref()?,
+                    rt.value.rng().as_any().downcast_ref()?,
+                )
+                .with_span_begin(rt.value.span_begin()),
+            )
         }
 
         /**
@@ -16,7 +16,7 @@ Hello, Rust.
 `Template`s are conceptually similar to the standard library's `Arguments` type. The key difference between them is that templates are a runtime construct rather than a compile time one. You can construct a template programmatically, inspect its holes, and choose to render it in any way you like. The standard library's formatting APIs are optimized for producing strings. Templates are both a property capturing and a formatting tool.
 */
 
-use core::{cmp, fmt};
+use core::{cmp, fmt, str::FromStr};
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
@@ -136,6 +136,26 @@ impl<'a> Template<'a> {
         }
     }
 
+    // Hash the parts of the template without rendering them
+    // Templates split into parts differently may hash differently, even if they render the same
+    #[cfg(feature = "std")]
+    pub(crate) fn hash_parts<H: core::hash::Hasher>(&self, state: &mut H) {
+        use core::hash::Hash;
+
+        for part in self.0.parts() {
+            match part.0 {
+                PartKind::Text { ref value } => {
+                    0u8.hash(state);
+                    value.hash(state);
+                }
+                PartKind::Hole { ref label, .. } => {
+                    1u8.hash(state);
+                    label.hash(state);
+                }
+            }
+        }
+    }
+
     /**
     Lazily render the template, using the given properties for interpolation.
     */
@@ -335,6 +355,16 @@ pub trait Write: fmt::Write {
     fn write_hole_label(&mut self, label: &str) -> fmt::Result {
         self.write_fmt(format_args!("`{}`", label))
     }
+
+    /**
+    Write a hole with a formatter but without a matching value.
+
+    This method is called for any [`Part::hole`] in the template with special formatting requirements where a matching property doesn't exist. The default implementation ignores the formatter and calls [`Write::write_hole_label`].
+    */
+    fn write_hole_label_fmt(&mut self, label: &str, formatter: Formatter) -> fmt::Result {
+        let _ = formatter;
+        self.write_hole_label(label)
+    }
 }
 
 impl<'a, W: Write + ?Sized> Write for &'a mut W {
@@ -353,6 +383,10 @@ impl<'a, W: Write + ?Sized> Write for &'a mut W {
     fn write_hole_label(&mut self, label: &str) -> fmt::Result {
         (**self).write_hole_label(label)
     }
+
+    fn write_hole_label_fmt(&mut self, label: &str, formatter: Formatter) -> fmt::Result {
+        (**self).write_hole_label_fmt(label, formatter)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -425,7 +459,15 @@ impl<W: fmt::Write> fmt::Write for WriteBraced<W> {
 }
 
 impl<W: Write> Write for WriteBraced<W> {
-    fn write_text(&mut self, text: &str) -> fmt::Result {
+    fn write_text(&mut self, mut text: &str) -> fmt::Result {
+        // Escape braces so the result can be parsed back into a template
+        while let Some(i) = text.find(['{', '}']) {
+            self.0.write_text(&text[..=i])?;
+            self.0.write_text(&text[i..=i])?;
+
+            text = &text[i + 1..];
+        }
+
         self.0.write_text(text)
     }
 
@@ -440,6 +482,16 @@ impl<W: Write> Write for WriteBraced<W> {
     fn write_hole_label(&mut self, label: &str) -> fmt::Result {
         self.0.write_fmt(format_args!("{{{}}}", label))
     }
+
+    fn write_hole_label_fmt(&mut self, label: &str, formatter: Formatter) -> fmt::Result {
+        // Keep format specs so the result can be parsed back into the same template
+        match formatter.spec() {
+            Some(spec) if *spec != FormatSpec::new() => {
+                self.0.write_fmt(format_args!("{{{}:{}}}", label, spec))
+            }
+            _ => self.write_hole_label(label),
+        }
+    }
 }
 
 /**
@@ -452,6 +504,8 @@ pub struct Braced<'a, P>(Render<'a, P>);
 impl<'a, P> Render<'a, P> {
     /**
     Render holes that have missing properties between braces, like `Hello, {user}`.
+
+    Braces in the text of the template are escaped by doubling them, like `{{`.
     */
     pub fn braced(self) -> Braced<'a, P> {
         Braced(self)
@@ -588,6 +642,8 @@ impl<'a> Part<'a> {
                     } else {
                         writer.write_hole_value(label, value)
                     }
+                } else if let Some(formatter) = formatter {
+                    writer.write_hole_label_fmt(label, formatter.clone())
                 } else {
                     writer.write_hole_label(label)
                 }
@@ -599,11 +655,15 @@ impl<'a> Part<'a> {
 /**
 A specialized formatter for a [`Value`] interpolated into a [`Part::hole`].
 
-This type supports formatting values using standard Rust flags like padding and precision.
+This type supports formatting values using standard Rust flags like padding and precision. A formatter is either a function, which is what the `emit::fmt` attribute produces, or a [`FormatSpec`] that's interpreted when the template is rendered.
 */
 #[derive(Clone)]
-pub struct Formatter {
-    fmt: fn(Value, &mut fmt::Formatter) -> fmt::Result,
+pub struct Formatter(FormatterKind);
+
+#[derive(Clone)]
+enum FormatterKind {
+    Fn(fn(Value, &mut fmt::Formatter) -> fmt::Result),
+    Spec(FormatSpec),
 }
 
 impl Formatter {
@@ -613,14 +673,36 @@ impl Formatter {
     It's the responsibility of the function to actually write the value into the formatter.
     */
     pub fn new(fmt: fn(Value, &mut fmt::Formatter) -> fmt::Result) -> Self {
-        Formatter { fmt }
+        Formatter(FormatterKind::Fn(fmt))
+    }
+
+    /**
+    Create a formatter from a [`FormatSpec`].
+    */
+    pub const fn from_spec(spec: FormatSpec) -> Self {
+        Formatter(FormatterKind::Spec(spec))
+    }
+
+    /**
+    Get the [`FormatSpec`] this formatter was created from, if there is one.
+
+    Formatters created from a function through [`Formatter::new`] will return `None`.
+    */
+    pub fn spec(&self) -> Option<&FormatSpec> {
+        match self.0 {
+            FormatterKind::Spec(ref spec) => Some(spec),
+            FormatterKind::Fn(_) => None,
+        }
     }
 
     /**
     Invoke the formatter on a given value.
     */
     pub fn fmt(&self, value: Value, f: &mut fmt::Formatter) -> fmt::Result {
-        (self.fmt)(value, f)
+        match self.0 {
+            FormatterKind::Fn(fmt) => fmt(value, f),
+            FormatterKind::Spec(ref spec) => spec.fmt_value(value, f),
+        }
     }
 
     /**
@@ -629,22 +711,461 @@ impl Formatter {
     pub fn apply<'b>(&'b self, value: Value<'b>) -> impl fmt::Display + 'b {
         struct FormatValue<'a> {
             value: Value<'a>,
-            fmt: fn(Value, &mut fmt::Formatter) -> fmt::Result,
+            formatter: &'a Formatter,
         }
 
         impl<'a> fmt::Display for FormatValue<'a> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                (self.fmt)(self.value.by_ref(), f)
+                self.formatter.fmt(self.value.by_ref(), f)
             }
         }
 
         FormatValue {
             value,
-            fmt: self.fmt,
+            formatter: self,
+        }
+    }
+}
+
+/**
+A standard Rust format spec, like `>8.2`, that's interpreted at runtime.
+
+Format specs follow the same syntax as the standard library's, described in [`core::fmt`]:
+
+```text
+[[fill]align][sign]['#']['0'][width]['.' precision][type]
+```
+
+The `width` and `precision` must be integers; they can't refer to other arguments. The `type` may be empty, for [`fmt::Display`], or `?` for [`fmt::Debug`]. Other format traits like `x` aren't supported.
+
+Specs can be parsed from text through [`FormatSpec::parse`], or built up from [`FormatSpec::new`].
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatSpec {
+    fill: char,
+    align: Option<Align>,
+    sign_plus: bool,
+    alternate: bool,
+    zero_pad: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+    debug: bool,
+}
+
+/**
+The alignment of a value padded to a given width in a [`FormatSpec`].
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    /**
+    Align the value to the left, `<`.
+    */
+    Left,
+    /**
+    Center the value, `^`.
+    */
+    Center,
+    /**
+    Align the value to the right, `>`.
+    */
+    Right,
+}
+
+impl Default for FormatSpec {
+    fn default() -> Self {
+        FormatSpec::new()
+    }
+}
+
+impl FormatSpec {
+    /**
+    Create an empty format spec.
+
+    An empty spec formats values using their [`fmt::Display`] implementation, the same as a hole without any formatter.
+    */
+    pub const fn new() -> Self {
+        FormatSpec {
+            fill: ' ',
+            align: None,
+            sign_plus: false,
+            alternate: false,
+            zero_pad: false,
+            width: None,
+            precision: None,
+            debug: false,
+        }
+    }
+
+    /**
+    Parse a format spec from text, like `>8.2`.
+
+    The input shouldn't include the leading `:`.
+    */
+    pub fn parse(spec: &str) -> Result<Self, ParseFormatSpecError> {
+        let mut parsed = FormatSpec::new();
+        let mut rest = spec;
+
+        fn to_align(c: char) -> Option<Align> {
+            match c {
+                '<' => Some(Align::Left),
+                '^' => Some(Align::Center),
+                '>' => Some(Align::Right),
+                _ => None,
+            }
+        }
+
+        fn take_usize(rest: &mut &str) -> Option<usize> {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+
+            let (digits, tail) = rest.split_at(end);
+            *rest = tail;
+
+            digits.parse().ok()
+        }
+
+        // [[fill]align]
+        let mut chars = rest.chars();
+        match (chars.next(), chars.next()) {
+            (Some(fill), Some(align)) if to_align(align).is_some() => {
+                parsed.fill = fill;
+                parsed.align = to_align(align);
+                rest = chars.as_str();
+            }
+            (Some(align), _) if to_align(align).is_some() => {
+                parsed.align = to_align(align);
+                rest = &rest[1..];
+            }
+            _ => (),
+        }
+
+        // [sign]
+        if let Some(tail) = rest.strip_prefix('+') {
+            parsed.sign_plus = true;
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix('-') {
+            rest = tail;
+        }
+
+        // ['#']
+        if let Some(tail) = rest.strip_prefix('#') {
+            parsed.alternate = true;
+            rest = tail;
+        }
+
+        // ['0']
+        if let Some(tail) = rest.strip_prefix('0') {
+            parsed.zero_pad = true;
+            rest = tail;
+        }
+
+        // [width]
+        parsed.width = take_usize(&mut rest);
+
+        // ['.' precision]
+        if let Some(tail) = rest.strip_prefix('.') {
+            rest = tail;
+            parsed.precision = Some(take_usize(&mut rest).ok_or(ParseFormatSpecError {})?);
+        }
+
+        // [type]
+        match rest {
+            "" => (),
+            "?" => parsed.debug = true,
+            _ => return Err(ParseFormatSpecError {}),
+        }
+
+        Ok(parsed)
+    }
+
+    /**
+    The character used to pad values to their width.
+
+    The default fill is a space.
+    */
+    pub const fn fill(&self) -> char {
+        self.fill
+    }
+
+    /**
+    Set the character used to pad values to their width.
+    */
+    pub const fn with_fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /**
+    The alignment of values padded to their width.
+
+    If there's no alignment then the default for the value is used. Numbers are aligned to the right, and everything else to the left.
+    */
+    pub const fn align(&self) -> Option<Align> {
+        self.align
+    }
+
+    /**
+    Set the alignment of values padded to their width.
+    */
+    pub const fn with_align(mut self, align: Align) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /**
+    The minimum width of the formatted value.
+    */
+    pub const fn width(&self) -> Option<usize> {
+        self.width
+    }
+
+    /**
+    Set the minimum width of the formatted value.
+    */
+    pub const fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /**
+    The precision of the formatted value.
+
+    For floating points, this is the number of digits after the decimal point. For strings, this is the maximum number of characters.
+    */
+    pub const fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /**
+    Set the precision of the formatted value.
+    */
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /**
+    Whether a `+` is always written for non-negative numbers.
+    */
+    pub const fn sign_plus(&self) -> bool {
+        self.sign_plus
+    }
+
+    /**
+    Set whether a `+` is always written for non-negative numbers.
+    */
+    pub const fn with_sign_plus(mut self, sign_plus: bool) -> Self {
+        self.sign_plus = sign_plus;
+        self
+    }
+
+    /**
+    Whether the alternate form of the value is written, `#`.
+    */
+    pub const fn alternate(&self) -> bool {
+        self.alternate
+    }
+
+    /**
+    Set whether the alternate form of the value is written.
+    */
+    pub const fn with_alternate(mut self, alternate: bool) -> Self {
+        self.alternate = alternate;
+        self
+    }
+
+    /**
+    Whether numbers are padded to their width with zeroes, `0`.
+    */
+    pub const fn zero_pad(&self) -> bool {
+        self.zero_pad
+    }
+
+    /**
+    Set whether numbers are padded to their width with zeroes.
+    */
+    pub const fn with_zero_pad(mut self, zero_pad: bool) -> Self {
+        self.zero_pad = zero_pad;
+        self
+    }
+
+    /**
+    Whether values are formatted using [`fmt::Debug`] instead of [`fmt::Display`].
+    */
+    pub const fn debug(&self) -> bool {
+        self.debug
+    }
+
+    /**
+    Set whether values are formatted using [`fmt::Debug`] instead of [`fmt::Display`].
+    */
+    pub const fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    fn fmt_value(&self, value: Value, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some(align) = self.align.filter(|_| !self.zero_pad) else {
+            // Without an explicit alignment, let the value decide how to pad itself
+            return self.fmt_value_unpadded(&value, f, self.width.unwrap_or(0));
+        };
+
+        let width = self.width.unwrap_or(0);
+
+        // Custom fill characters can't be passed to the standard formatting
+        // machinery, so measure the value and pad it here
+        let len = {
+            let mut count = Count(0);
+            self.fmt_value_unpadded(&value, &mut count, 0)?;
+
+            count.0
+        };
+
+        let pad = width.saturating_sub(len);
+        let (pre, post) = match align {
+            Align::Left => (0, pad),
+            Align::Center => (pad / 2, pad - pad / 2),
+            Align::Right => (pad, 0),
+        };
+
+        for _ in 0..pre {
+            fmt::Write::write_char(f, self.fill)?;
+        }
+
+        self.fmt_value_unpadded(&value, &mut *f, 0)?;
+
+        for _ in 0..post {
+            fmt::Write::write_char(f, self.fill)?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_value_unpadded(
+        &self,
+        value: &Value,
+        mut f: impl fmt::Write,
+        width: usize,
+    ) -> fmt::Result {
+        macro_rules! fmt_value {
+            ($($flags:literal)?, $($ty:literal)?) => {
+                match self.precision {
+                    Some(precision) => write!(
+                        f,
+                        concat!("{value:", $($flags,)? "width$.precision$", $($ty,)? "}"),
+                        value = value,
+                        width = width,
+                        precision = precision
+                    ),
+                    None => write!(
+                        f,
+                        concat!("{value:", $($flags,)? "width$", $($ty,)? "}"),
+                        value = value,
+                        width = width
+                    ),
+                }
+            };
+        }
+
+        match (self.sign_plus, self.alternate, self.zero_pad, self.debug) {
+            (false, false, false, false) => fmt_value!(,),
+            (true, false, false, false) => fmt_value!("+",),
+            (false, true, false, false) => fmt_value!("#",),
+            (false, false, true, false) => fmt_value!("0",),
+            (true, true, false, false) => fmt_value!("+#",),
+            (true, false, true, false) => fmt_value!("+0",),
+            (false, true, true, false) => fmt_value!("#0",),
+            (true, true, true, false) => fmt_value!("+#0",),
+            (false, false, false, true) => fmt_value!(, "?"),
+            (true, false, false, true) => fmt_value!("+", "?"),
+            (false, true, false, true) => fmt_value!("#", "?"),
+            (false, false, true, true) => fmt_value!("0", "?"),
+            (true, true, false, true) => fmt_value!("+#", "?"),
+            (true, false, true, true) => fmt_value!("+0", "?"),
+            (false, true, true, true) => fmt_value!("#0", "?"),
+            (true, true, true, true) => fmt_value!("+#0", "?"),
+        }
+    }
+}
+
+struct Count(usize);
+
+impl fmt::Write for Count {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for FormatSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write as _;
+
+        if let Some(align) = self.align {
+            if self.fill != ' ' {
+                f.write_char(self.fill)?;
+            }
+
+            f.write_char(match align {
+                Align::Left => '<',
+                Align::Center => '^',
+                Align::Right => '>',
+            })?;
+        }
+
+        if self.sign_plus {
+            f.write_char('+')?;
+        }
+
+        if self.alternate {
+            f.write_char('#')?;
+        }
+
+        if self.zero_pad {
+            f.write_char('0')?;
         }
+
+        if let Some(width) = self.width {
+            write!(f, "{}", width)?;
+        }
+
+        if let Some(precision) = self.precision {
+            write!(f, ".{}", precision)?;
+        }
+
+        if self.debug {
+            f.write_char('?')?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for FormatSpec {
+    type Err = ParseFormatSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FormatSpec::parse(s)
     }
 }
 
+/**
+An error attempting to parse a [`FormatSpec`] from text.
+*/
+#[derive(Debug)]
+pub struct ParseFormatSpecError {}
+
+impl fmt::Display for ParseFormatSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the input was not a valid format spec")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFormatSpecError {}
+
 #[derive(Clone)]
 enum PartKind<'a> {
     Text {
@@ -660,7 +1181,8 @@ enum PartKind<'a> {
 mod alloc_support {
     use super::*;
 
-    use alloc::vec::Vec;
+    use alloc::{string::String, vec::Vec};
+    use core::mem;
 
     impl Template<'static> {
         /**
@@ -671,8 +1193,197 @@ mod alloc_support {
 
             Template(TemplateKind::Owned(parts))
         }
+
+        /**
+        Parse a template from text.
+
+        Holes are labels wrapped in braces, like `{user}`. Whitespace around labels is ignored. Holes may include a [`FormatSpec`] after a `:`, like `{elapsed_ms:>8.2}`. Literal braces can be escaped by doubling them, so `{{` is parsed as text containing `{`, and `}}` as text containing `}`. This is the same syntax used by the [`Render::braced`] rendering of a template, so those can be parsed back into their original templates.
+
+        If the template is invalid, such as when a hole is missing its closing brace, then this method will return an error carrying the position in the input where the problem was found.
+        */
+        pub fn parse(tpl: &str) -> Result<Self, ParseTemplateError> {
+            let mut parts = Vec::new();
+            let mut text = String::new();
+
+            let mut chars = tpl.char_indices().peekable();
+
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '{' => {
+                        if let Some((_, '{')) = chars.peek() {
+                            chars.next();
+                            text.push('{');
+
+                            continue;
+                        }
+
+                        let mut end = None;
+                        for (j, c) in chars.by_ref() {
+                            match c {
+                                '}' => {
+                                    end = Some(j);
+                                    break;
+                                }
+                                '{' => return Err(ParseTemplateError::nested_hole(j)),
+                                _ => (),
+                            }
+                        }
+
+                        let Some(end) = end else {
+                            return Err(ParseTemplateError::unclosed_hole(i));
+                        };
+
+                        let (label, spec) = match tpl[i + 1..end].split_once(':') {
+                            Some((label, spec)) => (label, Some(spec)),
+                            None => (&tpl[i + 1..end], None),
+                        };
+
+                        let label = label.trim();
+
+                        if label.is_empty() {
+                            return Err(ParseTemplateError::empty_hole(i));
+                        }
+
+                        if !text.is_empty() {
+                            parts.push(Part::text_owned(mem::take(&mut text)));
+                        }
+
+                        let mut hole = Part::hole_owned(label);
+
+                        if let Some(spec) = spec {
+                            let spec = FormatSpec::parse(spec)
+                                .map_err(|_| ParseTemplateError::invalid_format_spec(i))?;
+
+                            hole = hole.with_formatter(Formatter::from_spec(spec));
+                        }
+
+                        parts.push(hole);
+                    }
+                    '}' => {
+                        if let Some((_, '}')) = chars.peek() {
+                            chars.next();
+                            text.push('}');
+
+                            continue;
+                        }
+
+                        return Err(ParseTemplateError::unopened_hole(i));
+                    }
+                    c => text.push(c),
+                }
+            }
+
+            if !text.is_empty() || parts.is_empty() {
+                parts.push(Part::text_owned(text));
+            }
+
+            Ok(Template::new_owned(parts))
+        }
     }
 
+    impl FromStr for Template<'static> {
+        type Err = ParseTemplateError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Template::parse(s)
+        }
+    }
+
+    /**
+    An error attempting to parse a [`Template`] from text.
+    */
+    #[derive(Debug)]
+    pub struct ParseTemplateError {
+        kind: ParseTemplateErrorKind,
+        position: usize,
+    }
+
+    #[derive(Debug)]
+    enum ParseTemplateErrorKind {
+        Unclosed,
+        Unopened,
+        Nested,
+        Empty,
+        FormatSpec,
+    }
+
+    impl ParseTemplateError {
+        fn unclosed_hole(position: usize) -> Self {
+            ParseTemplateError {
+                kind: ParseTemplateErrorKind::Unclosed,
+                position,
+            }
+        }
+
+        fn unopened_hole(position: usize) -> Self {
+            ParseTemplateError {
+                kind: ParseTemplateErrorKind::Unopened,
+                position,
+            }
+        }
+
+        fn nested_hole(position: usize) -> Self {
+            ParseTemplateError {
+                kind: ParseTemplateErrorKind::Nested,
+                position,
+            }
+        }
+
+        fn empty_hole(position: usize) -> Self {
+            ParseTemplateError {
+                kind: ParseTemplateErrorKind::Empty,
+                position,
+            }
+        }
+
+        fn invalid_format_spec(position: usize) -> Self {
+            ParseTemplateError {
+                kind: ParseTemplateErrorKind::FormatSpec,
+                position,
+            }
+        }
+
+        /**
+        The byte offset in the input where the error was found.
+        */
+        pub fn position(&self) -> usize {
+            self.position
+        }
+    }
+
+    impl fmt::Display for ParseTemplateError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self.kind {
+                ParseTemplateErrorKind::Unclosed => write!(
+                    f,
+                    "the hole at position {} is missing a closing `}}`; literal braces can be escaped as `{{{{`",
+                    self.position
+                ),
+                ParseTemplateErrorKind::Unopened => write!(
+                    f,
+                    "the `}}` at position {} doesn't close a hole; literal braces can be escaped as `}}}}`",
+                    self.position
+                ),
+                ParseTemplateErrorKind::Nested => write!(
+                    f,
+                    "the `{{` at position {} is inside another hole",
+                    self.position
+                ),
+                ParseTemplateErrorKind::Empty => {
+                    write!(f, "the hole at position {} has no label", self.position)
+                }
+                ParseTemplateErrorKind::FormatSpec => write!(
+                    f,
+                    "the hole at position {} has an invalid format spec",
+                    self.position
+                ),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for ParseTemplateError {}
+
     impl<'a> Template<'a> {
         /**
         Get a new template from this one, converting its parts into owned data.
@@ -761,4 +1472,112 @@ mod tests {
 
         assert_eq!(a, b);
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn template_parse() {
+        for (input, expected) in [
+            ("", &[Part::text("")] as &[_]),
+            ("Hello", &[Part::text("Hello")]),
+            (
+                "User {user} logged in from { ip }",
+                &[
+                    Part::text("User "),
+                    Part::hole("user"),
+                    Part::text(" logged in from "),
+                    Part::hole("ip"),
+                ],
+            ),
+            ("{a}{b}", &[Part::hole("a"), Part::hole("b")]),
+            (
+                "{{a}} {{{b}}}",
+                &[Part::text("{a} {"), Part::hole("b"), Part::text("}")],
+            ),
+        ] {
+            let actual = Template::parse(input).unwrap();
+
+            assert_eq!(Template::new_ref(expected), actual, "{input}");
+            assert_eq!(
+                input.replace(" ip ", "ip"),
+                actual.render(Empty).braced().to_string()
+            );
+        }
+
+        // Format specs are kept when rendering braced
+        for input in ["{a:>8.2}", "a {b:?} {c:*^10}", "{d:+#010.3}"] {
+            let actual = Template::parse(input).unwrap();
+
+            assert_eq!(input, actual.render(Empty).braced().to_string());
+        }
+
+        for (input, position) in [
+            ("a {b", 2),
+            ("a }", 2),
+            ("{a {b}}", 3),
+            ("a { }", 2),
+            ("a {b:x}", 2),
+        ] {
+            assert_eq!(
+                position,
+                Template::parse(input).unwrap_err().position(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn format_spec_parse() {
+        for (input, expected) in [
+            ("", FormatSpec::new()),
+            ("?", FormatSpec::new().with_debug(true)),
+            ("8", FormatSpec::new().with_width(8)),
+            (".2", FormatSpec::new().with_precision(2)),
+            (
+                ">8.2",
+                FormatSpec::new()
+                    .with_align(Align::Right)
+                    .with_width(8)
+                    .with_precision(2),
+            ),
+            (
+                "*^10?",
+                FormatSpec::new()
+                    .with_fill('*')
+                    .with_align(Align::Center)
+                    .with_width(10)
+                    .with_debug(true),
+            ),
+            ("<", FormatSpec::new().with_align(Align::Left)),
+        ] {
+            let actual = FormatSpec::parse(input).unwrap();
+
+            assert_eq!(expected, actual, "{input}");
+            assert_eq!(input, actual.to_string());
+        }
+
+        for input in ["x", "8.", ".x", "8 ", "$"] {
+            assert!(FormatSpec::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn format_spec_fmt() {
+        for (spec, value, expected) in [
+            ("", Value::from(1.5f64), "1.5"),
+            (".2", Value::from(1.5f64), "1.50"),
+            ("8.2", Value::from(1.5f64), "    1.50"),
+            ("<8.2", Value::from(1.5f64), "1.50    "),
+            ("*^9", Value::from("abc"), "***abc***"),
+            ("8", Value::from("abc"), "abc     "),
+            (">5", Value::from("abc"), "  abc"),
+            ("+06.1", Value::from(1.5f64), "+001.5"),
+            ("?", Value::from("abc"), "\"abc\""),
+            ("-<3", Value::from("abcd"), "abcd"),
+        ] {
+            let formatter = Formatter::from_spec(FormatSpec::parse(spec).unwrap());
+
+            assert_eq!(expected, formatter.apply(value).to_string(), "{spec}");
+        }
+    }
 }
@@ -6,6 +6,8 @@ A timestamp is a point in time, represented as the number of nanoseconds since t
 Timestamps can be constructed manually through [`Timestamp::from_unix`], or the current timestamp can be read from an instance of [`crate::clock::Clock`].
 
 A timestamp can be converted into a point [`crate::extent::Extent`]. A pair of timestamps representing a timespan can be converted into a span [`crate::extent::Extent`].
+
+Timestamps are always in UTC, but can be rendered with a fixed UTC [`Offset`] through [`Timestamp::display_with_offset`].
 */
 
 /*
@@ -72,8 +74,16 @@ pub struct Parts {
     pub nanos: u32,
 }
 
+/**
+A fixed offset from UTC.
+
+Offsets are a whole number of minutes ahead or behind UTC, less than a day in either direction.
+*/
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Offset(i32);
+
 // 2000-03-01 (mod 400 year, immediately after feb29
-const LEAPOCH_SECS: u64 = 946_684_800 + 86400 * (31 + 29);
+const LEAPOCH_SECS: i64 = 946_684_800 + 86400 * (31 + 29);
 const DAYS_PER_400Y: i32 = 365 * 400 + 97;
 const DAYS_PER_100Y: i32 = 365 * 100 + 24;
 const DAYS_PER_4Y: i32 = 365 * 4 + 1;
@@ -85,6 +95,9 @@ const MIN: Duration = Duration::new(0, 0);
 // 9999-12-31T23:59:59.999999999Z
 const MAX: Duration = Duration::new(253402300799, 999999999);
 
+// 23:59
+const MAX_OFFSET_SECS: i32 = 86_400 - 60;
+
 impl Timestamp {
     /**
     The minimum timestamp, `1970-01-01T00:00:00Z`.
@@ -143,174 +156,202 @@ impl Timestamp {
     If any field of `parts` would overflow its maximum value, such as `days: 32`, then it will wrap into the next unit.
     */
     pub fn from_parts(parts: Parts) -> Option<Self> {
-        let is_leap;
-        let start_of_year;
-        let year = (parts.years as i64) - 1900;
-
-        // Fast path for years 1900 - 2038.
-        if year as u64 <= 138 {
-            let mut leaps: i64 = (year - 68) >> 2;
-            if (year - 68).trailing_zeros() >= 2 {
-                leaps -= 1;
-                is_leap = true;
-            } else {
-                is_leap = false;
-            }
+        Timestamp::from_unix(Duration::new(
+            unix_secs_from_parts(&parts).try_into().ok()?,
+            parts.nanos,
+        ))
+    }
+
+    /**
+    Get the individual date and time parts of the timestamp.
+
+    The returned parts are in exactly the form needed to display them. Months and days are both one-based.
+    */
+    pub fn to_parts(&self) -> Parts {
+        self.to_parts_with_offset(Offset::UTC)
+    }
+
+    /**
+    Get the individual date and time parts of the timestamp, shifted by a UTC offset.
+
+    The returned parts are the wall-clock date and time in a time zone with the given `offset`. They may fall outside of [`Timestamp::MIN`]..=[`Timestamp::MAX`], such as `1969-12-31T19:00:00` for [`Timestamp::MIN`] at `-05:00`.
+    */
+    pub fn to_parts_with_offset(&self, offset: Offset) -> Parts {
+        parts_from_unix_secs(
+            self.0.as_secs() as i64 + offset.as_secs() as i64,
+            self.0.subsec_nanos(),
+        )
+    }
 
-            start_of_year = i128::from(31_536_000 * (year - 70) + 86400 * leaps);
+    /**
+    Get a value that formats the timestamp in RFC3339 format, shifted by a UTC offset.
+
+    The result includes the offset, like `2024-01-01T10:00:00.000000000+10:00`. If the offset is [`Offset::UTC`] then it's formatted the same as the timestamp itself, with a trailing `Z`. If shifting the timestamp by the offset would put it after the year `9999`, which can only happen close to [`Timestamp::MAX`], then it's also formatted in UTC.
+    */
+    pub fn display_with_offset(&self, offset: Offset) -> DisplayWithOffset {
+        DisplayWithOffset { ts: *self, offset }
+    }
+
+    /**
+    Parse a timestamp from RFC3339 format, also returning the UTC offset it was written with.
+
+    The returned timestamp is in UTC. Formatting it with [`Timestamp::display_with_offset`] using the returned offset will produce an equivalent string.
+    */
+    pub fn parse_with_offset(s: &str) -> Result<(Self, Offset), ParseTimestampError> {
+        parse_rfc3339(s)
+    }
+}
+
+fn unix_secs_from_parts(parts: &Parts) -> i128 {
+    let is_leap;
+    let start_of_year;
+    let year = (parts.years as i64) - 1900;
+
+    // Fast path for years 1900 - 2038.
+    if year as u64 <= 138 {
+        let mut leaps: i64 = (year - 68) >> 2;
+        if (year - 68).trailing_zeros() >= 2 {
+            leaps -= 1;
+            is_leap = true;
         } else {
-            let centuries: i64;
-            let mut leaps: i64;
+            is_leap = false;
+        }
 
-            let mut cycles: i64 = (year - 100) / 400;
-            let mut rem: i64 = (year - 100) % 400;
+        start_of_year = i128::from(31_536_000 * (year - 70) + 86400 * leaps);
+    } else {
+        let centuries: i64;
+        let mut leaps: i64;
+
+        let mut cycles: i64 = (year - 100) / 400;
+        let mut rem: i64 = (year - 100) % 400;
 
-            if rem < 0 {
-                cycles -= 1;
-                rem += 400
+        if rem < 0 {
+            cycles -= 1;
+            rem += 400
+        }
+        if rem == 0 {
+            is_leap = true;
+            centuries = 0;
+            leaps = 0;
+        } else {
+            if rem >= 200 {
+                if rem >= 300 {
+                    centuries = 3;
+                    rem -= 300;
+                } else {
+                    centuries = 2;
+                    rem -= 200;
+                }
+            } else if rem >= 100 {
+                centuries = 1;
+                rem -= 100;
+            } else {
+                centuries = 0;
             }
             if rem == 0 {
-                is_leap = true;
-                centuries = 0;
+                is_leap = false;
                 leaps = 0;
             } else {
-                if rem >= 200 {
-                    if rem >= 300 {
-                        centuries = 3;
-                        rem -= 300;
-                    } else {
-                        centuries = 2;
-                        rem -= 200;
-                    }
-                } else if rem >= 100 {
-                    centuries = 1;
-                    rem -= 100;
-                } else {
-                    centuries = 0;
-                }
-                if rem == 0 {
-                    is_leap = false;
-                    leaps = 0;
-                } else {
-                    leaps = rem / 4;
-                    rem %= 4;
-                    is_leap = rem == 0;
-                }
+                leaps = rem / 4;
+                rem %= 4;
+                is_leap = rem == 0;
             }
-            leaps += 97 * cycles + 24 * centuries - i64::from(is_leap);
-
-            start_of_year = i128::from((year - 100) * 31_536_000)
-                + i128::from(leaps * 86400 + 946_684_800 + 86400);
         }
+        leaps += 97 * cycles + 24 * centuries - i64::from(is_leap);
 
-        let seconds_within_month = 86400 * u32::from(parts.days - 1)
-            + 3600 * u32::from(parts.hours)
-            + 60 * u32::from(parts.minutes)
-            + u32::from(parts.seconds);
-
-        let mut seconds_within_year = [
-            0,           // Jan
-            31 * 86400,  // Feb
-            59 * 86400,  // Mar
-            90 * 86400,  // Apr
-            120 * 86400, // May
-            151 * 86400, // Jun
-            181 * 86400, // Jul
-            212 * 86400, // Aug
-            243 * 86400, // Sep
-            273 * 86400, // Oct
-            304 * 86400, // Nov
-            334 * 86400, // Dec
-        ][usize::from(parts.months - 1)]
-            + seconds_within_month;
-
-        if is_leap && parts.months > 2 {
-            seconds_within_year += 86400
-        }
+        start_of_year =
+            i128::from((year - 100) * 31_536_000) + i128::from(leaps * 86400 + 946_684_800 + 86400);
+    }
 
-        Timestamp::from_unix(Duration::new(
-            (start_of_year + i128::from(seconds_within_year))
-                .try_into()
-                .ok()?,
-            parts.nanos,
-        ))
+    let seconds_within_month = 86400 * u32::from(parts.days - 1)
+        + 3600 * u32::from(parts.hours)
+        + 60 * u32::from(parts.minutes)
+        + u32::from(parts.seconds);
+
+    let mut seconds_within_year = [
+        0,           // Jan
+        31 * 86400,  // Feb
+        59 * 86400,  // Mar
+        90 * 86400,  // Apr
+        120 * 86400, // May
+        151 * 86400, // Jun
+        181 * 86400, // Jul
+        212 * 86400, // Aug
+        243 * 86400, // Sep
+        273 * 86400, // Oct
+        304 * 86400, // Nov
+        334 * 86400, // Dec
+    ][usize::from(parts.months - 1)]
+        + seconds_within_month;
+
+    if is_leap && parts.months > 2 {
+        seconds_within_year += 86400
     }
 
-    /**
-    Get the individual date and time parts of the timestamp.
+    start_of_year + i128::from(seconds_within_year)
+}
 
-    The returned parts are in exactly the form needed to display them. Months and days are both one-based.
-    */
-    pub fn to_parts(&self) -> Parts {
-        let dur = self.0;
-        let secs = dur.as_secs();
-        let nanos = dur.subsec_nanos();
-
-        // Note(dcb): this bit is rearranged slightly to avoid integer overflow.
-        let mut days = ((secs / 86_400) - (LEAPOCH_SECS / 86_400)) as i64;
-        let mut remsecs = (secs % 86_400) as i32;
-        if remsecs < 0i32 {
-            remsecs += 86_400;
-            days -= 1
-        }
+fn parts_from_unix_secs(secs: i64, nanos: u32) -> Parts {
+    // Note(dcb): this bit is rearranged slightly to avoid integer overflow.
+    let days = secs.div_euclid(86_400) - LEAPOCH_SECS / 86_400;
+    let remsecs = secs.rem_euclid(86_400) as i32;
 
-        let mut qc_cycles: i32 = (days / (DAYS_PER_400Y as i64)) as i32;
-        let mut remdays: i32 = (days % (DAYS_PER_400Y as i64)) as i32;
-        if remdays < 0 {
-            remdays += DAYS_PER_400Y;
-            qc_cycles -= 1;
-        }
+    let mut qc_cycles: i32 = (days / (DAYS_PER_400Y as i64)) as i32;
+    let mut remdays: i32 = (days % (DAYS_PER_400Y as i64)) as i32;
+    if remdays < 0 {
+        remdays += DAYS_PER_400Y;
+        qc_cycles -= 1;
+    }
 
-        let mut c_cycles: i32 = remdays / DAYS_PER_100Y;
-        if c_cycles == 4 {
-            c_cycles -= 1;
-        }
-        remdays -= c_cycles * DAYS_PER_100Y;
+    let mut c_cycles: i32 = remdays / DAYS_PER_100Y;
+    if c_cycles == 4 {
+        c_cycles -= 1;
+    }
+    remdays -= c_cycles * DAYS_PER_100Y;
 
-        let mut q_cycles: i32 = remdays / DAYS_PER_4Y;
-        if q_cycles == 25 {
-            q_cycles -= 1;
-        }
-        remdays -= q_cycles * DAYS_PER_4Y;
+    let mut q_cycles: i32 = remdays / DAYS_PER_4Y;
+    if q_cycles == 25 {
+        q_cycles -= 1;
+    }
+    remdays -= q_cycles * DAYS_PER_4Y;
 
-        let mut remyears: i32 = remdays / 365;
-        if remyears == 4 {
-            remyears -= 1;
-        }
-        remdays -= remyears * 365;
+    let mut remyears: i32 = remdays / 365;
+    if remyears == 4 {
+        remyears -= 1;
+    }
+    remdays -= remyears * 365;
 
-        let mut years: i64 = i64::from(remyears)
-            + 4 * i64::from(q_cycles)
-            + 100 * i64::from(c_cycles)
-            + 400 * i64::from(qc_cycles);
+    let mut years: i64 = i64::from(remyears)
+        + 4 * i64::from(q_cycles)
+        + 100 * i64::from(c_cycles)
+        + 400 * i64::from(qc_cycles);
 
-        let mut months: i32 = 0;
-        while i32::from(DAYS_IN_MONTH[months as usize]) <= remdays {
-            remdays -= i32::from(DAYS_IN_MONTH[months as usize]);
-            months += 1
-        }
+    let mut months: i32 = 0;
+    while i32::from(DAYS_IN_MONTH[months as usize]) <= remdays {
+        remdays -= i32::from(DAYS_IN_MONTH[months as usize]);
+        months += 1
+    }
 
-        if months >= 10 {
-            months -= 12;
-            years += 1;
-        }
+    if months >= 10 {
+        months -= 12;
+        years += 1;
+    }
 
-        let years = (years + 2000) as u16;
-        let months = (months + 3) as u8;
-        let days = (remdays + 1) as u8;
-        let hours = (remsecs / 3600) as u8;
-        let minutes = (remsecs / 60 % 60) as u8;
-        let seconds = (remsecs % 60) as u8;
-
-        Parts {
-            years,
-            months,
-            days,
-            hours,
-            minutes,
-            seconds,
-            nanos,
-        }
+    let years = (years + 2000) as u16;
+    let months = (months + 3) as u8;
+    let days = (remdays + 1) as u8;
+    let hours = (remsecs / 3600) as u8;
+    let minutes = (remsecs / 60 % 60) as u8;
+    let seconds = (remsecs % 60) as u8;
+
+    Parts {
+        years,
+        months,
+        days,
+        hours,
+        minutes,
+        seconds,
+        nanos,
     }
 }
 
@@ -353,14 +394,14 @@ impl fmt::Debug for Timestamp {
         use fmt::Write as _;
 
         f.write_char('"')?;
-        fmt_rfc3339(*self, f)?;
+        fmt_rfc3339(*self, Offset::UTC, f)?;
         f.write_char('"')
     }
 }
 
 impl fmt::Display for Timestamp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt_rfc3339(*self, f)
+        fmt_rfc3339(*self, Offset::UTC, f)
     }
 }
 
@@ -368,7 +409,7 @@ impl FromStr for Timestamp {
     type Err = ParseTimestampError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse_rfc3339(s)
+        parse_rfc3339(s).map(|(ts, _)| ts)
     }
 }
 
@@ -387,6 +428,103 @@ impl<'v> FromValue<'v> for Timestamp {
     }
 }
 
+/**
+The result of calling [`Timestamp::display_with_offset`].
+
+This type formats a timestamp in RFC3339 format with a UTC offset.
+*/
+#[derive(Clone, Copy)]
+pub struct DisplayWithOffset {
+    ts: Timestamp,
+    offset: Offset,
+}
+
+impl DisplayWithOffset {
+    /**
+    Get the underlying timestamp, which is always in UTC.
+    */
+    pub fn timestamp(&self) -> Timestamp {
+        self.ts
+    }
+
+    /**
+    Get the offset the timestamp is formatted with.
+    */
+    pub fn offset(&self) -> Offset {
+        self.offset
+    }
+}
+
+impl fmt::Debug for DisplayWithOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use fmt::Write as _;
+
+        f.write_char('"')?;
+        fmt_rfc3339(self.ts, self.offset, f)?;
+        f.write_char('"')
+    }
+}
+
+impl fmt::Display for DisplayWithOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_rfc3339(self.ts, self.offset, f)
+    }
+}
+
+impl ToValue for DisplayWithOffset {
+    fn to_value(&self) -> Value<'_> {
+        Value::capture_display(self)
+    }
+}
+
+impl Offset {
+    /**
+    The offset of UTC itself, `+00:00`.
+    */
+    pub const UTC: Self = Offset(0);
+
+    /**
+    Try create an offset from a number of seconds ahead of UTC.
+
+    Negative values are behind UTC. If `secs` is a whole number of minutes less than a day in either direction then this method will return `Some`. Otherwise it will return `None`.
+    */
+    pub const fn from_secs(secs: i32) -> Option<Self> {
+        if secs % 60 == 0 && secs >= -MAX_OFFSET_SECS && secs <= MAX_OFFSET_SECS {
+            Some(Offset(secs))
+        } else {
+            None
+        }
+    }
+
+    /**
+    Get the number of seconds this offset is ahead of UTC.
+
+    Negative values are behind UTC.
+    */
+    pub const fn as_secs(&self) -> i32 {
+        self.0
+    }
+}
+
+impl fmt::Debug for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use fmt::Write as _;
+
+        f.write_char('"')?;
+        fmt::Display::fmt(self, f)?;
+        f.write_char('"')
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minutes = self.0.unsigned_abs() / 60;
+        let sign = if self.0 < 0 { '-' } else { '+' };
+
+        write!(f, "{}{:>02}:{:>02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
 /**
 An error attempting to parse a [`Timestamp`] from text.
 */
@@ -402,29 +540,113 @@ impl fmt::Display for ParseTimestampError {
 #[cfg(feature = "std")]
 impl std::error::Error for ParseTimestampError {}
 
-fn parse_rfc3339(fmt: &str) -> Result<Timestamp, ParseTimestampError> {
-    if fmt.len() > 30 || fmt.len() < 19 {
-        unimplemented!("invalid len {}", fmt.len());
+fn parse_rfc3339(fmt: &str) -> Result<(Timestamp, Offset), ParseTimestampError> {
+    fn digits<T: From<u8> + core::ops::Mul<Output = T> + core::ops::Add<Output = T>>(
+        input: &[u8],
+    ) -> Result<T, ParseTimestampError> {
+        let mut value = T::from(0);
+
+        for b in input {
+            if !b.is_ascii_digit() {
+                return Err(ParseTimestampError {});
+            }
+
+            value = value * T::from(10) + T::from(b - b'0');
+        }
+
+        Ok(value)
     }
 
-    if *fmt.as_bytes().last().unwrap() != b'Z' {
-        unimplemented!("non-UTC")
+    fn expect(input: &[u8], i: usize, expected: &[u8]) -> Result<(), ParseTimestampError> {
+        if input.get(i).map(|b| expected.contains(b)).unwrap_or(false) {
+            Ok(())
+        } else {
+            Err(ParseTimestampError {})
+        }
     }
 
-    let years = u16::from_str_radix(&fmt[0..4], 10).unwrap();
-    let months = u8::from_str_radix(&fmt[5..7], 10).unwrap();
-    let days = u8::from_str_radix(&fmt[8..10], 10).unwrap();
-    let hours = u8::from_str_radix(&fmt[11..13], 10).unwrap();
-    let minutes = u8::from_str_radix(&fmt[14..16], 10).unwrap();
-    let seconds = u8::from_str_radix(&fmt[17..19], 10).unwrap();
-    let nanos = if fmt.len() > 19 {
-        let subsecond = &fmt[20..fmt.len() - 1];
-        u32::from_str_radix(subsecond, 10).unwrap() * 10u32.pow(9 - subsecond.len() as u32)
+    let input = fmt.as_bytes();
+
+    // 0000-00-00T00:00:00
+    if input.len() < 20 {
+        return Err(ParseTimestampError {});
+    }
+
+    expect(input, 4, b"-")?;
+    expect(input, 7, b"-")?;
+    expect(input, 10, b"Tt ")?;
+    expect(input, 13, b":")?;
+    expect(input, 16, b":")?;
+
+    let years: u16 = digits(&input[0..4])?;
+    let months: u8 = digits(&input[5..7])?;
+    let days: u8 = digits(&input[8..10])?;
+    let hours: u8 = digits(&input[11..13])?;
+    let minutes: u8 = digits(&input[14..16])?;
+    let seconds: u8 = digits(&input[17..19])?;
+
+    let is_leap = matches!(
+        (years % 4, years % 100, years % 400),
+        (_, _, 0) | (0, 1.., _)
+    );
+    let days_in_month = match months {
+        2 if is_leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+
+    // Leap seconds are accepted, and roll into the next minute
+    if !(1..=12).contains(&months)
+        || !(1..=days_in_month).contains(&days)
+        || hours > 23
+        || minutes > 59
+        || seconds > 60
+    {
+        return Err(ParseTimestampError {});
+    }
+
+    let mut i = 19;
+
+    let nanos = if input[i] == b'.' {
+        i += 1;
+
+        let start = i;
+        while i < input.len() && input[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        // Precision beyond nanoseconds is truncated
+        let subsecond = &input[start..cmp::min(i, start + 9)];
+        if subsecond.is_empty() {
+            return Err(ParseTimestampError {});
+        }
+
+        digits::<u32>(subsecond)? * 10u32.pow(9 - subsecond.len() as u32)
     } else {
         0
     };
 
-    Timestamp::from_parts(Parts {
+    let offset = match &input[i..] {
+        b"Z" | b"z" => Offset::UTC,
+        [sign @ (b'+' | b'-'), offset @ ..] if offset.len() == 5 => {
+            expect(offset, 2, b":")?;
+
+            let hours: u8 = digits(&offset[0..2])?;
+            let minutes: u8 = digits(&offset[3..5])?;
+
+            if hours > 23 || minutes > 59 {
+                return Err(ParseTimestampError {});
+            }
+
+            let secs = hours as i32 * 3600 + minutes as i32 * 60;
+
+            Offset(if *sign == b'-' { -secs } else { secs })
+        }
+        _ => return Err(ParseTimestampError {}),
+    };
+
+    let secs = unix_secs_from_parts(&Parts {
         years,
         months,
         days,
@@ -432,11 +654,24 @@ fn parse_rfc3339(fmt: &str) -> Result<Timestamp, ParseTimestampError> {
         minutes,
         seconds,
         nanos,
-    })
-    .ok_or_else(|| ParseTimestampError {})
+    }) - offset.as_secs() as i128;
+
+    let ts = Timestamp::from_unix(Duration::new(
+        secs.try_into().map_err(|_| ParseTimestampError {})?,
+        nanos,
+    ))
+    .ok_or_else(|| ParseTimestampError {})?;
+
+    Ok((ts, offset))
 }
 
-fn fmt_rfc3339(ts: Timestamp, f: &mut fmt::Formatter) -> fmt::Result {
+fn fmt_rfc3339(ts: Timestamp, mut offset: Offset, f: &mut fmt::Formatter) -> fmt::Result {
+    // Timestamps near `Timestamp::MAX` may fall outside of four-digit years
+    // when shifted forwards, so they're written in UTC instead
+    if ts.to_parts_with_offset(offset).years > 9999 {
+        offset = Offset::UTC;
+    }
+
     let Parts {
         years,
         months,
@@ -445,11 +680,11 @@ fn fmt_rfc3339(ts: Timestamp, f: &mut fmt::Formatter) -> fmt::Result {
         minutes,
         seconds,
         nanos: subsecond_nanos,
-    } = ts.to_parts();
+    } = ts.to_parts_with_offset(offset);
 
-    const BUF_INIT: [u8; 30] = *b"0000-00-00T00:00:00.000000000Z";
+    const BUF_INIT: [u8; 35] = *b"0000-00-00T00:00:00.000000000+00:00";
 
-    let mut buf: [u8; 30] = BUF_INIT;
+    let mut buf: [u8; 35] = BUF_INIT;
     buf[0] = b'0' + (years / 1000) as u8;
     buf[1] = b'0' + (years / 100 % 10) as u8;
     buf[2] = b'0' + (years / 10 % 10) as u8;
@@ -465,7 +700,7 @@ fn fmt_rfc3339(ts: Timestamp, f: &mut fmt::Formatter) -> fmt::Result {
     buf[17] = b'0' + (seconds / 10) as u8;
     buf[18] = b'0' + (seconds % 10) as u8;
 
-    let i = match f.precision() {
+    let mut i = match f.precision() {
         Some(0) => 19,
         precision => {
             let mut i = 20;
@@ -483,7 +718,21 @@ fn fmt_rfc3339(ts: Timestamp, f: &mut fmt::Formatter) -> fmt::Result {
         }
     };
 
-    buf[i] = b'Z';
+    if offset == Offset::UTC {
+        buf[i] = b'Z';
+    } else {
+        let offset_secs = offset.as_secs();
+        let offset_minutes = offset_secs.unsigned_abs() / 60;
+
+        buf[i] = if offset_secs < 0 { b'-' } else { b'+' };
+        buf[i + 1] = b'0' + (offset_minutes / 600) as u8;
+        buf[i + 2] = b'0' + (offset_minutes / 60 % 10) as u8;
+        buf[i + 3] = b':';
+        buf[i + 4] = b'0' + (offset_minutes % 60 / 10) as u8;
+        buf[i + 5] = b'0' + (offset_minutes % 10) as u8;
+
+        i += 5;
+    }
 
     // we know our chars are all ascii
     f.write_str(str::from_utf8(&buf[..=i]).expect("Conversion to utf8 failed"))
@@ -535,4 +784,111 @@ mod tests {
 
         assert_eq!(ts.to_unix(), MIN);
     }
+
+    #[test]
+    fn timestamp_offset_roundtrip() {
+        let ts = Timestamp::from_unix(Duration::new(1691961703, 17532)).unwrap();
+
+        for (offset, expected) in [
+            (0, "2023-08-13T21:21:43.000017532Z"),
+            (10 * 3600, "2023-08-14T07:21:43.000017532+10:00"),
+            (-(5 * 3600 + 30 * 60), "2023-08-13T15:51:43.000017532-05:30"),
+        ] {
+            let offset = Offset::from_secs(offset).unwrap();
+
+            let fmt = ts.display_with_offset(offset).to_string();
+            assert_eq!(expected, fmt);
+
+            let (parsed, parsed_offset) = Timestamp::parse_with_offset(&fmt).unwrap();
+            assert_eq!(ts, parsed, "{}", fmt);
+            assert_eq!(offset, parsed_offset, "{}", fmt);
+        }
+    }
+
+    #[test]
+    fn timestamp_offset_out_of_range() {
+        let offset = Offset::from_secs(10 * 3600).unwrap();
+
+        assert_eq!(
+            "9999-12-31T23:59:59.999999999Z",
+            Timestamp::MAX.display_with_offset(offset).to_string()
+        );
+
+        assert_eq!(
+            "1969-12-31T19:00:00.000000000-05:00",
+            Timestamp::MIN
+                .display_with_offset(Offset::from_secs(-5 * 3600).unwrap())
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn timestamp_parts_with_offset() {
+        let parts = Timestamp::MIN.to_parts_with_offset(Offset::from_secs(-5 * 3600).unwrap());
+
+        assert_eq!(
+            Parts {
+                years: 1969,
+                months: 12,
+                days: 31,
+                hours: 19,
+                minutes: 0,
+                seconds: 0,
+                nanos: 0,
+            },
+            parts
+        );
+    }
+
+    #[test]
+    fn timestamp_parse() {
+        for (input, expected) in [
+            ("2023-08-13T21:21:43Z", Some(Duration::new(1691961703, 0))),
+            (
+                "2023-08-13t21:21:43.5z",
+                Some(Duration::new(1691961703, 500_000_000)),
+            ),
+            (
+                "2023-08-13 21:21:43+00:00",
+                Some(Duration::new(1691961703, 0)),
+            ),
+            (
+                "2023-08-14T07:21:43+10:00",
+                Some(Duration::new(1691961703, 0)),
+            ),
+            ("1969-12-31T19:00:00-05:00", Some(Duration::new(0, 0))),
+            (
+                "2023-08-13T21:21:43.0000000001Z",
+                Some(Duration::new(1691961703, 0)),
+            ),
+            ("1969-12-31T23:59:59Z", None),
+            ("2023-08-13T21:21:43", None),
+            ("2023-08-13T21:21:43.Z", None),
+            ("2023-08-13T21:21:43+1000", None),
+            ("2023-02-29T21:21:43Z", None),
+            ("2023-13-13T21:21:43Z", None),
+            ("2023-08-13T24:21:43Z", None),
+            ("2023-08-13T21:21:43+24:00", None),
+            ("2023-08-1aT21:21:43Z", None),
+            ("", None),
+        ] {
+            assert_eq!(
+                expected,
+                input.parse::<Timestamp>().ok().map(|ts| ts.to_unix()),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn offset_from_secs() {
+        assert_eq!("+00:00", Offset::UTC.to_string());
+        assert_eq!(
+            "-09:30",
+            Offset::from_secs(-(9 * 3600 + 1800)).unwrap().to_string()
+        );
+
+        assert!(Offset::from_secs(86_400).is_none());
+        assert!(Offset::from_secs(30).is_none());
+    }
 }
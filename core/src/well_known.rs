@@ -18,6 +18,9 @@ Components can use the presence of well-known properties to change the way they
         - [`LVL_ERROR`]: An erroneous event.
     - [`KEY_ERR`]: A [`std::error::Error`] associated with the event.
 
+- Sampling:
+    - [`KEY_SAMPLE_RATE`]: The number of events a sampled event represents.
+
 Extensions to the data model are signaled by the well-known [`KEY_EVENT_KIND`] property.
 
 - Tracing [`KEY_EVENT_KIND`] = [`EVENT_KIND_SPAN`]:
@@ -25,6 +28,29 @@ Extensions to the data model are signaled by the well-known [`KEY_EVENT_KIND`] p
     - [`KEY_TRACE_ID`]: The trace id.
     - [`KEY_SPAN_ID`]: The span id.
     - [`KEY_SPAN_PARENT`]: The parent span id.
+    - [`KEY_SPAN_KIND`]: The relationship of the span to other spans in the trace.
+        - [`SPAN_KIND_INTERNAL`]: The span is an internal operation.
+        - [`SPAN_KIND_SERVER`]: The span handles an incoming request.
+        - [`SPAN_KIND_CLIENT`]: The span makes an outgoing request.
+        - [`SPAN_KIND_PRODUCER`]: The span sends a message to a broker.
+        - [`SPAN_KIND_CONSUMER`]: The span receives a message from a broker.
+    - [`KEY_SPAN_STATUS`]: Whether the operation the span represents succeeded.
+        - [`SPAN_STATUS_OK`]: The operation succeeded.
+        - [`SPAN_STATUS_ERROR`]: The operation failed.
+    - [`KEY_SPAN_STATUS_DESCRIPTION`]: A description of the span status.
+    - [`KEY_SPAN_LINKS`]: Links from the span to spans in other traces.
+    - [`KEY_TRACE_FLAGS`]: The W3C trace flags, like whether the trace is sampled.
+    - [`KEY_TRACE_STATE`]: The W3C tracestate carrying vendor-specific trace data.
+
+- Span beginnings [`KEY_EVENT_KIND`] = [`EVENT_KIND_SPAN_BEGIN`]:
+    - [`KEY_SPAN_NAME`]: The informative name of the span.
+    - [`KEY_TRACE_ID`]: The trace id of the span that began.
+    - [`KEY_SPAN_ID`]: The span id of the span that began.
+    - [`KEY_SPAN_PARENT`]: The parent span id of the span that began.
+
+- Span events [`KEY_EVENT_KIND`] = [`EVENT_KIND_SPAN_EVENT`]:
+    - [`KEY_TRACE_ID`]: The trace id of the span the event belongs to.
+    - [`KEY_SPAN_ID`]: The span id of the span the event belongs to.
 
 - Metrics [`KEY_EVENT_KIND`] = [`EVENT_KIND_METRIC`]:
     - [`KEY_METRIC_NAME`]: The name of the underlying data source.
@@ -56,6 +82,10 @@ pub const KEY_EVENT_KIND: &'static str = "event_kind";
 pub const EVENT_KIND_SPAN: &'static str = "span";
 /** The event is a metric sample. */
 pub const EVENT_KIND_METRIC: &'static str = "metric";
+/** The event is a timestamped annotation on a span in a distributed trace. */
+pub const EVENT_KIND_SPAN_EVENT: &'static str = "span_event";
+/** The event marks the beginning of a span in a distributed trace. */
+pub const EVENT_KIND_SPAN_BEGIN: &'static str = "span_begin";
 
 // Log
 /** A severity level to categorize the event by. */
@@ -74,6 +104,10 @@ pub const LVL_ERROR: &'static str = "error";
 /**  A [`std::error::Error`] associated with the event. */
 pub const KEY_ERR: &'static str = "err";
 
+// Sampling
+/** The number of events a sampled event represents. */
+pub const KEY_SAMPLE_RATE: &'static str = "sample_rate";
+
 // Trace
 /** The informative name of the span. */
 pub const KEY_SPAN_NAME: &'static str = "span_name";
@@ -83,6 +117,34 @@ pub const KEY_TRACE_ID: &'static str = "trace_id";
 pub const KEY_SPAN_ID: &'static str = "span_id";
 /** The parent span id. */
 pub const KEY_SPAN_PARENT: &'static str = "span_parent";
+/** The relationship of the span to other spans in the trace. */
+pub const KEY_SPAN_KIND: &'static str = "span_kind";
+/** Whether the operation the span represents succeeded. */
+pub const KEY_SPAN_STATUS: &'static str = "span_status";
+/** A description of the span status. */
+pub const KEY_SPAN_STATUS_DESCRIPTION: &'static str = "span_status_description";
+/** Links from the span to spans in other traces. */
+pub const KEY_SPAN_LINKS: &'static str = "span_links";
+/** The W3C trace flags, like whether the trace is sampled. */
+pub const KEY_TRACE_FLAGS: &'static str = "trace_flags";
+/** The W3C tracestate carrying vendor-specific trace data. */
+pub const KEY_TRACE_STATE: &'static str = "trace_state";
+
+/** The span is an internal operation. */
+pub const SPAN_KIND_INTERNAL: &'static str = "internal";
+/** The span handles an incoming request. */
+pub const SPAN_KIND_SERVER: &'static str = "server";
+/** The span makes an outgoing request. */
+pub const SPAN_KIND_CLIENT: &'static str = "client";
+/** The span sends a message to a broker. */
+pub const SPAN_KIND_PRODUCER: &'static str = "producer";
+/** The span receives a message from a broker. */
+pub const SPAN_KIND_CONSUMER: &'static str = "consumer";
+
+/** The operation succeeded. */
+pub const SPAN_STATUS_OK: &'static str = "ok";
+/** The operation failed. */
+pub const SPAN_STATUS_ERROR: &'static str = "error";
 
 // Metric
 /** The name of the underlying data source. */
@@ -104,3 +166,37 @@ pub const METRIC_AGG_MIN: &'static str = "min";
 pub const METRIC_AGG_MAX: &'static str = "max";
 /** The sample is the last or most recent value. */
 pub const METRIC_AGG_LAST: &'static str = "last";
+
+/**
+Whether `key` is one of the well-known property keys defined in this module.
+
+Components that rewrite or import properties from outside sources can use this to avoid clobbering properties that other components interpret.
+*/
+pub fn is_well_known(key: &str) -> bool {
+    matches!(
+        key,
+        KEY_MODULE
+            | KEY_TS
+            | KEY_TS_START
+            | KEY_TPL
+            | KEY_MSG
+            | KEY_EVENT_KIND
+            | KEY_LVL
+            | KEY_ERR
+            | KEY_SAMPLE_RATE
+            | KEY_SPAN_NAME
+            | KEY_TRACE_ID
+            | KEY_SPAN_ID
+            | KEY_SPAN_PARENT
+            | KEY_SPAN_KIND
+            | KEY_SPAN_STATUS
+            | KEY_SPAN_STATUS_DESCRIPTION
+            | KEY_SPAN_LINKS
+            | KEY_TRACE_FLAGS
+            | KEY_TRACE_STATE
+            | KEY_METRIC_NAME
+            | KEY_METRIC_AGG
+            | KEY_METRIC_VALUE
+            | KEY_METRIC_UNIT
+    )
+}
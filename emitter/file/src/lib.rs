@@ -6,7 +6,7 @@ All file IO is performed on batches in a dedicated background thread.
 This library writes newline delimited JSON by default, like:
 
 ```text
-{"ts_start":"2024-05-29T03:35:13.922768000Z","ts":"2024-05-29T03:35:13.943506000Z","msg":"in_ctxt failed with `a` is odd","tpl":"in_ctxt failed with `err`","a":1,"err":"`a` is odd","lvl":"warn","span_id":"0a3686d1b788b277","span_parent":"1a50b58f2ef93f3b","trace_id":"8dd5d1f11af6ba1db4124072024933cb"}
+{"ts_start":"2024-05-29T03:35:13.922768000Z","ts":"2024-05-29T03:35:13.943506000Z","msg":"in_ctxt failed with `a` is odd","tpl":"in_ctxt failed with {err}","a":1,"err":"`a` is odd","lvl":"warn","span_id":"0a3686d1b788b277","span_parent":"1a50b58f2ef93f3b","trace_id":"8dd5d1f11af6ba1db4124072024933cb"}
 ```
 
 # Getting started
@@ -190,14 +190,18 @@ pub struct FileSetBuilder {
     max_files: usize,
     max_file_size_bytes: usize,
     reuse_files: bool,
-    writer: Box<
-        dyn Fn(&mut FileBuf, &emit::Event<&dyn emit::props::ErasedProps>) -> io::Result<()>
-            + Send
-            + Sync,
-    >,
+    #[cfg(feature = "default_writer")]
+    local_time: bool,
+    writer: Option<Writer>,
     separator: &'static [u8],
 }
 
+type Writer = Box<
+    dyn Fn(&mut FileBuf, &emit::Event<&dyn emit::props::ErasedProps>) -> io::Result<()>
+        + Send
+        + Sync,
+>;
+
 #[derive(Debug, Clone, Copy)]
 enum RollBy {
     Day,
@@ -209,6 +213,8 @@ const DEFAULT_ROLL_BY: RollBy = RollBy::Hour;
 const DEFAULT_MAX_FILES: usize = 32;
 const DEFAULT_MAX_FILE_SIZE_BYTES: usize = 1024 * 1024 * 1024; // 1GiB
 const DEFAULT_REUSE_FILES: bool = false;
+#[cfg(feature = "default_writer")]
+const DEFAULT_LOCAL_TIME: bool = false;
 
 impl FileSetBuilder {
     /**
@@ -224,7 +230,7 @@ impl FileSetBuilder {
     */
     #[cfg(feature = "default_writer")]
     pub fn new(file_set: impl Into<PathBuf>) -> Self {
-        Self::new_with_writer(file_set, default_writer, b"\n")
+        Self::new_with_optional_writer(file_set.into(), None, b"\n")
     }
 
     /**
@@ -249,14 +255,24 @@ impl FileSetBuilder {
             + Sync
             + 'static,
         separator: &'static [u8],
+    ) -> Self {
+        Self::new_with_optional_writer(file_set.into(), Some(Box::new(writer)), separator)
+    }
+
+    fn new_with_optional_writer(
+        file_set: PathBuf,
+        writer: Option<Writer>,
+        separator: &'static [u8],
     ) -> Self {
         FileSetBuilder {
-            file_set: file_set.into(),
+            file_set,
             roll_by: DEFAULT_ROLL_BY,
             max_files: DEFAULT_MAX_FILES,
             max_file_size_bytes: DEFAULT_MAX_FILE_SIZE_BYTES,
             reuse_files: DEFAULT_REUSE_FILES,
-            writer: Box::new(writer),
+            #[cfg(feature = "default_writer")]
+            local_time: DEFAULT_LOCAL_TIME,
+            writer,
             separator,
         }
     }
@@ -317,6 +333,21 @@ impl FileSetBuilder {
         self
     }
 
+    /**
+    Whether to write timestamps with the offset of the system's local time zone.
+
+    By default, timestamps are written in UTC, like `2024-05-29T03:35:13.922768000Z`. If `local_time` is true then timestamps are written with the local offset instead, like `2024-05-29T13:35:13.922768000+10:00`.
+
+    The local offset is determined once through [`emit::platform::local_offset`] when [`FileSetBuilder::spawn`] is called, so spawn the file set early, like at the start of `main` before any other threads are spawned. If the local offset can't be determined then timestamps are written in UTC.
+
+    This option only applies to the default newline-delimited JSON format. It has no effect when a custom writer is used.
+    */
+    #[cfg(feature = "default_writer")]
+    pub fn local_time(mut self, local_time: bool) -> Self {
+        self.local_time = local_time;
+        self
+    }
+
     /**
     Specify a writer for incoming [`emit::Event`]s.
 
@@ -332,7 +363,7 @@ impl FileSetBuilder {
             + 'static,
         separator: &'static [u8],
     ) -> Self {
-        self.writer = Box::new(writer);
+        self.writer = Some(Box::new(writer));
         self.separator = separator;
         self
     }
@@ -343,6 +374,27 @@ impl FileSetBuilder {
     pub fn spawn(self) -> Result<FileSet, Error> {
         let (dir, file_prefix, file_ext) = dir_prefix_ext(self.file_set).map_err(Error::new)?;
 
+        let writer = match self.writer {
+            Some(writer) => writer,
+            #[cfg(feature = "default_writer")]
+            None => {
+                // The local offset is resolved here, before the worker thread is spawned
+                let offset = if self.local_time {
+                    emit::platform::local_offset().unwrap_or(emit::timestamp::Offset::UTC)
+                } else {
+                    emit::timestamp::Offset::UTC
+                };
+
+                Box::new(
+                    move |buf: &mut FileBuf, evt: &emit::Event<&dyn emit::props::ErasedProps>| {
+                        default_writer(buf, evt, offset)
+                    },
+                )
+            }
+            #[cfg(not(feature = "default_writer"))]
+            None => unreachable!("a writer is always set without the default writer"),
+        };
+
         let metrics = Arc::new(InternalMetrics::default());
 
         let mut worker = Worker::new(
@@ -366,7 +418,7 @@ impl FileSetBuilder {
         Ok(FileSet {
             sender,
             metrics,
-            writer: self.writer,
+            writer,
             separator: self.separator,
             _handle: handle,
         })
@@ -381,11 +433,7 @@ Create a file set through the [`set`] function, calling [`FileSetBuilder::spawn`
 pub struct FileSet {
     sender: emit_batcher::Sender<EventBatch>,
     metrics: Arc<InternalMetrics>,
-    writer: Box<
-        dyn Fn(&mut FileBuf, &emit::Event<&dyn emit::props::ErasedProps>) -> io::Result<()>
-            + Send
-            + Sync,
-    >,
+    writer: Writer,
     separator: &'static [u8],
     _handle: thread::JoinHandle<()>,
 }
@@ -481,6 +529,7 @@ impl io::Write for FileBuf {
 fn default_writer(
     buf: &mut FileBuf,
     evt: &emit::Event<&dyn emit::props::ErasedProps>,
+    offset: emit::timestamp::Offset,
 ) -> io::Result<()> {
     use std::ops::ControlFlow;
 
@@ -489,7 +538,16 @@ fn default_writer(
         Props as _,
     };
 
-    struct EventValue<'a, P>(&'a emit::Event<'a, P>);
+    struct EventValue<'a, P> {
+        evt: &'a emit::Event<'a, P>,
+        offset: emit::timestamp::Offset,
+    }
+
+    impl<'a, P> EventValue<'a, P> {
+        fn ts(&self, ts: emit::Timestamp) -> emit::timestamp::DisplayWithOffset {
+            ts.display_with_offset(self.offset)
+        }
+    }
 
     impl<'a, P: emit::Props> sval::Value for EventValue<'a, P> {
         fn stream<'sval, S: sval::Stream<'sval> + ?Sized>(
@@ -498,29 +556,29 @@ fn default_writer(
         ) -> sval::Result {
             stream.record_begin(None, None, None, None)?;
 
-            if let Some(extent) = self.0.extent() {
+            if let Some(extent) = self.evt.extent() {
                 let range = extent.as_range();
 
                 if range.end != range.start {
                     stream.record_value_begin(None, &sval::Label::new(KEY_TS_START))?;
-                    sval::stream_display(&mut *stream, &range.start)?;
+                    sval::stream_display(&mut *stream, self.ts(range.start))?;
                     stream.record_value_end(None, &sval::Label::new(KEY_TS_START))?;
                 }
 
                 stream.record_value_begin(None, &sval::Label::new(KEY_TS))?;
-                sval::stream_display(&mut *stream, &range.end)?;
+                sval::stream_display(&mut *stream, self.ts(range.end))?;
                 stream.record_value_end(None, &sval::Label::new(KEY_TS))?;
             }
 
             stream.record_value_begin(None, &sval::Label::new(KEY_MSG))?;
-            sval::stream_display(&mut *stream, self.0.msg())?;
+            sval::stream_display(&mut *stream, self.evt.msg())?;
             stream.record_value_end(None, &sval::Label::new(KEY_MSG))?;
 
             stream.record_value_begin(None, &sval::Label::new(KEY_TPL))?;
-            sval::stream_display(&mut *stream, self.0.tpl())?;
+            sval::stream_display(&mut *stream, self.evt.tpl().render(emit::Empty).braced())?;
             stream.record_value_end(None, &sval::Label::new(KEY_TPL))?;
 
-            self.0.props().dedup().for_each(|k, v| {
+            self.evt.props().dedup().for_each(|k, v| {
                 match (|| {
                     stream.record_value_begin(None, &sval::Label::new_computed(k.get()))?;
                     stream.value_computed(&v)?;
@@ -537,7 +595,7 @@ fn default_writer(
         }
     }
 
-    sval_json::stream_to_io_write(buf, EventValue(evt))
+    sval_json::stream_to_io_write(buf, EventValue { evt, offset })
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
 
     Ok(())
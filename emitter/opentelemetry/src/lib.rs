@@ -33,11 +33,13 @@ fn main() {
 
 Both the `emitter` and `ctxt` values must be set in order for `emit` to integrate with the OpenTelemetry SDK properly.
 
-Diagnostic events produced by the [`macro@emit::span`] macro are sent to an [`opentelemetry::global::tracer`] as an [`opentelemetry::trace::Span`] on completion. All other emitted events are sent to an [`opentelemetry::global::logger`] as [`opentelemetry::logs::LogRecord`]s.
+Diagnostic events produced by the [`macro@emit::span`] macro are sent to an [`opentelemetry::global::tracer`] as an [`opentelemetry::trace::Span`] on completion. Events carrying an [`emit::Kind::SpanEvent`] are added as events to the span they were emitted inside of. Events carrying an [`emit::Kind::SpanBegin`] are ignored, since spans are started in the OpenTelemetry SDK when their context is pushed. All other emitted events are sent to an [`opentelemetry::global::logger`] as [`opentelemetry::logs::LogRecord`]s.
 
 # Limitations
 
 This library doesn't support `emit`'s metrics as OpenTelemetry metrics. Any metric samples produced by `emit` will be emitted as log records.
+
+The OpenTelemetry SDK needs to know the kind and links of a span when it starts. The [`emit::well_known::KEY_SPAN_KIND`] and [`emit::well_known::KEY_SPAN_LINKS`] properties are only used if they're present in the properties a span's context is pushed with, like through [`emit::Span::push_ctxt`]. The `kind` and `links` control parameters of the [`macro@emit::span`] macro are only added to the span's own events, so they aren't seen by the OpenTelemetry SDK. To set the kind or links of a span in the SDK, push them with the span's context through [`emit::Span::push_ctxt`] instead.
 */
 
 #![doc(html_logo_url = "https://raw.githubusercontent.com/KodrAus/emit/main/asset/logo.svg")]
@@ -50,8 +52,9 @@ use emit::{
     str::ToStr,
     value::ToValue,
     well_known::{
-        KEY_ERR, KEY_EVENT_KIND, KEY_LVL, KEY_SPAN_ID, KEY_SPAN_NAME, KEY_SPAN_PARENT,
-        KEY_TRACE_ID, LVL_DEBUG, LVL_ERROR, LVL_INFO, LVL_WARN,
+        KEY_ERR, KEY_EVENT_KIND, KEY_LVL, KEY_SPAN_ID, KEY_SPAN_KIND, KEY_SPAN_LINKS,
+        KEY_SPAN_NAME, KEY_SPAN_PARENT, KEY_SPAN_STATUS, KEY_SPAN_STATUS_DESCRIPTION,
+        KEY_TRACE_FLAGS, KEY_TRACE_ID, KEY_TRACE_STATE, LVL_DEBUG, LVL_ERROR, LVL_INFO, LVL_WARN,
     },
     Filter, Props,
 };
@@ -60,7 +63,8 @@ use opentelemetry::{
     global::{self, BoxedTracer, GlobalLoggerProvider},
     logs::{AnyValue, LogRecord, Logger, LoggerProvider, Severity},
     trace::{
-        SpanContext, SpanId, Status, TraceContextExt, TraceFlags, TraceId, TraceState, Tracer,
+        Link, SpanContext, SpanId, SpanKind, Status, TraceContextExt, TraceFlags, TraceId,
+        TraceState, Tracer,
     },
     Context, ContextGuard, Key, KeyValue, Value,
 };
@@ -275,6 +279,44 @@ impl<C: emit::Ctxt> emit::Ctxt for OpenTelemetryCtxt<C> {
 
                 let mut span = self.tracer.span_builder("emit_span").with_span_id(span_id);
 
+                if let Some(kind) = props.pull::<emit::span::SpanKind, _>(KEY_SPAN_KIND) {
+                    span = span.with_kind(match kind {
+                        emit::span::SpanKind::Internal => SpanKind::Internal,
+                        emit::span::SpanKind::Server => SpanKind::Server,
+                        emit::span::SpanKind::Client => SpanKind::Client,
+                        emit::span::SpanKind::Producer => SpanKind::Producer,
+                        emit::span::SpanKind::Consumer => SpanKind::Consumer,
+                    });
+                }
+
+                if let Some(links) = props.pull::<emit::span::SpanLinks, _>(KEY_SPAN_LINKS) {
+                    span = span.with_links(
+                        links
+                            .iter()
+                            .map(|(link, attributes)| {
+                                Link::new(
+                                    SpanContext::new(
+                                        otel_trace_id(*link.trace_id()),
+                                        otel_span_id(*link.span_id()),
+                                        TraceFlags::default(),
+                                        true,
+                                        TraceState::NONE,
+                                    ),
+                                    attributes
+                                        .iter()
+                                        .filter_map(|(k, v)| {
+                                            Some(KeyValue::new(
+                                                k.to_cow(),
+                                                otel_span_value(v.by_ref())?,
+                                            ))
+                                        })
+                                        .collect(),
+                                )
+                            })
+                            .collect(),
+                    );
+                }
+
                 if let Some(trace_id) = trace_id {
                     span = span.with_trace_id(trace_id);
                 }
@@ -459,6 +501,12 @@ impl emit::Emitter for OpenTelemetryEmitter {
     fn emit<E: emit::event::ToEvent>(&self, evt: E) {
         let evt = evt.to_event();
 
+        // Spans are started in the OpenTelemetry SDK when their context is pushed,
+        // so span begin events are ignored instead of being emitted as log records
+        if emit::kind::is_span_begin_filter().matches(&evt) {
+            return;
+        }
+
         // If the event is for a span then attempt to end it
         // The typical case is the span was created through `#[emit::span]`
         // and so is the currently active frame. If it isn't the active frame
@@ -489,13 +537,24 @@ impl emit::Emitter for OpenTelemetryEmitter {
 
                         span.update_name(name);
 
-                        evt.props().for_each(|k, v| {
-                            if k == KEY_LVL {
-                                if let Some(emit::Level::Error) = v.by_ref().cast::<emit::Level>() {
-                                    span.set_status(Status::error("error"));
+                        match emit::span::SpanStatus::from_props(evt.props()) {
+                            Some(emit::span::SpanStatus::Ok) => span.set_status(Status::Ok),
+                            Some(emit::span::SpanStatus::Error) => {
+                                let description = evt
+                                    .props()
+                                    .get(KEY_SPAN_STATUS_DESCRIPTION)
+                                    .or_else(|| evt.props().get(KEY_ERR))
+                                    .map(|description| description.to_string())
+                                    .unwrap_or_default();
+
+                                span.set_status(Status::error(description));
+                            }
+                            None => (),
+                        }
 
-                                    return ControlFlow::Continue(());
-                                }
+                        evt.props().for_each(|k, v| {
+                            if k == KEY_SPAN_STATUS || k == KEY_SPAN_STATUS_DESCRIPTION {
+                                return ControlFlow::Continue(());
                             }
 
                             if k == KEY_ERR {
@@ -511,7 +570,11 @@ impl emit::Emitter for OpenTelemetryEmitter {
                                 || k == KEY_SPAN_ID
                                 || k == KEY_SPAN_PARENT
                                 || k == KEY_SPAN_NAME
+                                || k == KEY_SPAN_KIND
+                                || k == KEY_SPAN_LINKS
                                 || k == KEY_EVENT_KIND
+                                || k == KEY_TRACE_FLAGS
+                                || k == KEY_TRACE_STATE
                             {
                                 return ControlFlow::Continue(());
                             }
@@ -542,6 +605,60 @@ impl emit::Emitter for OpenTelemetryEmitter {
             }
         }
 
+        // If the event is a span event then attempt to add it to its span
+        // This only works if the span is the currently active one
+        if emit::kind::is_span_event_filter().matches(&evt) {
+            let ctxt = Context::current();
+            let span = ctxt.span();
+
+            let span_id = span.span_context().span_id();
+
+            let evt_span_id = evt
+                .props()
+                .pull::<emit::span::SpanId, _>(KEY_SPAN_ID)
+                .map(otel_span_id);
+
+            if Some(span_id) == evt_span_id {
+                let name = format!(
+                    "{}",
+                    MessageRenderer {
+                        fmt: &self.log_body,
+                        evt: &evt,
+                    }
+                );
+
+                let mut attributes = Vec::new();
+                let _ = evt.props().for_each(|k, v| {
+                    if k == KEY_TRACE_ID
+                        || k == KEY_SPAN_ID
+                        || k == KEY_SPAN_PARENT
+                        || k == KEY_EVENT_KIND
+                        || k == KEY_TRACE_FLAGS
+                        || k == KEY_TRACE_STATE
+                    {
+                        return ControlFlow::Continue(());
+                    }
+
+                    if let Some(v) = otel_span_value(v) {
+                        attributes.push(KeyValue::new(k.to_cow(), v));
+                    }
+
+                    ControlFlow::Continue(())
+                });
+
+                match evt.extent() {
+                    Some(extent) => span.add_event_with_timestamp(
+                        name,
+                        extent.as_point().to_system_time(),
+                        attributes,
+                    ),
+                    None => span.add_event(name, attributes),
+                }
+
+                return;
+            }
+        }
+
         // If the event wasn't emitted as a span then emit it as a log record
         let mut record = LogRecord::builder();
 
@@ -624,6 +741,10 @@ impl emit::Emitter for OpenTelemetryEmitter {
                     }
                 }
 
+                if k == KEY_TRACE_FLAGS || k == KEY_TRACE_STATE {
+                    return ControlFlow::Continue(());
+                }
+
                 if let Some(v) = otel_log_value(v) {
                     attributes.push((Key::new(k.to_cow()), v));
                 }
@@ -1147,3 +1268,63 @@ mod any_value {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    use emit::Emitter;
+    use opentelemetry_sdk::{
+        export::logs::LogData,
+        logs::{LogProcessor, LoggerProvider as SdkLoggerProvider},
+    };
+
+    #[derive(Debug, Default, Clone)]
+    struct CaptureLogs(Arc<Mutex<Vec<LogData>>>);
+
+    impl LogProcessor for CaptureLogs {
+        fn emit(&self, data: LogData) {
+            self.0.lock().unwrap().push(data);
+        }
+
+        fn force_flush(&self) -> opentelemetry::logs::LogResult<()> {
+            Ok(())
+        }
+
+        fn shutdown(&mut self) -> opentelemetry::logs::LogResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn span_begin_ignored() {
+        let logs = CaptureLogs::default();
+
+        global::set_logger_provider(
+            SdkLoggerProvider::builder()
+                .with_log_processor(logs.clone())
+                .build(),
+        );
+
+        let emitter = EmitOpenTelemetry::new("emit").emitter();
+
+        let event = |kind: Option<&'static str>| {
+            emit::Event::new(
+                emit::Path::new("test"),
+                emit::Timestamp::from_unix(std::time::Duration::from_secs(1)),
+                emit::Template::literal("test"),
+                [(KEY_EVENT_KIND, emit::value::Value::from(kind))],
+            )
+        };
+
+        emitter.emit(event(Some(emit::well_known::EVENT_KIND_SPAN_BEGIN)));
+
+        assert_eq!(0, logs.0.lock().unwrap().len());
+
+        emitter.emit(event(None));
+
+        assert_eq!(1, logs.0.lock().unwrap().len());
+    }
+}
@@ -592,6 +592,19 @@ impl emit::emitter::Emitter for Otlp {
             if let Some(encoded) = encoder.encode_event(&evt) {
                 return self.sender.send(ChannelItem::Span(encoded));
             }
+
+            if encoder.encoder.push_span_event(
+                &evt,
+                || {
+                    self.otlp_logs
+                        .as_ref()
+                        .and_then(|encoder| encoder.encode_event(&evt))
+                },
+                &self.metrics,
+                |log_record| self.sender.send(ChannelItem::LogRecord(log_record)),
+            ) {
+                return;
+            }
         }
 
         if let Some(ref encoder) = self.otlp_logs {
@@ -604,6 +617,13 @@ impl emit::emitter::Emitter for Otlp {
     }
 
     fn blocking_flush(&self, timeout: Duration) -> bool {
+        // Send any log events still waiting on their span as log records
+        if let Some(ref encoder) = self.otlp_traces {
+            for log_record in encoder.encoder.take_pending_log_records() {
+                self.sender.send(ChannelItem::LogRecord(log_record));
+            }
+        }
+
         emit_batcher::tokio::blocking_flush(&self.sender, timeout)
     }
 }
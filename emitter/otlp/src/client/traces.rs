@@ -94,6 +94,16 @@ impl OtlpTracesBuilder {
         self
     }
 
+    /**
+    Specify how log events that occur inside a span are sent.
+
+    By default, log events are only sent as log records. See [`LogsInSpans`] for details.
+    */
+    pub fn logs_in_spans(mut self, logs_in_spans: LogsInSpans) -> Self {
+        self.event_encoder.logs_in_spans = logs_in_spans;
+        self
+    }
+
     pub(in crate::client) fn build(
         self,
         metrics: Arc<InternalMetrics>,
@@ -117,3 +127,29 @@ impl OtlpTracesBuilder {
         ))
     }
 }
+
+/**
+How log events that occur inside a span are sent by the traces signal.
+
+Log events occur inside a span if they carry the [`emit::well_known::KEY_SPAN_ID`] of a span that hasn't completed yet. When log events are attached to their span as span events, they're held onto until that span completes. If too many spans have events pending, then the oldest of them will be discarded. If too many events are pending for a single span, then further ones will be sent as log records instead.
+
+Log events inside spans that never complete aren't lost. If their span is discarded, or is still pending when the emitter is flushed, they're sent as log records instead.
+
+Events carrying [`emit::Kind::SpanEvent`] are always attached to their span as span events, regardless of this setting.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogsInSpans {
+    /**
+    Log events are only sent as log records.
+    */
+    #[default]
+    LogRecords,
+    /**
+    Log events are attached to their span as span events, and not sent as log records unless their span never completes.
+    */
+    SpanEvents,
+    /**
+    Log events are attached to their span as span events, and also sent as log records.
+    */
+    SpanEventsAndLogRecords,
+}
@@ -1,6 +1,8 @@
 mod export_logs_service;
 mod log_record;
 
+use emit::Filter;
+
 use crate::Error;
 
 pub use self::{export_logs_service::*, log_record::*};
@@ -32,6 +34,11 @@ impl EventEncoder for LogsEventEncoder {
         &self,
         evt: &emit::event::Event<impl emit::props::Props>,
     ) -> Option<EncodedEvent> {
+        // Span begin events are only useful in-process, so they aren't sent as log records
+        if emit::kind::is_span_begin_filter().matches(evt) {
+            return None;
+        }
+
         let time_unix_nano = evt
             .extent()
             .map(|extent| extent.as_point().to_unix().as_nanos() as u64)
@@ -114,6 +114,7 @@ impl<
                             .map(|trace_id| TR::from(trace_id));
                         true
                     }
+                    emit::well_known::KEY_TRACE_FLAGS | emit::well_known::KEY_TRACE_STATE => true,
                     _ => false,
                 })
             },
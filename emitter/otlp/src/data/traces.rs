@@ -1,9 +1,21 @@
 mod export_trace_service;
 mod span;
 
-use emit::{well_known::KEY_SPAN_NAME, Filter, Props};
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::ControlFlow,
+    sync::Mutex,
+};
+
+use emit::{
+    well_known::{
+        KEY_EVENT_KIND, KEY_SPAN_ID, KEY_SPAN_NAME, KEY_SPAN_PARENT, KEY_TRACE_FLAGS, KEY_TRACE_ID,
+        KEY_TRACE_STATE,
+    },
+    Filter, Props,
+};
 
-use crate::Error;
+use crate::{internal_metrics::InternalMetrics, Error, LogsInSpans};
 
 pub use self::{export_trace_service::*, span::*};
 
@@ -14,16 +26,205 @@ use super::{
 
 pub(crate) struct TracesEventEncoder {
     pub name: Box<MessageFormatter>,
+    pub logs_in_spans: LogsInSpans,
+    pending_span_events: Mutex<PendingSpanEvents>,
 }
 
 impl Default for TracesEventEncoder {
     fn default() -> Self {
         TracesEventEncoder {
             name: default_name_formatter(),
+            logs_in_spans: LogsInSpans::default(),
+            pending_span_events: Mutex::new(PendingSpanEvents::default()),
+        }
+    }
+}
+
+impl TracesEventEncoder {
+    /**
+    Hold on to an event that occurred inside a span so it can be encoded as a span event when that span completes.
+
+    If this method returns `true` then the event has been handled and shouldn't also be encoded as a log record. Log events that would otherwise only be sent as span events are held along with their `log_record`. If their span is discarded before it completes then that log record is passed to `evicted` instead.
+    */
+    pub(crate) fn push_span_event(
+        &self,
+        evt: &emit::event::Event<impl emit::props::Props>,
+        log_record: impl FnOnce() -> Option<EncodedEvent>,
+        metrics: &InternalMetrics,
+        evicted: impl FnMut(EncodedEvent),
+    ) -> bool {
+        // Span begin events are only useful in-process, so they aren't sent as span events
+        if emit::kind::is_span_begin_filter().matches(evt) {
+            return false;
+        }
+
+        let is_span_event = emit::kind::is_span_event_filter().matches(evt);
+
+        if !is_span_event && self.logs_in_spans == LogsInSpans::LogRecords {
+            return false;
+        }
+
+        let Some(span_id) = evt.props().pull::<emit::span::SpanId, _>(KEY_SPAN_ID) else {
+            return false;
+        };
+
+        let time_unix_nano = evt
+            .extent()
+            .map(|extent| extent.as_point().to_unix().as_nanos() as u64)
+            .unwrap_or_default();
+
+        let mut attributes = Vec::new();
+        let _ = evt.props().dedup().for_each(|k, v| {
+            match k.get() {
+                KEY_EVENT_KIND | KEY_TRACE_ID | KEY_SPAN_ID | KEY_SPAN_PARENT | KEY_TRACE_FLAGS
+                | KEY_TRACE_STATE => (),
+                _ => attributes.push((k.to_owned(), v.to_owned())),
+            }
+
+            ControlFlow::Continue(())
+        });
+
+        // If the log event is only sent as a span event then keep its log record
+        // in case its span never completes
+        let handled = is_span_event || self.logs_in_spans == LogsInSpans::SpanEvents;
+        let log_record = if !is_span_event && handled {
+            log_record()
+        } else {
+            None
+        };
+
+        let mut discarded = Vec::new();
+        let pushed = self
+            .pending_span_events
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push(
+                span_id.to_u64(),
+                PendingSpanEvent {
+                    name: evt.msg().to_string(),
+                    time_unix_nano,
+                    attributes,
+                    log_record,
+                },
+                &mut discarded,
+            );
+
+        evict(discarded, metrics, evicted);
+
+        // If the event couldn't be attached to its span then it's sent as a log record instead
+        pushed && handled
+    }
+
+    /**
+    Take the log records of any log events that are still waiting for their span to complete.
+
+    This is used when flushing, so log events inside spans that never complete aren't lost. Events taken this way won't be attached to their span if it does eventually complete.
+    */
+    pub(crate) fn take_pending_log_records(&self) -> Vec<EncodedEvent> {
+        self.pending_span_events
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take_log_records()
+    }
+}
+
+fn evict(
+    discarded: Vec<PendingSpanEvent>,
+    metrics: &InternalMetrics,
+    mut evicted: impl FnMut(EncodedEvent),
+) {
+    for evt in discarded {
+        match evt.log_record {
+            Some(log_record) => evicted(log_record),
+            None => metrics.span_event_discarded.increment(),
         }
     }
 }
 
+// The maximum number of spans to hold events for
+// Spans that never complete, like remote parents, will eventually be discarded
+const MAX_PENDING_SPANS: usize = 1024;
+// The maximum number of events to hold for a single span
+const MAX_EVENTS_PER_SPAN: usize = 128;
+
+#[derive(Default)]
+struct PendingSpanEvents {
+    spans: HashMap<u64, Vec<PendingSpanEvent>>,
+    order: VecDeque<u64>,
+}
+
+pub(crate) struct PendingSpanEvent {
+    pub name: String,
+    pub time_unix_nano: u64,
+    pub attributes: Vec<(emit::Str<'static>, emit::value::OwnedValue)>,
+    log_record: Option<EncodedEvent>,
+}
+
+impl PendingSpanEvents {
+    fn push(
+        &mut self,
+        span_id: u64,
+        evt: PendingSpanEvent,
+        discarded: &mut Vec<PendingSpanEvent>,
+    ) -> bool {
+        if let Some(events) = self.spans.get_mut(&span_id) {
+            if events.len() >= MAX_EVENTS_PER_SPAN {
+                return false;
+            }
+
+            events.push(evt);
+            return true;
+        }
+
+        // Make room by discarding the events of the oldest span
+        if self.spans.len() >= MAX_PENDING_SPANS {
+            if let Some(events) = self
+                .order
+                .pop_front()
+                .and_then(|span_id| self.spans.remove(&span_id))
+            {
+                discarded.extend(events);
+            }
+        }
+
+        self.order.push_back(span_id);
+        self.spans.insert(span_id, vec![evt]);
+
+        true
+    }
+
+    fn take(&mut self, span_id: u64) -> Vec<PendingSpanEvent> {
+        match self.spans.remove(&span_id) {
+            Some(events) => {
+                self.order.retain(|pending| *pending != span_id);
+
+                events
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn take_log_records(&mut self) -> Vec<EncodedEvent> {
+        let mut log_records = Vec::new();
+
+        for events in self.spans.values_mut() {
+            events.retain_mut(|evt| match evt.log_record.take() {
+                Some(log_record) => {
+                    log_records.push(log_record);
+                    false
+                }
+                None => true,
+            });
+        }
+
+        self.spans.retain(|_, events| !events.is_empty());
+        self.order
+            .retain(|span_id| self.spans.contains_key(span_id));
+
+        log_records
+    }
+}
+
 fn default_name_formatter() -> Box<MessageFormatter> {
     Box::new(|evt, f| {
         if let Some(name) = evt.props().get(KEY_SPAN_NAME) {
@@ -53,6 +254,17 @@ impl EventEncoder for TracesEventEncoder {
                 )
             })?;
 
+        let events = evt
+            .props()
+            .pull::<emit::span::SpanId, _>(KEY_SPAN_ID)
+            .map(|span_id| {
+                self.pending_span_events
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .take(span_id.to_u64())
+            })
+            .unwrap_or_default();
+
         Some(EncodedEvent {
             scope: evt.module().to_owned(),
             payload: E::encode(Span {
@@ -65,8 +277,9 @@ impl EventEncoder for TracesEventEncoder {
                 attributes: &PropsSpanAttributes::<E::TraceId, E::SpanId, _>::new(
                     end_time_unix_nano,
                     evt.props(),
+                    &events,
                 ),
-                kind: SpanKind::Unspecified,
+                kind: SpanKind::from_props(evt.props()),
             }),
         })
     }
@@ -104,3 +317,342 @@ impl<'a> sval::Value for EncodedScopeSpans<'a> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use prost::Message;
+
+    use emit::value::Value;
+
+    use crate::data::{generated::trace::v1 as generated, logs::LogsEventEncoder, Proto};
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+
+    fn encoder(logs_in_spans: LogsInSpans) -> TracesEventEncoder {
+        TracesEventEncoder {
+            logs_in_spans,
+            ..Default::default()
+        }
+    }
+
+    fn span_id(span_id: u64) -> String {
+        emit::span::SpanId::from_u64(span_id).unwrap().to_string()
+    }
+
+    fn ts(secs: u64) -> emit::Timestamp {
+        emit::Timestamp::from_unix(Duration::from_secs(secs)).unwrap()
+    }
+
+    fn encode_span(encoder: &TracesEventEncoder, span_id: u64) -> generated::Span {
+        let span_id = self::span_id(span_id);
+
+        let encoded = encoder
+            .encode_event::<Proto>(&emit::Event::new(
+                emit::Path::new("test"),
+                ts(1)..ts(2),
+                emit::Template::literal("span"),
+                [
+                    (
+                        KEY_EVENT_KIND,
+                        Value::from(emit::well_known::EVENT_KIND_SPAN),
+                    ),
+                    (KEY_TRACE_ID, Value::from(TRACE_ID)),
+                    (KEY_SPAN_ID, Value::from(&*span_id)),
+                ],
+            ))
+            .unwrap();
+
+        let EncodedPayload::Proto(buf) = encoded.payload else {
+            panic!("expected a protobuf payload");
+        };
+
+        generated::Span::decode(&*buf.to_vec()).unwrap()
+    }
+
+    fn push(
+        encoder: &TracesEventEncoder,
+        metrics: &InternalMetrics,
+        event_kind: Option<&'static str>,
+        span_id: u64,
+    ) -> bool {
+        push_evicted(encoder, metrics, event_kind, span_id, |_| {
+            panic!("unexpected evicted log record")
+        })
+    }
+
+    fn push_evicted(
+        encoder: &TracesEventEncoder,
+        metrics: &InternalMetrics,
+        event_kind: Option<&'static str>,
+        span_id: u64,
+        evicted: impl FnMut(EncodedEvent),
+    ) -> bool {
+        let span_id = self::span_id(span_id);
+
+        let evt = emit::Event::new(
+            emit::Path::new("test"),
+            ts(1),
+            emit::Template::literal("event"),
+            [
+                (KEY_EVENT_KIND, Value::from(event_kind)),
+                (KEY_TRACE_ID, Value::from(TRACE_ID)),
+                (KEY_SPAN_ID, Value::from(&*span_id)),
+                ("attempt", Value::from(1)),
+            ],
+        );
+
+        encoder.push_span_event(
+            &evt,
+            || LogsEventEncoder::default().encode_event::<Proto>(&evt),
+            metrics,
+            evicted,
+        )
+    }
+
+    #[test]
+    fn span_events_attach_on_complete() {
+        let encoder = encoder(LogsInSpans::LogRecords);
+        let metrics = InternalMetrics::default();
+
+        assert!(push(
+            &encoder,
+            &metrics,
+            Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+            1
+        ));
+
+        let span = encode_span(&encoder, 1);
+
+        assert_eq!(1, span.events.len());
+        assert_eq!("event", span.events[0].name);
+        assert_eq!(1_000_000_000, span.events[0].time_unix_nano);
+        assert_eq!(
+            vec!["attempt"],
+            span.events[0]
+                .attributes
+                .iter()
+                .map(|kv| &*kv.key)
+                .collect::<Vec<_>>()
+        );
+
+        // Events are only attached once
+        assert_eq!(0, encode_span(&encoder, 1).events.len());
+
+        // Events are only attached to their own span
+        assert!(push(
+            &encoder,
+            &metrics,
+            Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+            2
+        ));
+        assert_eq!(0, encode_span(&encoder, 3).events.len());
+        assert_eq!(1, encode_span(&encoder, 2).events.len());
+    }
+
+    #[test]
+    fn span_events_per_span_cap() {
+        let encoder = encoder(LogsInSpans::LogRecords);
+        let metrics = InternalMetrics::default();
+
+        for _ in 0..MAX_EVENTS_PER_SPAN {
+            assert!(push(
+                &encoder,
+                &metrics,
+                Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+                1
+            ));
+        }
+
+        // Once the span is full, events fall back to log records
+        assert!(!push(
+            &encoder,
+            &metrics,
+            Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+            1
+        ));
+        assert_eq!(0, metrics.span_event_discarded.sample());
+
+        assert_eq!(MAX_EVENTS_PER_SPAN, encode_span(&encoder, 1).events.len());
+    }
+
+    #[test]
+    fn span_events_evict_oldest_span() {
+        let encoder = encoder(LogsInSpans::LogRecords);
+        let metrics = InternalMetrics::default();
+
+        for span_id in 1..=MAX_PENDING_SPANS as u64 {
+            assert!(push(
+                &encoder,
+                &metrics,
+                Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+                span_id
+            ));
+        }
+
+        // Push a second event for the oldest span so we can see it counted
+        assert!(push(
+            &encoder,
+            &metrics,
+            Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+            1
+        ));
+        assert_eq!(0, metrics.span_event_discarded.sample());
+
+        let newest = MAX_PENDING_SPANS as u64 + 1;
+        assert!(push(
+            &encoder,
+            &metrics,
+            Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+            newest
+        ));
+
+        assert_eq!(2, metrics.span_event_discarded.sample());
+
+        assert_eq!(0, encode_span(&encoder, 1).events.len());
+        assert_eq!(1, encode_span(&encoder, 2).events.len());
+        assert_eq!(1, encode_span(&encoder, newest).events.len());
+    }
+
+    #[test]
+    fn logs_in_spans() {
+        for (logs_in_spans, handled, attached) in [
+            (LogsInSpans::LogRecords, false, 0),
+            (LogsInSpans::SpanEvents, true, 1),
+            (LogsInSpans::SpanEventsAndLogRecords, false, 1),
+        ] {
+            let encoder = encoder(logs_in_spans);
+            let metrics = InternalMetrics::default();
+
+            assert_eq!(
+                handled,
+                push(&encoder, &metrics, None, 1),
+                "{logs_in_spans:?}"
+            );
+            assert_eq!(
+                attached,
+                encode_span(&encoder, 1).events.len(),
+                "{logs_in_spans:?}"
+            );
+
+            // Span events are always attached, and never sent as log records
+            assert!(
+                push(
+                    &encoder,
+                    &metrics,
+                    Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+                    2
+                ),
+                "{logs_in_spans:?}"
+            );
+            assert_eq!(
+                1,
+                encode_span(&encoder, 2).events.len(),
+                "{logs_in_spans:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn span_begin_ignored() {
+        let metrics = InternalMetrics::default();
+        let span_id = self::span_id(1);
+
+        let begin = emit::Event::new(
+            emit::Path::new("test"),
+            ts(1),
+            emit::Template::literal("span"),
+            [
+                (
+                    KEY_EVENT_KIND,
+                    Value::from(emit::well_known::EVENT_KIND_SPAN_BEGIN),
+                ),
+                (KEY_TRACE_ID, Value::from(TRACE_ID)),
+                (KEY_SPAN_ID, Value::from(&*span_id)),
+            ],
+        );
+
+        assert!(LogsEventEncoder::default()
+            .encode_event::<Proto>(&begin)
+            .is_none());
+
+        for logs_in_spans in [
+            LogsInSpans::LogRecords,
+            LogsInSpans::SpanEvents,
+            LogsInSpans::SpanEventsAndLogRecords,
+        ] {
+            let encoder = encoder(logs_in_spans);
+
+            assert!(encoder.encode_event::<Proto>(&begin).is_none());
+            assert!(!encoder.push_span_event(
+                &begin,
+                || panic!("span begin events aren't sent as log records"),
+                &metrics,
+                |_| panic!("unexpected evicted log record"),
+            ));
+
+            assert_eq!(
+                0,
+                encode_span(&encoder, 1).events.len(),
+                "{logs_in_spans:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn logs_in_spans_evicted_as_log_records() {
+        let encoder = encoder(LogsInSpans::SpanEvents);
+        let metrics = InternalMetrics::default();
+
+        assert!(push(&encoder, &metrics, None, 1));
+
+        for span_id in 2..=MAX_PENDING_SPANS as u64 {
+            assert!(push(
+                &encoder,
+                &metrics,
+                Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+                span_id
+            ));
+        }
+
+        // Evicting the log event's span sends it as a log record instead
+        let mut evicted = Vec::new();
+        assert!(push_evicted(
+            &encoder,
+            &metrics,
+            Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+            MAX_PENDING_SPANS as u64 + 1,
+            |log_record| evicted.push(log_record),
+        ));
+
+        assert_eq!(1, evicted.len());
+        assert_eq!(0, metrics.span_event_discarded.sample());
+        assert_eq!(0, encode_span(&encoder, 1).events.len());
+    }
+
+    #[test]
+    fn logs_in_spans_flushed_as_log_records() {
+        let encoder = encoder(LogsInSpans::SpanEvents);
+        let metrics = InternalMetrics::default();
+
+        assert!(push(&encoder, &metrics, None, 1));
+        assert!(push(
+            &encoder,
+            &metrics,
+            Some(emit::well_known::EVENT_KIND_SPAN_EVENT),
+            1
+        ));
+        assert!(push(&encoder, &metrics, None, 2));
+
+        // Log events still waiting on their span are taken as log records
+        assert_eq!(2, encoder.take_pending_log_records().len());
+        assert_eq!(0, encoder.take_pending_log_records().len());
+
+        // Span events are still attached when their span completes
+        assert_eq!(1, encode_span(&encoder, 1).events.len());
+        assert_eq!(0, encode_span(&encoder, 2).events.len());
+    }
+}
@@ -4,6 +4,8 @@ use sval_derive::Value;
 
 use crate::data::{stream_attributes, stream_field, AnyValue, KeyValue};
 
+use super::PendingSpanEvent;
+
 #[derive(Value)]
 #[repr(i32)]
 #[sval(unlabeled_variants)]
@@ -16,7 +18,27 @@ pub enum StatusCode {
 #[repr(i32)]
 #[sval(unlabeled_variants)]
 pub enum SpanKind {
-    Unspecified = 0,
+    Internal = 1,
+    Server = 2,
+    Client = 3,
+    Producer = 4,
+    Consumer = 5,
+}
+
+impl SpanKind {
+    pub fn from_props(props: impl emit::props::Props) -> Self {
+        // Spans without a kind are internal
+        match props
+            .pull::<emit::span::SpanKind, _>(emit::well_known::KEY_SPAN_KIND)
+            .unwrap_or_default()
+        {
+            emit::span::SpanKind::Internal => SpanKind::Internal,
+            emit::span::SpanKind::Server => SpanKind::Server,
+            emit::span::SpanKind::Client => SpanKind::Client,
+            emit::span::SpanKind::Producer => SpanKind::Producer,
+            emit::span::SpanKind::Consumer => SpanKind::Consumer,
+        }
+    }
 }
 
 #[derive(Value)]
@@ -47,6 +69,8 @@ const SPAN_TRACE_ID_LABEL: sval::Label =
     sval::Label::new("traceId").with_tag(&sval::tags::VALUE_IDENT);
 const SPAN_SPAN_ID_LABEL: sval::Label =
     sval::Label::new("spanId").with_tag(&sval::tags::VALUE_IDENT);
+const SPAN_TRACE_STATE_LABEL: sval::Label =
+    sval::Label::new("traceState").with_tag(&sval::tags::VALUE_IDENT);
 const SPAN_PARENT_SPAN_ID_LABEL: sval::Label =
     sval::Label::new("parentSpanId").with_tag(&sval::tags::VALUE_IDENT);
 const SPAN_STATUS_LABEL: sval::Label =
@@ -54,13 +78,16 @@ const SPAN_STATUS_LABEL: sval::Label =
 
 const SPAN_EVENTS_LABEL: sval::Label =
     sval::Label::new("events").with_tag(&sval::tags::VALUE_IDENT);
+const SPAN_LINKS_LABEL: sval::Label = sval::Label::new("links").with_tag(&sval::tags::VALUE_IDENT);
 
 const SPAN_ATTRIBUTES_INDEX: sval::Index = sval::Index::new(9);
 const SPAN_TRACE_ID_INDEX: sval::Index = sval::Index::new(1);
 const SPAN_SPAN_ID_INDEX: sval::Index = sval::Index::new(2);
+const SPAN_TRACE_STATE_INDEX: sval::Index = sval::Index::new(3);
 const SPAN_PARENT_SPAN_ID_INDEX: sval::Index = sval::Index::new(4);
 const SPAN_STATUS_INDEX: sval::Index = sval::Index::new(15);
 const SPAN_EVENTS_INDEX: sval::Index = sval::Index::new(11);
+const SPAN_LINKS_INDEX: sval::Index = sval::Index::new(13);
 
 #[derive(Value)]
 pub struct InlineSpanAttributes<
@@ -83,17 +110,19 @@ pub struct InlineSpanAttributes<
     pub events: &'a E,
 }
 
-pub struct PropsSpanAttributes<T, S, P> {
+pub struct PropsSpanAttributes<'a, T, S, P> {
     time_unix_nano: u64,
     props: P,
+    events: &'a [PendingSpanEvent],
     _marker: PhantomData<(T, S)>,
 }
 
-impl<T, S, P> PropsSpanAttributes<T, S, P> {
-    pub fn new(time_unix_nano: u64, props: P) -> Self {
+impl<'a, T, S, P> PropsSpanAttributes<'a, T, S, P> {
+    pub fn new(time_unix_nano: u64, props: P, events: &'a [PendingSpanEvent]) -> Self {
         PropsSpanAttributes {
             time_unix_nano,
             props,
+            events,
             _marker: PhantomData,
         }
     }
@@ -103,14 +132,15 @@ impl<
         TR: From<emit::span::TraceId> + sval::Value,
         SP: From<emit::span::SpanId> + sval::Value,
         P: emit::props::Props,
-    > sval::Value for PropsSpanAttributes<TR, SP, P>
+    > sval::Value for PropsSpanAttributes<'_, TR, SP, P>
 {
     fn stream<'sval, S: sval::Stream<'sval> + ?Sized>(&'sval self, stream: &mut S) -> sval::Result {
         let mut trace_id = None;
         let mut span_id = None;
         let mut parent_span_id = None;
-        let mut level = emit::level::Level::default();
         let mut has_err = false;
+        let mut trace_state = None;
+        let mut links = None;
 
         stream.record_tuple_begin(None, None, None, None)?;
 
@@ -122,10 +152,10 @@ impl<
                 stream_attributes(stream, &self.props, |k, v| match k.get() {
                     emit::well_known::KEY_EVENT_KIND => true,
                     emit::well_known::KEY_SPAN_NAME => true,
-                    emit::well_known::KEY_LVL => {
-                        level = v.by_ref().cast().unwrap_or_default();
-                        true
-                    }
+                    emit::well_known::KEY_SPAN_KIND => true,
+                    emit::well_known::KEY_LVL => true,
+                    emit::well_known::KEY_SPAN_STATUS => true,
+                    emit::well_known::KEY_SPAN_STATUS_DESCRIPTION => true,
                     emit::well_known::KEY_SPAN_ID => {
                         span_id = v
                             .by_ref()
@@ -147,6 +177,15 @@ impl<
                             .map(|trace_id| TR::from(trace_id));
                         true
                     }
+                    emit::well_known::KEY_TRACE_STATE => {
+                        trace_state = Some(v.to_string());
+                        true
+                    }
+                    emit::well_known::KEY_TRACE_FLAGS => true,
+                    emit::well_known::KEY_SPAN_LINKS => {
+                        links = v.by_ref().cast::<emit::span::SpanLinks>();
+                        true
+                    }
                     emit::well_known::KEY_ERR => {
                         has_err = true;
                         true
@@ -174,6 +213,15 @@ impl<
             )?;
         }
 
+        if let Some(trace_state) = trace_state {
+            stream_field(
+                &mut *stream,
+                &SPAN_TRACE_STATE_LABEL,
+                &SPAN_TRACE_STATE_INDEX,
+                |stream| stream.value_computed(&*trace_state),
+            )?;
+        }
+
         if let Some(parent_span_id) = parent_span_id {
             stream_field(
                 &mut *stream,
@@ -183,59 +231,101 @@ impl<
             )?;
         }
 
-        // If the span has an error on it then set the conventional error event
-        // along with the span status
-        if has_err {
-            let err = self.props.get(emit::well_known::KEY_ERR).unwrap();
+        // Events that occurred inside the span are attached to it
+        // If the span has an error on it then also set the conventional error event
+        if has_err || !self.events.is_empty() {
+            let err = self.props.get(emit::well_known::KEY_ERR);
 
             stream_field(
                 &mut *stream,
                 &SPAN_EVENTS_LABEL,
                 &SPAN_EVENTS_INDEX,
                 |stream| {
-                    stream.value_computed(&[Event {
-                        name: "exception",
-                        time_unix_nano: self.time_unix_nano,
-                        attributes: &InlineEventAttributes {
-                            attributes: &[KeyValue {
-                                key: "exception.message",
-                                value: AnyValue::<_>::String(sval::Display::new_borrowed(&err)),
-                            }],
-                        },
-                    }])
+                    stream.seq_begin(None)?;
+
+                    for evt in self.events {
+                        stream.seq_value_begin()?;
+                        stream.value_computed(&Event {
+                            name: &*evt.name,
+                            time_unix_nano: evt.time_unix_nano,
+                            attributes: &PropsEventAttributes(&*evt.attributes),
+                        })?;
+                        stream.seq_value_end()?;
+                    }
+
+                    if let Some(err) = err {
+                        stream.seq_value_begin()?;
+                        stream.value_computed(&Event {
+                            name: "exception",
+                            time_unix_nano: self.time_unix_nano,
+                            attributes: &InlineEventAttributes {
+                                attributes: &[KeyValue {
+                                    key: "exception.message",
+                                    value: AnyValue::<_>::String(sval::Display::new_borrowed(&err)),
+                                }],
+                            },
+                        })?;
+                        stream.seq_value_end()?;
+                    }
+
+                    stream.seq_end()
                 },
             )?;
+        }
 
-            let status = Status {
-                code: StatusCode::Error,
-                message: sval::Display::new_borrowed(&err),
-            };
-
+        if let Some(links) = links {
             stream_field(
                 &mut *stream,
-                &SPAN_STATUS_LABEL,
-                &SPAN_STATUS_INDEX,
-                |stream| stream.value_computed(&status),
+                &SPAN_LINKS_LABEL,
+                &SPAN_LINKS_INDEX,
+                |stream| {
+                    stream.seq_begin(Some(links.len()))?;
+
+                    for (link, attributes) in links.iter() {
+                        stream.seq_value_begin()?;
+                        stream.value_computed(&PropsLink::<TR, SP> {
+                            link,
+                            attributes,
+                            _marker: PhantomData,
+                        })?;
+                        stream.seq_value_end()?;
+                    }
+
+                    stream.seq_end()
+                },
             )?;
         }
-        // If the span doesn't have an error then use the level to determine
-        // the span status
-        else {
-            let code = match level {
-                emit::Level::Debug | emit::Level::Info => StatusCode::Ok,
-                emit::Level::Warn | emit::Level::Error => StatusCode::Error,
-            };
 
-            let status = Status {
-                code,
-                message: sval::Display::new_borrowed(&level),
+        // If the span has a status then set it, using the error as its description
+        // if there isn't an explicit one
+        if let Some(status) = emit::span::SpanStatus::from_props(&self.props) {
+            let code = match status {
+                emit::span::SpanStatus::Ok => StatusCode::Ok,
+                emit::span::SpanStatus::Error => StatusCode::Error,
             };
 
+            let description = self
+                .props
+                .get(emit::well_known::KEY_SPAN_STATUS_DESCRIPTION)
+                .or_else(|| {
+                    if status == emit::span::SpanStatus::Error {
+                        self.props.get(emit::well_known::KEY_ERR)
+                    } else {
+                        None
+                    }
+                });
+
             stream_field(
                 &mut *stream,
                 &SPAN_STATUS_LABEL,
                 &SPAN_STATUS_INDEX,
-                |stream| stream.value_computed(&status),
+                |stream| match description {
+                    Some(ref description) => stream.value_computed(&Status {
+                        code,
+                        message: sval::Display::new_borrowed(description),
+                    }),
+                    None => stream.value_computed(&Status { code, message: "" }),
+                },
             )?;
         }
 
@@ -292,3 +382,82 @@ impl<P: emit::props::Props> sval::Value for PropsEventAttributes<P> {
         stream.record_tuple_end(None, None, None)
     }
 }
+
+const LINK_TRACE_ID_LABEL: sval::Label =
+    sval::Label::new("traceId").with_tag(&sval::tags::VALUE_IDENT);
+const LINK_SPAN_ID_LABEL: sval::Label =
+    sval::Label::new("spanId").with_tag(&sval::tags::VALUE_IDENT);
+const LINK_ATTRIBUTES_LABEL: sval::Label =
+    sval::Label::new("attributes").with_tag(&sval::tags::VALUE_IDENT);
+
+const LINK_TRACE_ID_INDEX: sval::Index = sval::Index::new(1);
+const LINK_SPAN_ID_INDEX: sval::Index = sval::Index::new(2);
+const LINK_ATTRIBUTES_INDEX: sval::Index = sval::Index::new(4);
+
+struct PropsLink<'a, T, S> {
+    link: &'a emit::span::SpanLink,
+    attributes: &'a [(emit::str::Str<'static>, emit::value::OwnedValue)],
+    _marker: PhantomData<(T, S)>,
+}
+
+impl<TR: From<emit::span::TraceId> + sval::Value, SP: From<emit::span::SpanId> + sval::Value>
+    sval::Value for PropsLink<'_, TR, SP>
+{
+    fn stream<'sval, S: sval::Stream<'sval> + ?Sized>(&'sval self, stream: &mut S) -> sval::Result {
+        stream.record_tuple_begin(None, None, None, None)?;
+
+        stream_field(
+            &mut *stream,
+            &LINK_TRACE_ID_LABEL,
+            &LINK_TRACE_ID_INDEX,
+            |stream| stream.value_computed(&TR::from(*self.link.trace_id())),
+        )?;
+
+        stream_field(
+            &mut *stream,
+            &LINK_SPAN_ID_LABEL,
+            &LINK_SPAN_ID_INDEX,
+            |stream| stream.value_computed(&SP::from(*self.link.span_id())),
+        )?;
+
+        stream_field(
+            &mut *stream,
+            &LINK_ATTRIBUTES_LABEL,
+            &LINK_ATTRIBUTES_INDEX,
+            |stream| stream_attributes(stream, &self.attributes, |_, _| false),
+        )?;
+
+        stream.record_tuple_end(None, None, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_kind_from_props() {
+        for (props, expected) in [
+            (None, SpanKind::Internal),
+            (Some("internal"), SpanKind::Internal),
+            (Some("server"), SpanKind::Server),
+            (Some("Client"), SpanKind::Client),
+            (Some("producer"), SpanKind::Producer),
+            (Some("consumer"), SpanKind::Consumer),
+            (Some("not a kind"), SpanKind::Internal),
+        ] {
+            let actual =
+                SpanKind::from_props(props.map(|kind| (emit::well_known::KEY_SPAN_KIND, kind)));
+
+            assert_eq!(expected as i32, actual as i32, "{props:?}");
+        }
+
+        assert_eq!(
+            SpanKind::Server as i32,
+            SpanKind::from_props((
+                emit::well_known::KEY_SPAN_KIND,
+                emit::span::SpanKind::Server
+            )) as i32
+        );
+    }
+}
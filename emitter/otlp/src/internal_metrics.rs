@@ -74,10 +74,14 @@ metrics!(
     OtlpMetrics {
         metrics: InternalMetrics {
             /**
-            An event didn't match a configured OTLP signal and the logs signal is not configured, so it was discarded.
+            An event didn't match a configured OTLP signal, so it was discarded. This includes span begin events, and any events when the logs signal is not configured.
             */
             event_discarded: Counter -> usize,
             /**
+            A span event was waiting for its span to complete, but too many spans had events pending, so it was discarded.
+            */
+            span_event_discarded: Counter -> usize,
+            /**
             A connection to a remote OTLP receiver was established successfully.
             */
             transport_conn_established: Counter -> usize,
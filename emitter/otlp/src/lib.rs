@@ -525,6 +525,50 @@ When the traces signal is configured, [`emit::Event`]s can be represented as OTL
 
 If any condition is not met, the event will be represented as an OTLP log record. If the logs signal is not configured then it will be discarded.
 
+If the event has an [`emit::span::SpanKind`] in the [`emit::well_known::KEY_SPAN_KIND`] property then it's used as the kind of the OTLP span. Otherwise the span is internal.
+
+The status of the OTLP span is determined by [`emit::span::SpanStatus::from_props`]. Spans that carry an error or have an error level will have an error status. Its description is taken from the [`emit::well_known::KEY_SPAN_STATUS_DESCRIPTION`] property, or from the error if there is one. Otherwise the status is unset.
+
+If the event has [`emit::span::SpanLinks`] in the [`emit::well_known::KEY_SPAN_LINKS`] property then they're used as the links of the OTLP span, along with any attributes on them.
+
+Events carrying an [`emit::Kind::SpanEvent`] in the [`emit::well_known::KEY_EVENT_KIND`] property are attached to the OTLP span they were emitted inside of as span events. Log events emitted inside a span can also be attached to it as span events, either instead of or as well as being sent as log records, using [`OtlpTracesBuilder::logs_in_spans`]:
+
+```
+# fn build() -> emit_otlp::Otlp {
+emit_otlp::new()
+    .resource(emit::props! {
+        #[emit::key("service.name")]
+        service_name: env!("CARGO_PKG_NAME"),
+    })
+    .traces(
+        emit_otlp::traces_grpc_proto("http://localhost:4318")
+            .logs_in_spans(emit_otlp::LogsInSpans::SpanEventsAndLogRecords),
+    )
+    .logs(emit_otlp::logs_grpc_proto("http://localhost:4318"))
+    .spawn()
+    .unwrap()
+# }
+```
+
+Span events are held onto until the span they belong to completes. If the span has an error then the conventional `exception` event is also attached to it.
+
+To only send traces that fail or run slowly, wrap the OTLP emitter in an [`emit::span::sampler::TailSampler`]. It buffers events by their trace id and only forwards whole traces that match its policies:
+
+```
+# use std::time::Duration;
+# fn build() -> impl emit::Emitter {
+emit::span::sampler::TailSampler::new()
+    .keep_slower_than(Duration::from_millis(500))
+    .keep_ratio(0.05)
+    .wrap_emitter(
+        emit_otlp::new()
+            .traces(emit_otlp::traces_grpc_proto("http://localhost:4319"))
+            .spawn()
+            .unwrap(),
+    )
+# }
+```
+
 A minimal logging configuration for gRPC+Protobuf is:
 
 ```
@@ -1101,10 +1145,8 @@ http://localhost:4318/v1/metrics
 
 This library is not an alternative to the OpenTelemetry SDK. It's specifically targeted at emitting diagnostic events to OTLP-compatible services. It has some intentional limitations:
 
-- **No propagation.** This is the responsibility of the application to manage.
+- **No propagation.** This is the responsibility of the application to manage, using helpers like `emit::span::propagation`.
 - **No histogram metrics.** `emit`'s data model for metrics is simplistic compared to OpenTelemetry's, so it doesn't support histograms or exponential histograms.
-- **No span events.** Only the conventional exception event is supported. Standalone log events are not converted into span events. They're sent via the logs endpoint instead.
-- **No tracestate.** `emit`'s data model for spans doesn't include the W3C tracestate.
 
 # Troubleshooting
 
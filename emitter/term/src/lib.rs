@@ -46,6 +46,8 @@ fn main() {
     rt.blocking_flush(std::time::Duration::from_secs(30));
 }
 ```
+
+Spans are written when they complete, with a `span ←` marker. If span begin events are enabled through [`emit::Setup::emit_span_begin`], or by the span macro itself, then spans are also written when they begin, with a `span →` marker, so operations that are still in progress are visible.
 */
 
 #![doc(html_logo_url = "https://raw.githubusercontent.com/KodrAus/emit/main/asset/logo.svg")]
@@ -88,6 +90,7 @@ fn main() {
 */
 pub struct Stdout {
     writer: BufferWriter,
+    local_offset: Option<emit::timestamp::Offset>,
 }
 
 impl Stdout {
@@ -99,6 +102,7 @@ impl Stdout {
     pub fn new() -> Self {
         Stdout {
             writer: BufferWriter::stdout(ColorChoice::Auto),
+            local_offset: emit::platform::local_offset(),
         }
     }
 
@@ -116,13 +120,32 @@ impl Stdout {
 
         self
     }
+
+    /**
+    Whether to write timestamps in local time.
+
+    By default, timestamps are written as the time of day in the system's local time zone. If `local_time` is false then timestamps will always be written in full in UTC.
+
+    The local offset is determined once through [`emit::platform::local_offset`] when the emitter is created, so create it early, like at the start of `main` before any other threads are spawned. If the local offset can't be determined then timestamps are written in full in UTC.
+    */
+    pub fn local_time(mut self, local_time: bool) -> Self {
+        self.local_offset = if local_time {
+            self.local_offset.or_else(emit::platform::local_offset)
+        } else {
+            None
+        };
+
+        self
+    }
 }
 
 impl emit::emitter::Emitter for Stdout {
     fn emit<E: emit::event::ToEvent>(&self, evt: E) {
         let evt = evt.to_event();
 
-        with_shared_buf(&self.writer, |writer, buf| print_event(writer, buf, &evt));
+        with_shared_buf(&self.writer, |writer, buf| {
+            print_event(writer, buf, &evt, self.local_offset)
+        });
     }
 
     fn blocking_flush(&self, _: Duration) -> bool {
@@ -136,6 +159,17 @@ fn print_event(
     out: &BufferWriter,
     buf: &mut Buffer,
     evt: &emit::event::Event<impl emit::props::Props>,
+    local_offset: Option<emit::timestamp::Offset>,
+) {
+    write_event(buf, evt, local_offset);
+
+    let _ = out.print(&buf);
+}
+
+fn write_event(
+    buf: &mut Buffer,
+    evt: &emit::event::Event<impl emit::props::Props>,
+    local_offset: Option<emit::timestamp::Offset>,
 ) {
     if let Some(span_id) = evt.props().pull::<emit::span::SpanId, _>(KEY_SPAN_ID) {
         if let Some(trace_id) = evt.props().pull::<emit::span::TraceId, _>(KEY_TRACE_ID) {
@@ -160,24 +194,37 @@ fn print_event(
     if let Some(extent) = evt.extent() {
         if extent.is_span() {
             if let Some(len) = extent.len() {
-                write_timestamp(buf, *extent.as_point());
+                write_timestamp(buf, *extent.as_point(), local_offset);
                 write_plain(buf, " ");
                 write_duration(buf, len);
             } else {
-                write_timestamp(buf, extent.as_range().start);
+                write_timestamp(buf, extent.as_range().start, local_offset);
                 write_plain(buf, "..");
-                write_timestamp(buf, extent.as_range().end);
+                write_timestamp(buf, extent.as_range().end, local_offset);
             }
         } else {
-            write_timestamp(buf, *extent.as_point());
+            write_timestamp(buf, *extent.as_point(), local_offset);
         }
 
         write_plain(buf, " ");
     }
 
-    if let Some(kind) = evt.props().get(KEY_EVENT_KIND) {
-        write_fg(buf, kind, KIND);
-        write_plain(buf, " ");
+    // Spans are written with an arrow so their beginning and completion can be paired up
+    match evt.props().pull::<emit::Kind, _>(KEY_EVENT_KIND) {
+        Some(emit::Kind::SpanBegin) => {
+            write_fg(buf, "span →", KIND);
+            write_plain(buf, " ");
+        }
+        Some(emit::Kind::Span) => {
+            write_fg(buf, "span ←", KIND);
+            write_plain(buf, " ");
+        }
+        _ => {
+            if let Some(kind) = evt.props().get(KEY_EVENT_KIND) {
+                write_fg(buf, kind, KIND);
+                write_plain(buf, " ");
+            }
+        }
     }
 
     let mut lvl = None;
@@ -214,8 +261,6 @@ fn print_event(
             write_timeseries(buf, &buckets);
         }
     }
-
-    let _ = out.print(&buf);
 }
 
 fn write_timeseries(buf: &mut Buffer, buckets: &[f64]) {
@@ -279,35 +324,23 @@ fn hex_slice<'a>(hex: &'a [u8], len: usize) -> impl fmt::Display + 'a {
     HexSlice(hex, len)
 }
 
-struct LocalTime {
-    h: u8,
-    m: u8,
-    s: u8,
-    ms: u16,
-}
-
-fn local_ts(ts: emit::Timestamp) -> Option<LocalTime> {
-    // See: https://github.com/rust-lang/rust/issues/27970
-    //
-    // On Linux and OSX, this will fail to get the local offset in
-    // any multi-threaded program. It needs to be fixed in the standard
-    // library and propagated through libraries like `time`. Until then,
-    // you probably won't get local timestamps outside of Windows.
-    let local =
-        time::OffsetDateTime::from_unix_timestamp_nanos(ts.to_unix().as_nanos().try_into().ok()?)
-            .ok()?;
-    let local = local.checked_to_offset(time::UtcOffset::local_offset_at(local).ok()?)?;
-
-    let (h, m, s, ms) = local.time().as_hms_milli();
-
-    Some(LocalTime { h, m, s, ms })
-}
+fn write_timestamp(
+    buf: &mut Buffer,
+    ts: emit::Timestamp,
+    local_offset: Option<emit::timestamp::Offset>,
+) {
+    if let Some(offset) = local_offset {
+        let parts = ts.to_parts_with_offset(offset);
 
-fn write_timestamp(buf: &mut Buffer, ts: emit::Timestamp) {
-    if let Some(LocalTime { h, m, s, ms }) = local_ts(ts) {
         write_plain(
             buf,
-            format_args!("{:>02}:{:>02}:{:>02}.{:>03}", h, m, s, ms),
+            format_args!(
+                "{:>02}:{:>02}:{:>02}.{:>03}",
+                parts.hours,
+                parts.minutes,
+                parts.seconds,
+                parts.nanos / 1_000_000
+            ),
         );
     } else {
         write_plain(buf, format_args!("{:.0}", ts));
@@ -496,3 +529,55 @@ fn with_shared_buf(writer: &BufferWriter, with_buf: impl FnOnce(&BufferWriter, &
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(kind: emit::Kind) -> String {
+        write_with_offset(kind, None)
+    }
+
+    fn write_with_offset(
+        kind: emit::Kind,
+        local_offset: Option<emit::timestamp::Offset>,
+    ) -> String {
+        let mut buf = Buffer::no_color();
+
+        write_event(
+            &mut buf,
+            &emit::Event::new(
+                emit::Path::new("test"),
+                emit::Timestamp::from_unix(Duration::from_secs(1)).unwrap(),
+                emit::Template::literal("work"),
+                (KEY_EVENT_KIND, kind),
+            ),
+            local_offset,
+        );
+
+        String::from_utf8(buf.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn write_span_markers() {
+        assert_eq!(
+            "1970-01-01T00:00:01Z span → test work\n",
+            write(emit::Kind::SpanBegin)
+        );
+        assert_eq!(
+            "1970-01-01T00:00:01Z span ← test work\n",
+            write(emit::Kind::Span)
+        );
+    }
+
+    #[test]
+    fn write_local_time() {
+        assert_eq!(
+            "10:00:01.000 span ← test work\n",
+            write_with_offset(
+                emit::Kind::Span,
+                emit::timestamp::Offset::from_secs(10 * 60 * 60)
+            )
+        );
+    }
+}
@@ -3,10 +3,11 @@ An example of how to pull a W3C traceparent header from an incoming HTTP request
 
 This example doesn't use any specific web frameworks, so it stubs out a few bits. The key pieces are:
 
-- The `traceparent` module. This implements a simple parser and formatter for the traceparent header.
 - The `http::incoming` function. This demonstrates pulling a traceparent off an incoming HTTP request.
 - The `http::outgoing` function. This demonstrates pulling a traceparent off the current `emit` context and adding it to an outgoing request.
 
+Headers are read and written by `emit::span::propagation`, which works with any header map that implements its `Extractor` and `Injector` traits.
+
 Applications using the OpenTelemetry SDK should use its propagation mechanisms instead of this approach.
 */
 
@@ -25,6 +26,8 @@ fn main() {
                     "traceparent".into(),
                     "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".into(),
                 );
+                map.insert("tracestate".into(), "congo=t61rcWkgMzE".into());
+                map.insert("baggage".into(), "tenant_id=acme".into());
                 map
             },
         },
@@ -55,53 +58,6 @@ fn routes(method: &str, path: &str) {
     }
 }
 
-// This is a portable traceparent parser
-// You may want to use it, or if you've got another one handy use it instead
-pub mod traceparent {
-    pub fn parse(
-        traceparent: &str,
-    ) -> Result<(emit::span::TraceId, emit::span::SpanId), Box<dyn std::error::Error + Send + Sync>>
-    {
-        let mut parts = traceparent.split('-');
-
-        let version = parts.next().ok_or("missing version")?;
-
-        let "00" = version else {
-            return Err(
-                format!("unexpected version {version:?}. Only version '00' is supported").into(),
-            );
-        };
-
-        let trace_id = parts.next().ok_or("missing trace id")?;
-        let span_id = parts.next().ok_or("missing span id")?;
-        let flags = parts.next().ok_or("missing flags")?;
-
-        let None = parts.next() else {
-            return Err(format!("traceparent {traceparent:?} is in an invalid format").into());
-        };
-
-        let ("00" | "01") = flags else {
-            return Err(format!("unexpected flags {flags:?}").into());
-        };
-
-        let trace_id = trace_id.parse()?;
-        let span_id = span_id.parse()?;
-
-        Ok((trace_id, span_id))
-    }
-
-    pub fn format(
-        trace_id: Option<emit::span::TraceId>,
-        span_id: Option<emit::span::SpanId>,
-    ) -> Option<String> {
-        let (Some(trace_id), Some(span_id)) = (trace_id, span_id) else {
-            return None;
-        };
-
-        Some(format!("00-{trace_id}-{span_id}-01"))
-    }
-}
-
 pub mod http {
     use emit::Props;
     use std::collections::HashMap;
@@ -118,28 +74,18 @@ pub mod http {
 
         emit::debug!("Inbound {#[emit::as_serde] request}");
 
-        // 1. Pull the trace and span ids from the incoming traceparent
-        let mut trace_id = None;
-        let mut span_id = None;
-
-        if let Some((parsed_trace_id, parsed_span_id)) = request
-            .headers
-            .get("traceparent")
-            .and_then(|traceparent| crate::traceparent::parse(traceparent).ok())
-        {
-            trace_id = Some(parsed_trace_id);
-            span_id = Some(parsed_span_id);
-        }
-
-        // 2. Push the trace and span ids to the current emit context
+        // 1. Pull the traceparent and tracestate from the incoming headers
+        //    and push them to the current emit context
         //    This ensures any spans created in the request use the same
         //    trace id, and set their parent span ids appropriately
+        // 2. Pull any baggage from the incoming headers too
+        //    This makes properties like `tenant_id` available to events
+        //    emitted while handling the request
         emit::Frame::push(
             emit::runtime::shared().ctxt(),
-            emit::props! {
-                trace_id,
-                span_id,
-            },
+            emit::span::propagation::extract(&request.headers).and_props(
+                emit::span::propagation::extract_baggage(&request.headers, &["tenant_id"]),
+            ),
         )
         .call(|| {
             // 3. Handle your request within the frame
@@ -150,20 +96,17 @@ pub mod http {
     pub fn outgoing(mut request: HttpRequest) {
         // Adding the traceparent from the current context onto a HTTP request
 
-        // 1. Pull the trace and span ids from the current context
-        let (trace_id, span_id) =
-            emit::Frame::current(emit::runtime::shared().ctxt()).with(|current| {
-                (
-                    current.pull(emit::well_known::KEY_TRACE_ID),
-                    current.pull(emit::well_known::KEY_SPAN_ID),
-                )
-            });
+        // 1. Add the traceparent and tracestate from the current context
+        //    onto the outgoing headers
+        emit::span::propagation::inject(emit::runtime::shared().ctxt(), &mut request.headers);
 
-        // 2. Format them as a traceparent header
-        if let Some(traceparent) = crate::traceparent::format(trace_id, span_id) {
-            // 3. Add the traceparent to the outgoing request
-            request.headers.insert("traceparent".into(), traceparent);
-        }
+        // 2. Add selected baggage from the current context
+        //    onto the outgoing headers
+        emit::span::propagation::inject_baggage(
+            emit::runtime::shared().ctxt(),
+            &["tenant_id"],
+            &mut request.headers,
+        );
 
         emit::debug!("Outbound {#[emit::as_serde] request}");
     }
@@ -1,3 +1,4 @@
+use emit_core::template::{Align, FormatSpec};
 use proc_macro2::TokenStream;
 use syn::{
     parse::Parse, punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Expr, ExprLit,
@@ -70,6 +71,24 @@ impl Parse for Args {
 }
 
 impl Args {
+    fn to_formatter(&self) -> TokenStream {
+        // Flags that are a valid `FormatSpec` are carried on the template
+        // so they can be inspected, and survive rendering the template braced
+        if let Ok(spec) = emit_core::template::FormatSpec::parse(&self.flags) {
+            if !self.flags.is_empty() {
+                let spec = format_spec_tokens(&spec);
+
+                return quote!(emit::template::Formatter::from_spec(#spec));
+            }
+        }
+
+        let fmt = self.to_format_args();
+
+        quote!(emit::template::Formatter::new(|v, f| {
+            emit::__private::core::write!(f, #fmt, v)
+        }))
+    }
+
     fn to_format_args(&self) -> String {
         if self.flags.is_empty() {
             "{}".to_owned()
@@ -80,6 +99,47 @@ impl Args {
     }
 }
 
+fn format_spec_tokens(spec: &FormatSpec) -> TokenStream {
+    let mut tokens = quote!(emit::template::FormatSpec::new());
+
+    if let Some(align) = spec.align() {
+        let fill = spec.fill();
+        let align = match align {
+            Align::Left => quote!(Left),
+            Align::Center => quote!(Center),
+            Align::Right => quote!(Right),
+        };
+
+        tokens = quote!(#tokens.with_fill(#fill).with_align(emit::template::Align::#align));
+    }
+
+    if spec.sign_plus() {
+        tokens = quote!(#tokens.with_sign_plus(true));
+    }
+
+    if spec.alternate() {
+        tokens = quote!(#tokens.with_alternate(true));
+    }
+
+    if spec.zero_pad() {
+        tokens = quote!(#tokens.with_zero_pad(true));
+    }
+
+    if let Some(width) = spec.width() {
+        tokens = quote!(#tokens.with_width(#width));
+    }
+
+    if let Some(precision) = spec.precision() {
+        tokens = quote!(#tokens.with_precision(#precision));
+    }
+
+    if spec.debug() {
+        tokens = quote!(#tokens.with_debug(true));
+    }
+
+    tokens
+}
+
 pub struct RenameHookTokens {
     pub args: TokenStream,
     pub expr: TokenStream,
@@ -99,12 +159,8 @@ pub fn rename_hook_tokens(opts: RenameHookTokens) -> Result<TokenStream, syn::Er
                 return None;
             }
 
-            let fmt = args.to_format_args();
-
             let to_ident = quote!(__private_fmt_as);
-            let to_arg = quote!(emit::template::Formatter::new(|v, f| {
-                emit::__private::core::write!(f, #fmt, v)
-            }));
+            let to_arg = args.to_formatter();
 
             Some((to_ident, to_arg))
         },
@@ -359,7 +359,10 @@ This macro accepts the following optional control parameters:
 
 - `rt: impl emit::runtime::Runtime`: The runtime to emit the event through.
 - `module: impl Into<emit::Path>`: The module the event belongs to. If unspecified the current module path is used.
-- `when: impl emit::Filter`: A filter to use instead of the one configured on the runtime.
+- `when: impl emit::span::sampler::Sampler`: A sampler or filter to use instead of the filter configured on the runtime. If a filter doesn't match, the span is disabled and nothing is pushed to the ambient context for it. See `emit::span::sampler` for samplers that sample whole traces. If a sampler discards the span, it isn't emitted, but its trace id and span id are still pushed to the ambient context with unsampled trace flags.
+- `begin: bool`: Whether to emit an event when the span begins, as well as when it completes. If unspecified the runtime's `span_begin` setting is used, which can be configured through `emit::Setup::emit_span_begin`.
+- `kind: impl emit::value::ToValue`: The kind of the span, like `emit::span::SpanKind::Server`. If unspecified the span is internal. The kind is added to the span's own events, and isn't inherited by events or spans inside it.
+- `links: impl emit::value::ToValue`: Links from the span to spans in other traces, like `emit::span::SpanLinks`. The links are evaluated when the span begins, and only added to the completed span event. They aren't inherited by events or spans inside it.
 - `arg`: An identifier to bind an `emit::Span` to in the body of the span for manual completion.
 
 # Template
@@ -689,6 +692,8 @@ where
 # Applicable to
 
 This attribute can be applied to properties that appear in a template.
+
+Holes in templates can also specify format flags after a `:`, like `{x:>8.2}`, which is equivalent to applying this attribute.
 */
 #[proc_macro_attribute]
 pub fn fmt(
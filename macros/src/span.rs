@@ -22,6 +22,9 @@ struct Args {
     rt: TokenStream,
     module: TokenStream,
     when: TokenStream,
+    begin: TokenStream,
+    kind: Option<TokenStream>,
+    links: Option<TokenStream>,
     arg: Option<Ident>,
 }
 
@@ -42,17 +45,46 @@ impl Parse for Args {
 
             Ok(quote_spanned!(expr.span()=> #expr))
         });
+        let mut begin = Arg::token_stream("begin", |fv| {
+            let expr = &fv.expr;
+
+            Ok(quote_spanned!(expr.span()=> #expr))
+        });
+        let mut kind = Arg::token_stream("kind", |fv| {
+            let expr = &fv.expr;
+
+            Ok(quote_spanned!(expr.span()=> #expr))
+        });
+        let mut links = Arg::token_stream("links", |fv| {
+            let expr = &fv.expr;
+
+            Ok(quote_spanned!(expr.span()=> #expr))
+        });
         let mut arg = Arg::ident("arg");
 
         args::set_from_field_values(
             input.parse_terminated(FieldValue::parse, Token![,])?.iter(),
-            [&mut module, &mut arg, &mut rt, &mut when],
+            [
+                &mut module,
+                &mut arg,
+                &mut rt,
+                &mut when,
+                &mut begin,
+                &mut kind,
+                &mut links,
+            ],
         )?;
 
         Ok(Args {
             rt: rt.take_rt()?,
             module: module.take().unwrap_or_else(|| module_tokens()),
             when: when.take_when(),
+            begin: begin
+                .take()
+                .map(|begin| quote!(Some(#begin)))
+                .unwrap_or_else(|| quote!(None)),
+            kind: kind.take(),
+            links: links.take(),
             arg: arg.take(),
         })
     }
@@ -66,8 +98,10 @@ pub fn expand_tokens(opts: ExpandTokens) -> Result<TokenStream, syn::Error> {
     let template =
         template.ok_or_else(|| syn::Error::new(span, "missing template string literal"))?;
 
-    let mut evt_props = Props::new();
-    push_event_props(&mut evt_props, opts.level)?;
+    let evt_props = span_evt_props(opts.level.clone(), args.kind.as_ref(), false)?;
+
+    // The links are only added to the completed span event
+    let complete_props = span_evt_props(opts.level, args.kind.as_ref(), args.links.is_some())?;
 
     let span_arg = args
         .arg
@@ -89,9 +123,12 @@ pub fn expand_tokens(opts: ExpandTokens) -> Result<TokenStream, syn::Error> {
                 &args.rt,
                 &module_tokens,
                 &args.when,
+                &args.begin,
                 &template,
                 &ctxt_props,
                 &evt_props,
+                &complete_props,
+                args.links.as_ref(),
                 &span_arg,
                 quote!(#block),
             ))?;
@@ -102,9 +139,12 @@ pub fn expand_tokens(opts: ExpandTokens) -> Result<TokenStream, syn::Error> {
                 &args.rt,
                 &module_tokens,
                 &args.when,
+                &args.begin,
                 &template,
                 &ctxt_props,
                 &evt_props,
+                &complete_props,
+                args.links.as_ref(),
                 &span_arg,
                 quote!(#block),
             ))?;
@@ -121,9 +161,12 @@ pub fn expand_tokens(opts: ExpandTokens) -> Result<TokenStream, syn::Error> {
                 &args.rt,
                 &module_tokens,
                 &args.when,
+                &args.begin,
                 &template,
                 &ctxt_props,
                 &evt_props,
+                &complete_props,
+                args.links.as_ref(),
                 &span_arg,
                 quote!(#block),
             ))?;
@@ -134,9 +177,12 @@ pub fn expand_tokens(opts: ExpandTokens) -> Result<TokenStream, syn::Error> {
                 &args.rt,
                 &module_tokens,
                 &args.when,
+                &args.begin,
                 &template,
                 &ctxt_props,
                 &evt_props,
+                &complete_props,
+                args.links.as_ref(),
                 &span_arg,
                 quote!(#block),
             ))?;
@@ -151,22 +197,29 @@ fn inject_sync(
     rt_tokens: &TokenStream,
     module_tokens: &TokenStream,
     when_tokens: &TokenStream,
+    begin_tokens: &TokenStream,
     template: &Template,
     ctxt_props: &Props,
     evt_props: &Props,
+    complete_props: &Props,
+    links: Option<&TokenStream>,
     span_arg: &Ident,
     body: TokenStream,
 ) -> TokenStream {
     let ctxt_props_tokens = ctxt_props.props_tokens();
     let evt_props_tokens = evt_props.props_tokens();
+    let complete_props_tokens = complete_props.props_tokens();
+    let links_tokens = links_tokens(links);
     let template_tokens = template.template_tokens();
     let template_literal_tokens = template.template_literal_tokens();
 
     quote!({
+        #links_tokens
         let (mut __ctxt, __span_arg) = emit::__private::__private_begin_span(
             #rt_tokens,
             #module_tokens,
             #when_tokens,
+            #begin_tokens,
             #template_tokens,
             #ctxt_props_tokens,
             #evt_props_tokens,
@@ -176,7 +229,7 @@ fn inject_sync(
                     #rt_tokens,
                     span,
                     #template_tokens,
-                    #evt_props_tokens,
+                    #complete_props_tokens,
                 )
             }
         );
@@ -192,22 +245,29 @@ fn inject_async(
     rt_tokens: &TokenStream,
     module_tokens: &TokenStream,
     when_tokens: &TokenStream,
+    begin_tokens: &TokenStream,
     template: &Template,
     ctxt_props: &Props,
     evt_props: &Props,
+    complete_props: &Props,
+    links: Option<&TokenStream>,
     span_arg: &Ident,
     body: TokenStream,
 ) -> TokenStream {
     let ctxt_props_tokens = ctxt_props.props_tokens();
     let evt_props_tokens = evt_props.props_tokens();
+    let complete_props_tokens = complete_props.props_tokens();
+    let links_tokens = links_tokens(links);
     let template_tokens = template.template_tokens();
     let template_literal_tokens = template.template_literal_tokens();
 
     quote!({
+        #links_tokens
         let (__ctxt, __span_arg) = emit::__private::__private_begin_span(
             #rt_tokens,
             #module_tokens,
             #when_tokens,
+            #begin_tokens,
             #template_tokens,
             #ctxt_props_tokens,
             #evt_props_tokens,
@@ -217,7 +277,7 @@ fn inject_async(
                     #rt_tokens,
                     span,
                     #template_tokens,
-                    #evt_props_tokens,
+                    #complete_props_tokens,
                 )
             }
         );
@@ -229,3 +289,46 @@ fn inject_async(
         }).await
     })
 }
+
+fn span_evt_props(
+    level: Option<TokenStream>,
+    kind: Option<&TokenStream>,
+    links: bool,
+) -> Result<Props, syn::Error> {
+    let mut evt_props = Props::new();
+    push_event_props(&mut evt_props, level)?;
+
+    // Add the kind as an event property
+    // It only describes this span, so it isn't pushed to the ambient context
+    // where it would be inherited by nested spans and events
+    if let Some(kind_value) = kind {
+        let kind_ident = Ident::new(emit_core::well_known::KEY_SPAN_KIND, Span::call_site());
+
+        evt_props.push(
+            &syn::parse2::<FieldValue>(quote!(#kind_ident: #kind_value))?,
+            false,
+            true,
+        )?;
+    }
+
+    // Add the links as an event property
+    // They're borrowed once when the span begins, by `links_tokens`
+    if links {
+        let links_ident = Ident::new(emit_core::well_known::KEY_SPAN_LINKS, Span::call_site());
+
+        evt_props.push(
+            &syn::parse2::<FieldValue>(quote!(#links_ident: __span_links))?,
+            false,
+            true,
+        )?;
+    }
+
+    Ok(evt_props)
+}
+
+fn links_tokens(links: Option<&TokenStream>) -> TokenStream {
+    match links {
+        Some(links) => quote!(let __span_links = &(#links);),
+        None => quote!(),
+    }
+}
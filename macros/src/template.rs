@@ -1,8 +1,11 @@
 use std::collections::BTreeMap;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream, TokenTree};
 
-use syn::{parse::Parse, punctuated::Punctuated, spanned::Spanned, Expr, ExprPath, FieldValue};
+use syn::{
+    parse::Parse, punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprPath, FieldValue,
+    LitStr,
+};
 
 use crate::{fmt, props::Props, util::FieldValueKey};
 
@@ -10,8 +13,8 @@ pub fn parse2<A: Parse>(
     input: TokenStream,
     captured: bool,
 ) -> Result<(A, Option<Template>, Props), syn::Error> {
-    let template =
-        fv_template::Template::parse2(input).map_err(|e| syn::Error::new(e.span(), e))?;
+    let template = fv_template::Template::parse2(expand_format_specs(input))
+        .map_err(|e| syn::Error::new(e.span(), e))?;
 
     // Parse args from the field values before the template
     let args = {
@@ -42,7 +45,8 @@ pub fn parse2<A: Parse>(
             Some(extra_fv) => {
                 if let Expr::Path(ExprPath { ref path, .. }) = fv.expr {
                     // Make sure the field-value in the template is just a plain identifier
-                    if !fv.attrs.is_empty() {
+                    // The exception is a format spec, like `{x:.2}`, which is carried over to the extra pair
+                    if fv.attrs.iter().any(|attr| !is_fmt_attr(attr)) {
                         return Err(syn::Error::new(fv.span(), "keys that exist in the template and extra pairs can only use attributes on the extra pair"));
                     }
 
@@ -58,7 +62,14 @@ pub fn parse2<A: Parse>(
                     ));
                 }
 
-                props.push(extra_fv, true, captured)?;
+                if fv.attrs.is_empty() {
+                    props.push(extra_fv, true, captured)?;
+                } else {
+                    let mut extra_fv = extra_fv.clone();
+                    extra_fv.attrs.extend(fv.attrs.iter().cloned());
+
+                    props.push(&extra_fv, true, captured)?;
+                }
             }
             None => {
                 props.push(fv, true, captured)?;
@@ -185,3 +196,183 @@ impl<'a> fv_template::LiteralVisitor for TemplateVisitor<'a> {
         parts.push(quote!(emit::template::Part::text(#text)));
     }
 }
+
+fn is_fmt_attr(attr: &Attribute) -> bool {
+    let path = attr.path();
+
+    path.segments.len() == 2 && path.segments[0].ident == "emit" && path.segments[1].ident == "fmt"
+}
+
+/**
+Rewrite holes with format specs, like `{x:>8.2}`, into holes with an `emit::fmt` attribute, like `{#[emit::fmt(">8.2")] x}`.
+
+Only the first standalone string literal in the input is considered the template.
+*/
+fn expand_format_specs(input: TokenStream) -> TokenStream {
+    let mut output = Vec::new();
+    let mut item_start = 0;
+    let mut expanded = false;
+
+    for tt in input {
+        if let TokenTree::Punct(ref punct) = tt {
+            if punct.as_char() == ',' {
+                if !expanded && output.len() == item_start + 1 {
+                    if let Some(lit) = expand_format_specs_lit(&output[item_start]) {
+                        output[item_start] = lit;
+                        expanded = true;
+                    }
+                }
+
+                output.push(tt);
+                item_start = output.len();
+
+                continue;
+            }
+        }
+
+        output.push(tt);
+    }
+
+    if !expanded && output.len() == item_start + 1 {
+        if let Some(lit) = expand_format_specs_lit(&output[item_start]) {
+            output[item_start] = lit;
+        }
+    }
+
+    output.into_iter().collect()
+}
+
+fn expand_format_specs_lit(tt: &TokenTree) -> Option<TokenTree> {
+    let TokenTree::Literal(_) = tt else {
+        return None;
+    };
+
+    let lit = syn::parse2::<LitStr>(tt.clone().into()).ok()?;
+    let tpl = lit.value();
+
+    let mut expanded = String::new();
+    let mut chars = tpl.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                expanded.push_str("{{");
+            }
+            '{' => {
+                let mut depth = 1;
+                let mut hole = String::new();
+
+                for c in chars.by_ref() {
+                    match c {
+                        '{' => depth += 1,
+                        '}' => {
+                            depth -= 1;
+
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => (),
+                    }
+
+                    hole.push(c);
+                }
+
+                expanded.push('{');
+                expanded.push_str(&expand_format_spec_hole(&hole).unwrap_or(hole));
+                expanded.push('}');
+            }
+            c => expanded.push(c),
+        }
+    }
+
+    let mut expanded_lit = Literal::string(&expanded);
+    expanded_lit.set_span(lit.span());
+
+    Some(TokenTree::Literal(expanded_lit))
+}
+
+fn expand_format_spec_hole(hole: &str) -> Option<String> {
+    // Skip over any attributes, like `#[emit::as_debug]`
+    let mut rest = hole.trim_start();
+    while let Some(attr) = rest.strip_prefix("#[") {
+        let mut depth = 1;
+        let end = attr.find(|c| {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                _ => (),
+            }
+
+            depth == 0
+        })?;
+
+        rest = attr[end + 1..].trim_start();
+    }
+
+    let attrs = &hole[..hole.len() - rest.len()];
+
+    let ident_end = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    let (ident, rest) = rest.split_at(ident_end);
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let spec = rest.strip_prefix(':')?;
+
+    if spec.starts_with(':') || !is_format_spec(spec) {
+        return None;
+    }
+
+    Some(format!("{attrs}#[emit::fmt({spec:?})] {ident}"))
+}
+
+/**
+Whether the input is a standard format spec.
+
+The syntax for format specs is `[[fill]align][sign]['#']['0'][width]['.' precision][type]`. Inputs that are also valid expressions, like `5` or `x?`, are field values rather than format specs.
+*/
+fn is_format_spec(spec: &str) -> bool {
+    !spec.is_empty()
+        && syn::parse_str::<Expr>(spec).is_err()
+        && emit_core::template::FormatSpec::parse(spec).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_spec_holes() {
+        for (hole, expected) in [
+            ("x:.2", Some("#[emit::fmt(\".2\")] x")),
+            ("x:>8.2", Some("#[emit::fmt(\">8.2\")] x")),
+            ("x:?", Some("#[emit::fmt(\"?\")] x")),
+            ("x:*^10", Some("#[emit::fmt(\"*^10\")] x")),
+            (
+                "#[emit::as_debug] x:<5",
+                Some("#[emit::as_debug] #[emit::fmt(\"<5\")] x"),
+            ),
+            // Field values that are also valid format specs stay field values
+            ("x:5", None),
+            ("x:0", None),
+            ("x:-1", None),
+            ("x:1.5", None),
+            ("x:x?", None),
+            ("x:X?", None),
+            ("x:y", None),
+            ("x", None),
+            ("x::y", None),
+        ] {
+            assert_eq!(
+                expected.map(String::from),
+                expand_format_spec_hole(hole),
+                "{hole}"
+            );
+        }
+    }
+}
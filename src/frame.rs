@@ -1,5 +1,33 @@
 /*!
 The [`Frame`] type.
+
+Frames capture a set of ambient properties so they can be moved across threads. With the `implicit_rt` Cargo feature, helpers are provided to propagate the current frame from the shared runtime into new threads:
+
+- [`spawn_in_current`] spawns a thread that runs a closure in the current frame.
+- [`in_current`] wraps a closure so it runs in the current frame on whatever thread calls it. This is useful for parallel iterators.
+- [`join_in_current`] runs two closures in the current frame through [`rayon::join`]. This requires the `rayon` Cargo feature.
+
+```
+# #[cfg(not(all(feature = "std", feature = "implicit_rt")))] fn main() {}
+# #[cfg(all(feature = "std", feature = "implicit_rt"))] fn main() {
+use emit::{Ctxt, Props};
+
+let rt = emit::setup().init();
+
+emit::Frame::push(emit::runtime::shared().ctxt(), emit::props! { user: "Rust" }).call(|| {
+    let handle = emit::frame::spawn_in_current(|| {
+        // `user` is visible on the spawned thread
+        emit::runtime::shared()
+            .ctxt()
+            .with_current(|props| props.get("user").map(|user| user.to_string()))
+    });
+
+    assert_eq!(Some("Rust"), handle.join().unwrap().as_deref());
+});
+
+rt.blocking_flush(std::time::Duration::from_secs(5));
+# }
+```
 */
 
 use core::{
@@ -160,3 +188,184 @@ impl<C: Ctxt, F: Future> Future for FrameFuture<C, F> {
         unsafe { Pin::new_unchecked(&mut unpinned.future) }.poll(cx)
     }
 }
+
+/**
+Spawn a thread that runs `f` in the current frame of the shared runtime.
+
+A bare [`std::thread::spawn`] doesn't carry ambient properties, like the current `trace_id` and `span_id`, into the new thread. This function captures the current [`Frame`] and calls `f` inside it on the new thread.
+*/
+#[cfg(all(feature = "std", feature = "implicit_rt"))]
+pub fn spawn_in_current<F, T>(f: F) -> std::thread::JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let frame = Frame::current(crate::runtime::shared().ctxt());
+
+    std::thread::spawn(move || frame.call(f))
+}
+
+/**
+Wrap `f` so it's called in the current frame of the shared runtime, regardless of the thread it's called on.
+
+The current properties are captured once, when this function is called. Each call to the returned closure enters a new frame with those properties, so it can be called concurrently from many threads. This makes it suitable for the closures passed to parallel iterators:
+
+```
+# #[cfg(not(all(feature = "std", feature = "implicit_rt")))] fn main() {}
+# #[cfg(all(feature = "std", feature = "implicit_rt"))] fn main() {
+# fn process(_: i32) {}
+# let items = vec![1, 2, 3];
+# let rt = emit::setup().init();
+// With `rayon`: `items.par_iter().for_each(...)`
+items.iter().copied().for_each(emit::frame::in_current(|item: i32| {
+    emit::info!("processing {item}");
+
+    process(item);
+}));
+# }
+```
+*/
+#[cfg(all(feature = "std", feature = "implicit_rt"))]
+pub fn in_current<T, R>(f: impl Fn(T) -> R + Send + Sync) -> impl Fn(T) -> R + Send + Sync {
+    let ctxt = crate::runtime::shared().ctxt();
+    let props = ctxt.with_current(|current| emit_core::props::OwnedProps::collect_shared(current));
+
+    move |arg| Frame::root(ctxt, &props).call(|| f(arg))
+}
+
+/**
+Run `oper_a` and `oper_b` in the current frame of the shared runtime, potentially in parallel, through [`rayon::join`].
+
+A bare [`rayon::join`] doesn't carry ambient properties into `oper_b` if it's stolen by another thread. This function captures the current [`Frame`] and calls both closures inside it.
+*/
+#[cfg(all(feature = "rayon", feature = "implicit_rt"))]
+pub fn join_in_current<A, B, RA, RB>(oper_a: A, oper_b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    let ctxt = crate::runtime::shared().ctxt();
+
+    let frame_a = Frame::current(ctxt);
+    let frame_b = Frame::current(ctxt);
+
+    rayon::join(move || frame_a.call(oper_a), move || frame_b.call(oper_b))
+}
+
+#[cfg(all(test, feature = "rayon", feature = "implicit_rt"))]
+mod tests {
+    use super::*;
+
+    use std::{
+        sync::{mpsc, Mutex, Once},
+        thread,
+        time::Duration,
+    };
+
+    use crate::{
+        span::{SpanCtxt, SpanId, TraceId},
+        well_known::{KEY_SPAN_ID, KEY_TRACE_ID},
+    };
+
+    fn init() {
+        static INIT: Once = Once::new();
+
+        INIT.call_once(|| {
+            let _ = crate::setup().init();
+        });
+    }
+
+    fn current_span() -> (Option<TraceId>, Option<SpanId>) {
+        crate::runtime::shared()
+            .ctxt()
+            .with_current(|props| (props.pull(KEY_TRACE_ID), props.pull(KEY_SPAN_ID)))
+    }
+
+    fn span_ctxt() -> SpanCtxt {
+        SpanCtxt::new(TraceId::from_u128(1), None, SpanId::from_u64(1))
+    }
+
+    #[test]
+    fn in_current_par_iter() {
+        use rayon::prelude::*;
+
+        init();
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+
+        let items = (0..64).collect::<Vec<i32>>();
+        let seen = Mutex::new(Vec::new());
+
+        let f = Frame::push(crate::runtime::shared().ctxt(), span_ctxt()).call(|| {
+            in_current(|_: &i32| {
+                seen.lock()
+                    .unwrap()
+                    .push((thread::current().id(), current_span()));
+            })
+        });
+
+        pool.install(|| items.par_iter().for_each(&f));
+        drop(f);
+
+        let seen = seen.into_inner().unwrap();
+
+        assert_eq!(64, seen.len());
+
+        for (thread, span) in seen {
+            // The closure only ever runs on the pool's worker threads
+            assert_ne!(thread::current().id(), thread);
+            assert_eq!(
+                (TraceId::from_u128(1), SpanId::from_u64(1)),
+                span,
+                "{:?}",
+                thread
+            );
+        }
+
+        // The frame doesn't leak into the worker threads outside of the closure
+        assert_eq!((None, None), pool.install(current_span));
+    }
+
+    #[test]
+    fn join_in_current_stolen() {
+        init();
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+
+        let (tx, rx) = mpsc::channel();
+
+        let ((a_thread, a_span), (b_thread, b_span)) = pool.install(|| {
+            Frame::push(crate::runtime::shared().ctxt(), span_ctxt()).call(|| {
+                join_in_current(
+                    move || {
+                        // Block until `oper_b` has run, which forces it to be stolen by another worker
+                        rx.recv_timeout(Duration::from_secs(10))
+                            .expect("`oper_b` was not stolen");
+
+                        (thread::current().id(), current_span())
+                    },
+                    move || {
+                        let b = (thread::current().id(), current_span());
+
+                        tx.send(()).unwrap();
+
+                        b
+                    },
+                )
+            })
+        });
+
+        assert_ne!(a_thread, b_thread);
+
+        assert_eq!((TraceId::from_u128(1), SpanId::from_u64(1)), a_span);
+        assert_eq!((TraceId::from_u128(1), SpanId::from_u64(1)), b_span);
+    }
+}
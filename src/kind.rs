@@ -9,7 +9,10 @@ use emit_core::{
     filter::Filter,
     props::Props,
     value::{FromValue, ToValue, Value},
-    well_known::{EVENT_KIND_METRIC, EVENT_KIND_SPAN, KEY_EVENT_KIND},
+    well_known::{
+        EVENT_KIND_METRIC, EVENT_KIND_SPAN, EVENT_KIND_SPAN_BEGIN, EVENT_KIND_SPAN_EVENT,
+        KEY_EVENT_KIND,
+    },
 };
 
 /**
@@ -28,6 +31,12 @@ match evt.props().pull::<emit::Kind, _>(emit::well_known::KEY_EVENT_KIND) {
     Some(emit::Kind::Metric) => {
         // The event is a metric
     }
+    Some(emit::Kind::SpanEvent) => {
+        // The event is an annotation on a span
+    }
+    Some(emit::Kind::SpanBegin) => {
+        // The event marks the beginning of a span
+    }
     Some(_) => {
         // The event is an unknown kind
     }
@@ -53,6 +62,18 @@ pub enum Kind {
     This variant is equal to [`EVENT_KIND_METRIC`]. See the [`mod@crate::metric`] module for details.
     */
     Metric,
+    /**
+    The event is a timestamped annotation on a span in a distributed trace.
+
+    This variant is equal to [`EVENT_KIND_SPAN_EVENT`]. See the [`mod@crate::span`] module for details.
+    */
+    SpanEvent,
+    /**
+    The event marks the beginning of a span in a distributed trace.
+
+    This variant is equal to [`EVENT_KIND_SPAN_BEGIN`]. See the [`mod@crate::span`] module for details.
+    */
+    SpanBegin,
 }
 
 impl fmt::Debug for Kind {
@@ -66,6 +87,8 @@ impl fmt::Display for Kind {
         match self {
             Kind::Span => f.write_str(EVENT_KIND_SPAN),
             Kind::Metric => f.write_str(EVENT_KIND_METRIC),
+            Kind::SpanEvent => f.write_str(EVENT_KIND_SPAN_EVENT),
+            Kind::SpanBegin => f.write_str(EVENT_KIND_SPAN_BEGIN),
         }
     }
 }
@@ -97,6 +120,14 @@ impl FromStr for Kind {
             return Ok(Kind::Metric);
         }
 
+        if s.eq_ignore_ascii_case(EVENT_KIND_SPAN_EVENT) {
+            return Ok(Kind::SpanEvent);
+        }
+
+        if s.eq_ignore_ascii_case(EVENT_KIND_SPAN_BEGIN) {
+            return Ok(Kind::SpanBegin);
+        }
+
         Err(ParseKindError {})
     }
 }
@@ -150,6 +181,24 @@ pub fn is_metric_filter() -> KindFilter {
     KindFilter::new(Kind::Metric)
 }
 
+/**
+Only match events that are annotations on spans.
+
+Events that match must carry a [`Kind::SpanEvent`].
+*/
+pub fn is_span_event_filter() -> KindFilter {
+    KindFilter::new(Kind::SpanEvent)
+}
+
+/**
+Only match events that mark the beginning of spans.
+
+Events that match must carry a [`Kind::SpanBegin`].
+*/
+pub fn is_span_begin_filter() -> KindFilter {
+    KindFilter::new(Kind::SpanBegin)
+}
+
 impl Filter for KindFilter {
     fn matches<E: ToEvent>(&self, evt: E) -> bool {
         evt.to_event().props().pull::<Kind, _>(KEY_EVENT_KIND) == Some(self.0)
@@ -230,14 +230,16 @@ impl InternalFilter for MinLevelFilter {}
 mod alloc_support {
     use super::*;
 
-    use alloc::vec::Vec;
+    use alloc::{borrow::ToOwned, string::String, vec::Vec};
 
-    use emit_core::path::Path;
+    use emit_core::path::{Path, PathPattern};
 
     /**
     Construct a set of [`MinLevelFilter`]s that are applied based on the module of an event.
+
+    Modules can be given as literal paths, like `my_app::db`, or as a [`PathPattern`], like `**::db`. Strings are parsed as patterns, while [`Path`]s are always treated literally.
     */
-    pub fn min_by_path_filter<P: Into<Path<'static>>, L: Into<MinLevelFilter>>(
+    pub fn min_by_path_filter<P: Into<PathPattern>, L: Into<MinLevelFilter>>(
         levels: impl IntoIterator<Item = (P, L)>,
     ) -> MinLevelPathMap {
         MinLevelPathMap::from_iter(levels)
@@ -248,11 +250,14 @@ mod alloc_support {
 
     This type allows different modules to apply different level filters. In particular, modules generating a lot of diagnostic noise can be silenced without affecting other modules.
 
-    Event modules are matched based on [`Path::is_child_of`]. If an event's module is a child of one in the map then its [`MinLevelFilter`] will be checked against it. If an event's module doesn't match any in the map then it will pass the filter.
+    Event modules are matched based on [`Path::is_child_of`], or [`PathPattern::matches_path`] for paths containing wildcards. If an event's module matches one in the map then its [`MinLevelFilter`] will be checked against it. If an event's module matches multiple paths in the map then the most specific one, with the most non-`**` segments, will be used. Literal paths are more specific than patterns with the same number of segments. If an event's module doesn't match any in the map then it will be checked against the default set by [`MinLevelPathMap::default_min_level`], or pass the filter if there isn't one.
+
+    Maps can also be parsed from text through [`MinLevelPathMap::parse`].
     */
     pub struct MinLevelPathMap {
-        // TODO: Ensure more specific paths apply ahead of less specific ones
+        default: Option<MinLevelFilter>,
         paths: Vec<(Path<'static>, MinLevelFilter)>,
+        patterns: Vec<(PathPattern, MinLevelFilter)>,
     }
 
     impl MinLevelPathMap {
@@ -260,18 +265,98 @@ mod alloc_support {
         Create an empty map.
         */
         pub const fn new() -> Self {
-            MinLevelPathMap { paths: Vec::new() }
+            MinLevelPathMap {
+                default: None,
+                paths: Vec::new(),
+                patterns: Vec::new(),
+            }
+        }
+
+        /**
+        Parse a map from a comma-separated list of directives.
+
+        Each directive is either a level, like `info`, or a path and level separated by `=`, like `my_app::db=debug`. Paths may contain wildcards supported by [`PathPattern`], like `**::db=debug`. A directive with just a level sets the [`MinLevelPathMap::default_min_level`]. A directive with a path sets the [`MinLevelPathMap::min_level`] for that path. Levels are parsed using [`Level`]'s [`FromStr`] implementation. Whitespace around directives and their parts is ignored.
+
+        ```
+        let filter = emit::level::MinLevelPathMap::parse("info,my_app::db=debug,hyper=warn").unwrap();
+        # let _ = filter;
+        ```
+
+        Commas inside the `{a, b}` alternatives of a [`PathPattern`] don't split directives:
+
+        ```
+        let filter = emit::level::MinLevelPathMap::parse("info,my_app::{api,jobs}=debug").unwrap();
+        # let _ = filter;
+        ```
+
+        If any directive is invalid then this method will return an error describing it.
+        */
+        pub fn parse(filter: &str) -> Result<Self, ParseMinLevelPathMapError> {
+            let mut map = MinLevelPathMap::new();
+
+            for directive in split_directives(filter) {
+                let directive = directive.trim();
+
+                if directive.is_empty() {
+                    continue;
+                }
+
+                match directive.split_once('=') {
+                    Some((path, level)) => {
+                        let path = path.trim();
+                        let level = level.trim();
+
+                        let pattern = PathPattern::parse(path).map_err(|_| {
+                            ParseMinLevelPathMapError::invalid_path(directive, path)
+                        })?;
+
+                        let level = level.parse::<Level>().map_err(|_| {
+                            ParseMinLevelPathMapError::invalid_level(directive, level)
+                        })?;
+
+                        map.min_level(pattern, level);
+                    }
+                    None => {
+                        let level = directive.parse::<Level>().map_err(|_| {
+                            ParseMinLevelPathMapError::invalid_level(directive, directive)
+                        })?;
+
+                        map.default_min_level(level);
+                    }
+                }
+            }
+
+            Ok(map)
         }
 
         /**
         Specify the minimum level for a module and its children.
+
+        The `path` may be a literal path, like `my_app::db`, or a [`PathPattern`], like `**::db`. Strings are parsed as patterns, while [`Path`]s are always treated literally.
         */
         pub fn min_level(
             &mut self,
-            path: impl Into<Path<'static>>,
+            path: impl Into<PathPattern>,
             min_level: impl Into<MinLevelFilter>,
         ) {
-            let path = path.into();
+            let pattern = path.into();
+
+            let Some(path) = pattern.to_literal_path() else {
+                match self
+                    .patterns
+                    .iter()
+                    .position(|(existing, _)| *existing == pattern)
+                {
+                    Some(index) => {
+                        self.patterns[index] = (pattern, min_level.into());
+                    }
+                    None => {
+                        self.patterns.push((pattern, min_level.into()));
+                    }
+                }
+
+                return;
+            };
 
             match self.paths.binary_search_by_key(&&path, |(path, _)| path) {
                 Ok(index) => {
@@ -282,6 +367,13 @@ mod alloc_support {
                 }
             }
         }
+
+        /**
+        Specify the minimum level for modules that don't match any path in the map.
+        */
+        pub fn default_min_level(&mut self, min_level: impl Into<MinLevelFilter>) {
+            self.default = Some(min_level.into());
+        }
     }
 
     impl Filter for MinLevelPathMap {
@@ -290,23 +382,47 @@ mod alloc_support {
 
             let evt_path = evt.module();
 
-            if let Ok(index) = self.paths.binary_search_by_key(&evt_path, |(path, _)| path) {
-                self.paths[index].1.matches(evt)
-            } else {
-                for (path, min_level) in &self.paths {
-                    if evt_path.is_child_of(path) {
-                        return min_level.matches(evt);
-                    }
+            let mut matched =
+                if let Ok(index) = self.paths.binary_search_by_key(&evt_path, |(path, _)| path) {
+                    Some((&self.paths[index].0, &self.paths[index].1))
+                } else {
+                    // Paths are sorted, so any parent of the event's module will
+                    // sort before its own parents. Searching from the end finds
+                    // the most specific match first
+                    self.paths
+                        .iter()
+                        .rev()
+                        .find(|(path, _)| evt_path.is_child_of(path))
+                        .map(|(path, min_level)| (path, min_level))
                 }
+                .map(|(path, min_level)| (specificity(path), min_level));
 
-                true
+            for (pattern, min_level) in &self.patterns {
+                if !pattern.matches_path(evt_path) {
+                    continue;
+                }
+
+                let pattern_specificity = pattern.specificity();
+
+                if matched.map(|(specificity, _)| pattern_specificity > specificity) != Some(false)
+                {
+                    matched = Some((pattern_specificity, min_level));
+                }
+            }
+
+            match matched {
+                Some((_, min_level)) => min_level.matches(evt),
+                None => match self.default {
+                    Some(ref min_level) => min_level.matches(evt),
+                    None => true,
+                },
             }
         }
     }
 
     impl InternalFilter for MinLevelPathMap {}
 
-    impl<P: Into<Path<'static>>, L: Into<MinLevelFilter>> FromIterator<(P, L)> for MinLevelPathMap {
+    impl<P: Into<PathPattern>, L: Into<MinLevelFilter>> FromIterator<(P, L)> for MinLevelPathMap {
         fn from_iter<T: IntoIterator<Item = (P, L)>>(iter: T) -> Self {
             let mut map = MinLevelPathMap::new();
 
@@ -317,11 +433,150 @@ mod alloc_support {
             map
         }
     }
+
+    impl FromStr for MinLevelPathMap {
+        type Err = ParseMinLevelPathMapError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            MinLevelPathMap::parse(s)
+        }
+    }
+
+    fn specificity(path: &Path) -> usize {
+        path.segments().count()
+    }
+
+    fn split_directives(filter: &str) -> impl Iterator<Item = &str> {
+        let mut depth = 0usize;
+
+        // Commas only separate directives outside of `{a, b}` alternatives
+        filter.split(move |c| match c {
+            '{' => {
+                depth += 1;
+                false
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                false
+            }
+            ',' => depth == 0,
+            _ => false,
+        })
+    }
+
+    /**
+    An error attempting to parse a [`MinLevelPathMap`] from text.
+    */
+    #[derive(Debug)]
+    pub struct ParseMinLevelPathMapError {
+        kind: ParseMinLevelPathMapErrorKind,
+    }
+
+    #[derive(Debug)]
+    enum ParseMinLevelPathMapErrorKind {
+        Path {
+            directive: String,
+            path: String,
+        },
+        Level {
+            directive: String,
+            level: String,
+        },
+        #[cfg(feature = "std")]
+        Var {
+            var: String,
+        },
+    }
+
+    impl ParseMinLevelPathMapError {
+        fn invalid_path(directive: &str, path: &str) -> Self {
+            ParseMinLevelPathMapError {
+                kind: ParseMinLevelPathMapErrorKind::Path {
+                    directive: directive.to_owned(),
+                    path: path.to_owned(),
+                },
+            }
+        }
+
+        fn invalid_level(directive: &str, level: &str) -> Self {
+            ParseMinLevelPathMapError {
+                kind: ParseMinLevelPathMapErrorKind::Level {
+                    directive: directive.to_owned(),
+                    level: level.to_owned(),
+                },
+            }
+        }
+
+        #[cfg(feature = "std")]
+        pub(super) fn invalid_var(var: &str) -> Self {
+            ParseMinLevelPathMapError {
+                kind: ParseMinLevelPathMapErrorKind::Var {
+                    var: var.to_owned(),
+                },
+            }
+        }
+    }
+
+    impl fmt::Display for ParseMinLevelPathMapError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self.kind {
+                ParseMinLevelPathMapErrorKind::Path {
+                    ref directive,
+                    ref path,
+                } => write!(
+                    f,
+                    "the directive `{}` has an invalid path `{}`",
+                    directive, path
+                ),
+                ParseMinLevelPathMapErrorKind::Level {
+                    ref directive,
+                    ref level,
+                } => write!(
+                    f,
+                    "the directive `{}` has an invalid level `{}`; expected one of `debug`, `info`, `warn`, or `error`",
+                    directive, level
+                ),
+                #[cfg(feature = "std")]
+                ParseMinLevelPathMapErrorKind::Var { ref var } => write!(
+                    f,
+                    "the environment variable `{}` is not valid unicode",
+                    var
+                ),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for ParseMinLevelPathMapError {}
 }
 
 #[cfg(feature = "alloc")]
 pub use self::alloc_support::*;
 
+#[cfg(feature = "std")]
+mod std_support {
+    use super::*;
+
+    use std::env;
+
+    impl MinLevelPathMap {
+        /**
+        Parse a map from the environment variable `var`.
+
+        The value of the variable uses the same syntax as [`MinLevelPathMap::parse`]. If the variable isn't set then an empty map that matches all events is returned.
+        */
+        pub fn from_env(var: &str) -> Result<Self, ParseMinLevelPathMapError> {
+            match env::var(var) {
+                Ok(filter) => MinLevelPathMap::parse(&filter),
+                Err(env::VarError::NotPresent) => Ok(MinLevelPathMap::new()),
+                Err(env::VarError::NotUnicode(_)) => {
+                    Err(ParseMinLevelPathMapError::invalid_var(var))
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,4 +591,55 @@ mod tests {
             assert_eq!(lvl, parsed, "{}", fmt);
         }
     }
+
+    #[test]
+    fn min_level_path_map_parse() {
+        use emit_core::{event::Event, path::Path, template::Template};
+
+        let map = MinLevelPathMap::parse(
+            " warn, a = info ,a::b=debug,, ::c=error, **::db=error, a::*::db=debug, d::{e, f}=debug",
+        )
+        .unwrap();
+
+        for (path, lvl, matches) in [
+            ("a", Level::Info, true),
+            ("a", Level::Debug, false),
+            ("a::c", Level::Debug, false),
+            ("a::b", Level::Debug, true),
+            ("a::b::c", Level::Debug, true),
+            ("b", Level::Info, false),
+            ("b", Level::Warn, true),
+            ("::c", Level::Warn, false),
+            ("b::db", Level::Warn, false),
+            ("b::db::pool", Level::Error, true),
+            ("a::db", Level::Info, true),
+            ("a::b::db", Level::Debug, true),
+            ("a::c::db", Level::Debug, true),
+            ("d::e", Level::Debug, true),
+            ("d::f::g", Level::Debug, true),
+            ("d::g", Level::Debug, false),
+        ] {
+            let evt = Event::new(
+                Path::new(path),
+                crate::Empty,
+                Template::literal("test"),
+                (KEY_LVL, lvl),
+            );
+
+            assert_eq!(matches, map.matches(&evt), "{} {}", path, lvl);
+        }
+
+        for invalid in [
+            "verbose",
+            "a=",
+            "=info",
+            "a::=info",
+            "a::{b,}=info",
+            "a::{b,c=info",
+            "a::b,c}=info",
+            "a:b=info",
+        ] {
+            assert!(MinLevelPathMap::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
 }
@@ -119,6 +119,23 @@ emit::emit!("Hello, {user: \"World\"}!");
 # }
 ```
 
+Holes can also include a format spec after a `:`, using the same syntax as Rust's `format!` macro. Format specs only change the way a property is rendered in the template; they don't change its captured value:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+let elapsed_ms = 1.23456;
+
+emit::emit!("Request completed in {elapsed_ms:>8.2}ms");
+# }
+```
+
+```text
+Request completed in     1.23ms
+```
+
+Format specs support fill, alignment, sign, width, and precision, along with the `?` type for formatting properties using their [`std::fmt::Debug`] implementation. A spec that could also be a field value expression, like `{x:5}`, `{x:1.5}`, or `{x:y?}`, is treated as an expression. To only set a width, include an alignment, like `{x:>5}` or `{x:<5}`.
+
 In these examples we've been using the string `"World"` as the property value. Other primitive types such as booleans, integers, floats, and most library-defined datastructures like UUIDs and URIs can be captured by default in templates.
 
 ### Properties outside templates
@@ -599,6 +616,10 @@ mod macro_hooks;
 pub mod setup;
 #[cfg(feature = "std")]
 pub use setup::{setup, Setup};
+#[cfg(feature = "std")]
+pub mod redact;
+#[cfg(feature = "std")]
+pub mod testing;
 
 #[doc(hidden)]
 pub mod __private {
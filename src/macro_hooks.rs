@@ -17,6 +17,7 @@ use emit_core::{
     template::{Formatter, Part, Template},
     timestamp::Timestamp,
     value::{ToValue, Value},
+    well_known::{KEY_EVENT_KIND, KEY_SPAN_NAME},
 };
 
 use emit_core::{empty::Empty, event::Event};
@@ -27,8 +28,8 @@ use crate::{frame::Frame, span::SpanEvent};
 use std::error::Error;
 
 use crate::{
-    span::{Span, SpanCtxt, SpanId, TraceId},
-    Level, Timer,
+    span::{sampler::Sampler, Span, SpanCtxt, SpanId, TraceId},
+    Kind, Level, Timer,
 };
 
 /**
@@ -570,21 +571,27 @@ pub fn __private_begin_span<
 >(
     rt: &'a Runtime<E, F, C, T, R>,
     module: impl Into<Path<'static>>,
-    when: Option<impl Filter>,
+    when: Option<impl Sampler>,
+    begin: Option<bool>,
     tpl: Template<'b>,
     ctxt_props: impl Props,
     evt_props: impl Props,
     name: impl Into<Str<'static>>,
     default_complete: S,
 ) -> (Frame<Option<&'a C>>, Span<'static, &'a T, Empty, S>) {
-    let mut span = Span::filtered_new(
+    let mut span = Span::sampled_new(
         |span| {
-            FirstDefined(when, rt.filter()).matches(
-                &span
-                    .to_event()
-                    .with_tpl(tpl)
-                    .map_props(|props| props.and_props(&ctxt_props).and_props(&evt_props)),
-            )
+            let span = span
+                .to_event()
+                .with_tpl(tpl.by_ref())
+                .map_props(|props| props.and_props(&ctxt_props).and_props(&evt_props));
+
+            // Only samplers given through `when` can discard a span
+            // The filter on the runtime disables any span it doesn't match
+            match when {
+                Some(when) => when.sample(&span),
+                None => rt.filter().sample(&span),
+            }
         },
         module,
         Timer::start(rt.clock()),
@@ -594,7 +601,25 @@ pub fn __private_begin_span<
         default_complete,
     );
 
-    let frame = span.push_ctxt(rt.ctxt(), ctxt_props);
+    let mut frame = span.push_ctxt(rt.ctxt(), ctxt_props);
+
+    if span.is_enabled() && begin.unwrap_or_else(|| rt.span_begin()) {
+        if let (Some(module), Some(name), Some(timer)) = (span.module(), span.name(), span.timer())
+        {
+            let _guard = frame.enter();
+
+            __private_emit(
+                rt,
+                module.by_ref(),
+                None::<Empty>,
+                timer.start_timestamp(),
+                tpl,
+                (KEY_EVENT_KIND, Kind::SpanBegin)
+                    .and_props((KEY_SPAN_NAME, name))
+                    .and_props(&evt_props),
+            );
+        }
+    }
 
     (frame, span)
 }
@@ -4,53 +4,46 @@ Components provided by the underlying platform.
 This module defines implementations of [`crate::runtime::Runtime`] components that use capabilities of the host platform.
 */
 
-#[cfg(feature = "std")]
-use emit_core::{clock::ErasedClock, rng::ErasedRng, runtime::AssertInternal};
-
 #[cfg(feature = "std")]
 pub mod system_clock;
 
 #[cfg(feature = "std")]
 pub mod thread_local_ctxt;
 
+#[cfg(feature = "tokio")]
+pub mod task_local_ctxt;
+
 #[cfg(feature = "rand")]
 pub mod rand_rng;
 
-#[cfg(feature = "std")]
-type DefaultClock = system_clock::SystemClock;
+/**
+Get the offset of the system's local time zone from UTC.
 
-#[cfg(feature = "rand")]
-type DefaultIdGen = rand_rng::RandRng;
+The offset is determined when this function is called, so it won't follow changes like daylight savings after that. If the offset can't be determined then this function will return `None`. On Unix platforms, the local offset can't be safely determined once the program has started multiple threads, so this function should be called early, like at the start of `main`, and its result kept. See [rust-lang/rust#27970](https://github.com/rust-lang/rust/issues/27970) for details.
+*/
+#[cfg(feature = "local_offset")]
+pub fn local_offset() -> Option<crate::timestamp::Offset> {
+    crate::timestamp::Offset::from_secs(
+        time::UtcOffset::current_local_offset()
+            .ok()?
+            .whole_seconds(),
+    )
+}
 
 /**
-The default [`crate::Ctxt`] to use in [`crate::setup()`].
+The default [`crate::Clock`] to use in [`crate::setup()`].
 */
 #[cfg(feature = "std")]
-pub type DefaultCtxt = thread_local_ctxt::ThreadLocalCtxt;
+pub type DefaultClock = system_clock::SystemClock;
 
 /**
-A type-erased container for system services used when intiailizing runtimes.
+The default [`crate::Rng`] to use in [`crate::setup()`].
 */
-pub(crate) struct Platform {
-    #[cfg(feature = "std")]
-    pub(crate) clock: AssertInternal<Box<dyn ErasedClock + Send + Sync>>,
-    #[cfg(feature = "std")]
-    pub(crate) rng: AssertInternal<Box<dyn ErasedRng + Send + Sync>>,
-}
-
-impl Default for Platform {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+#[cfg(feature = "rand")]
+pub type DefaultRng = rand_rng::RandRng;
 
-impl Platform {
-    pub fn new() -> Self {
-        Platform {
-            #[cfg(feature = "std")]
-            clock: AssertInternal(Box::new(DefaultClock::default())),
-            #[cfg(feature = "std")]
-            rng: AssertInternal(Box::new(DefaultIdGen::default())),
-        }
-    }
-}
+/**
+The default [`crate::Ctxt`] to use in [`crate::setup()`].
+*/
+#[cfg(feature = "std")]
+pub type DefaultCtxt = thread_local_ctxt::ThreadLocalCtxt;
@@ -2,10 +2,17 @@
 The [`SystemClock`] type.
 */
 
+use std::{
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
+
 use emit_core::{clock::Clock, runtime::InternalClock, timestamp::Timestamp};
 
 /**
 A [`Clock`] based on the standard library's [`std::time::SystemTime`].
+
+Monotonic readings are based on the standard library's [`std::time::Instant`], measured from the first time a monotonic reading is taken in the process.
 */
 #[derive(Default, Debug, Clone, Copy)]
 pub struct SystemClock {}
@@ -23,6 +30,12 @@ impl Clock for SystemClock {
     fn now(&self) -> Option<Timestamp> {
         Timestamp::from_unix(std::time::UNIX_EPOCH.elapsed().unwrap_or_default())
     }
+
+    fn monotonic(&self) -> Option<Duration> {
+        static START: OnceLock<Instant> = OnceLock::new();
+
+        Some(START.get_or_init(Instant::now).elapsed())
+    }
 }
 
 impl InternalClock for SystemClock {}
@@ -0,0 +1,227 @@
+/*!
+The [`TaskLocalCtxt`] type.
+
+This module requires the `tokio` Cargo feature.
+
+A bare `tokio::spawn` doesn't carry ambient properties into the spawned task. Use [`spawn`] instead to propagate them, including the current `trace_id` and `span_id`. Configure the [`TaskLocalCtxt`] in [`crate::setup()`] so frames entered within a task stay local to it:
+
+```
+# #[cfg(not(all(feature = "tokio", feature = "implicit_rt")))] fn main() {}
+# #[cfg(all(feature = "tokio", feature = "implicit_rt"))] fn main() {
+use emit::platform::task_local_ctxt::{self, TaskLocalCtxt};
+
+let rt = emit::setup()
+    .with_ctxt(TaskLocalCtxt::shared())
+    .init();
+
+#[emit::span("handle request")]
+async fn handle_request() {
+    // `background_work` will be a child of `handle_request`
+    task_local_ctxt::spawn(background_work()).await.unwrap();
+}
+
+#[emit::span("background work")]
+async fn background_work() {
+    // Your code goes here
+}
+
+tokio::runtime::Builder::new_current_thread()
+    .build()
+    .unwrap()
+    .block_on(handle_request());
+
+rt.blocking_flush(std::time::Duration::from_secs(5));
+# }
+```
+*/
+
+use core::{cell::RefCell, future::Future};
+use std::collections::HashMap;
+
+use emit_core::{ctxt::Ctxt, props::Props, runtime::InternalCtxt};
+
+use crate::{
+    frame::Frame,
+    platform::thread_local_ctxt::{ThreadLocalCtxt, ThreadLocalCtxtFrame},
+};
+
+tokio::task_local! {
+    static ACTIVE: RefCell<HashMap<usize, ThreadLocalCtxtFrame>>;
+}
+
+/**
+A [`Ctxt`] that stores ambient state in [`tokio`] task local storage.
+
+Frames entered within a task, such as through [`spawn`] or [`scope`], are only visible to that task, even if it's suspended with frames still entered. Outside of a task with task local storage, this context stores ambient state in thread local storage, just like [`ThreadLocalCtxt`].
+
+Frames fully encapsulate all properties that were active when they were created so can be sent across tasks and threads to move that state with them.
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct TaskLocalCtxt {
+    fallback: ThreadLocalCtxt,
+}
+
+impl Default for TaskLocalCtxt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskLocalCtxt {
+    /**
+    Create a new task local store with fully isolated storage.
+    */
+    pub fn new() -> Self {
+        TaskLocalCtxt {
+            fallback: ThreadLocalCtxt::new(),
+        }
+    }
+
+    /**
+    Create a new task local store sharing the same storage as any other [`TaskLocalCtxt::shared`].
+    */
+    pub const fn shared() -> Self {
+        TaskLocalCtxt {
+            fallback: ThreadLocalCtxt::shared(),
+        }
+    }
+
+    fn with_active<R>(&self, with: impl FnOnce(&mut ThreadLocalCtxtFrame) -> R) -> Option<R> {
+        ACTIVE
+            .try_with(|active| {
+                with(
+                    active
+                        .borrow_mut()
+                        .entry(self.fallback.id())
+                        .or_insert_with(ThreadLocalCtxtFrame::empty),
+                )
+            })
+            .ok()
+    }
+}
+
+impl Ctxt for TaskLocalCtxt {
+    type Current = ThreadLocalCtxtFrame;
+    type Frame = ThreadLocalCtxtFrame;
+
+    fn with_current<R, F: FnOnce(&Self::Current) -> R>(&self, with: F) -> R {
+        match self.with_active(|current| current.clone()) {
+            Some(current) => with(&current),
+            None => self.fallback.with_current(with),
+        }
+    }
+
+    fn open_root<P: Props>(&self, props: P) -> Self::Frame {
+        ThreadLocalCtxtFrame::root(props)
+    }
+
+    fn open_push<P: Props>(&self, props: P) -> Self::Frame {
+        match self.with_active(|current| current.clone()) {
+            Some(current) => current.push(props),
+            None => self.fallback.open_push(props),
+        }
+    }
+
+    fn enter(&self, frame: &mut Self::Frame) {
+        if self
+            .with_active(|current| core::mem::swap(current, frame))
+            .is_none()
+        {
+            self.fallback.enter(frame)
+        }
+    }
+
+    fn exit(&self, frame: &mut Self::Frame) {
+        if self
+            .with_active(|current| core::mem::swap(current, frame))
+            .is_none()
+        {
+            self.fallback.exit(frame)
+        }
+    }
+
+    fn close(&self, _: Self::Frame) {}
+}
+
+impl InternalCtxt for TaskLocalCtxt {}
+
+/**
+Run a future with its own task local storage for [`TaskLocalCtxt`].
+
+Frames entered while the future is executing are only visible to it. The future starts with no ambient properties. Use [`spawn`] to run a future on a new task with the current ambient properties.
+*/
+pub fn scope<F: Future>(future: F) -> impl Future<Output = F::Output> {
+    ACTIVE.scope(RefCell::new(HashMap::new()), future)
+}
+
+/**
+Spawn a future onto the current [`tokio`] runtime, propagating the current ambient properties from the shared runtime to it.
+
+A bare `tokio::spawn` doesn't carry ambient properties, like the current `trace_id` and `span_id`, into the spawned task. This function captures the current [`Frame`] and runs the spawned future inside it. The future also gets its own task local storage for [`TaskLocalCtxt`], through [`scope`].
+
+This function will panic if called outside of a [`tokio`] runtime.
+*/
+#[cfg(feature = "implicit_rt")]
+#[track_caller]
+pub fn spawn<F>(future: F) -> tokio::task::JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    spawn_with(crate::runtime::shared().ctxt(), future)
+}
+
+/**
+Spawn a future onto the current [`tokio`] runtime, propagating the current ambient properties from `ctxt` to it.
+
+This function is like [`spawn`], but works with any [`Ctxt`], not just the one in the shared runtime.
+
+This function will panic if called outside of a [`tokio`] runtime.
+*/
+#[track_caller]
+pub fn spawn_with<C, F>(ctxt: C, future: F) -> tokio::task::JoinHandle<F::Output>
+where
+    C: Ctxt + Send + 'static,
+    C::Frame: Send + 'static,
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(scope(Frame::current(ctxt).in_future(future)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{span::SpanId, well_known::KEY_SPAN_ID};
+
+    #[test]
+    fn spawn_propagates_frame() {
+        let ctxt = TaskLocalCtxt::new();
+        let span_id = SpanId::from_u64(1).unwrap();
+
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(scope(async move {
+                let mut frame = Frame::push(ctxt, (KEY_SPAN_ID, span_id));
+                let _guard = frame.enter();
+
+                // Frames stay entered across suspension points without leaking to other tasks
+                let other = tokio::spawn(scope(async move {
+                    ctxt.with_current(|current| current.get(KEY_SPAN_ID).is_none())
+                }));
+
+                tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+
+                assert!(other.await.unwrap());
+
+                let spawned = spawn_with(ctxt, async move {
+                    ctxt.with_current(|current| current.pull::<SpanId, _>(KEY_SPAN_ID))
+                });
+
+                assert_eq!(Some(span_id), spawned.await.unwrap());
+            }));
+    }
+}
@@ -44,6 +44,11 @@ impl ThreadLocalCtxt {
     pub const fn shared() -> Self {
         ThreadLocalCtxt { id: 0 }
     }
+
+    #[cfg(feature = "tokio")]
+    pub(super) fn id(&self) -> usize {
+        self.id
+    }
 }
 
 /**
@@ -54,6 +59,40 @@ pub struct ThreadLocalCtxtFrame {
     props: Option<Arc<HashMap<Str<'static>, OwnedValue>>>,
 }
 
+impl ThreadLocalCtxtFrame {
+    pub(super) fn empty() -> Self {
+        ThreadLocalCtxtFrame { props: None }
+    }
+
+    pub(super) fn root<P: Props>(props: P) -> Self {
+        let mut span = HashMap::new();
+
+        let _ = props.for_each(|k, v| {
+            span.insert(k.to_shared(), v.to_shared());
+            ControlFlow::Continue(())
+        });
+
+        ThreadLocalCtxtFrame {
+            props: Some(Arc::new(span)),
+        }
+    }
+
+    pub(super) fn push<P: Props>(mut self, props: P) -> Self {
+        if self.props.is_none() {
+            self.props = Some(Arc::new(HashMap::new()));
+        }
+
+        let span_props = Arc::make_mut(self.props.as_mut().unwrap());
+
+        let _ = props.for_each(|k, v| {
+            span_props.insert(k.to_shared(), v.to_shared());
+            ControlFlow::Continue(())
+        });
+
+        self
+    }
+}
+
 impl Props for ThreadLocalCtxtFrame {
     fn for_each<'a, F: FnMut(Str<'a>, Value<'a>) -> ControlFlow<()>>(
         &'a self,
@@ -87,33 +126,11 @@ impl Ctxt for ThreadLocalCtxt {
     }
 
     fn open_root<P: Props>(&self, props: P) -> Self::Frame {
-        let mut span = HashMap::new();
-
-        props.for_each(|k, v| {
-            span.insert(k.to_shared(), v.to_shared());
-            ControlFlow::Continue(())
-        });
-
-        ThreadLocalCtxtFrame {
-            props: Some(Arc::new(span)),
-        }
+        ThreadLocalCtxtFrame::root(props)
     }
 
     fn open_push<P: Props>(&self, props: P) -> Self::Frame {
-        let mut span = current(self.id);
-
-        if span.props.is_none() {
-            span.props = Some(Arc::new(HashMap::new()));
-        }
-
-        let span_props = Arc::make_mut(span.props.as_mut().unwrap());
-
-        props.for_each(|k, v| {
-            span_props.insert(k.to_shared(), v.to_shared());
-            ControlFlow::Continue(())
-        });
-
-        span
+        current(self.id).push(props)
     }
 
     fn enter(&self, link: &mut Self::Frame) {
@@ -149,7 +166,7 @@ fn current(id: usize) -> ThreadLocalCtxtFrame {
         active
             .borrow_mut()
             .entry(id)
-            .or_insert_with(|| ThreadLocalCtxtFrame { props: None })
+            .or_insert_with(ThreadLocalCtxtFrame::empty)
             .clone()
     })
 }
@@ -158,9 +175,7 @@ fn swap(id: usize, incoming: &mut ThreadLocalCtxtFrame) {
     ACTIVE.with(|active| {
         let mut active = active.borrow_mut();
 
-        let current = active
-            .entry(id)
-            .or_insert_with(|| ThreadLocalCtxtFrame { props: None });
+        let current = active.entry(id).or_insert_with(ThreadLocalCtxtFrame::empty);
 
         mem::swap(current, incoming);
     })
@@ -0,0 +1,354 @@
+/*!
+The [`Redact`] type.
+
+Redaction rewrites the properties on events before they're emitted, so sensitive values like passwords or tokens don't end up in the outside observer. A [`Redact`] is a set of rules keyed by property name patterns. Each rule can:
+
+- Drop the property entirely.
+- Mask its value, replacing it with `"***"`.
+- Hash its value, so it can still be correlated across events without being revealed.
+- Mask parts of its value that match a regular expression (requires the `regex` Cargo feature).
+
+Rules are applied by wrapping an [`Emitter`] through [`Redact::wrap_emitter`]. Ambient properties from the [`crate::Ctxt`] are added to events before they reach the emitter, so they're redacted along with the event's own properties. The event's message is rendered from its properties, so redacted values are also redacted in the message:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::redact::Redact;
+
+let captured = emit::testing::CaptureEmitter::new();
+
+let rt = emit::runtime::Runtime::build(
+    Redact::new()
+        .drop_key("password")
+        .mask_key("*token*")
+        .wrap_emitter(captured.clone()),
+    emit::Empty,
+    emit::platform::thread_local_ctxt::ThreadLocalCtxt::new(),
+    emit::platform::system_clock::SystemClock::new(),
+    emit::platform::rand_rng::RandRng::new(),
+);
+
+emit::emit!(rt: &rt, "{user} logged in with {access_token}", user: "Rust", password: "hunter2", access_token: "abc123");
+
+let evt = &captured.events()[0];
+
+assert_eq!("Rust logged in with ***", evt.msg().to_string());
+assert!(emit::Props::get(evt, "password").is_none());
+# }
+```
+
+Property names are matched using simple patterns. A `*` in the pattern matches any number of characters, so `*token*` matches `token`, `access_token`, and `token_expiry`. Patterns are matched case-insensitively. If multiple rules match a property, the first one added wins.
+
+Patterns containing a `*` never match the [well-known](crate::well_known) properties, like `lvl`, `trace_id`, or `span_id`. Other emitters rely on these properties to interpret events, so a broad rule like `*` would otherwise break them. Well-known properties can still be redacted by naming them exactly, like `err`.
+
+Only the names of top-level properties are matched. Values like structs that contain sensitive fields can be redacted as a whole, or masked using a regular expression over their string representation.
+*/
+
+use core::{fmt, ops::ControlFlow, time::Duration};
+
+use emit_core::{
+    emitter::Emitter,
+    event::ToEvent,
+    props::Props,
+    runtime::InternalEmitter,
+    str::Str,
+    value::{ToValue, Value},
+    well_known::is_well_known,
+};
+
+const MASK: &str = "***";
+
+/**
+A set of rules for redacting properties on events.
+
+Use [`Redact::wrap_emitter`] to apply the rules to events before they're emitted.
+*/
+#[derive(Default, Clone)]
+pub struct Redact {
+    rules: Vec<Rule>,
+}
+
+#[derive(Clone)]
+struct Rule {
+    key: String,
+    action: Action,
+}
+
+#[derive(Clone)]
+enum Action {
+    Drop,
+    Mask,
+    Hash,
+    #[cfg(feature = "regex")]
+    MaskRegex(regex::Regex),
+}
+
+impl Redact {
+    /**
+    Create a new set of redaction rules.
+
+    Without any rules, properties aren't changed.
+    */
+    pub fn new() -> Self {
+        Redact { rules: Vec::new() }
+    }
+
+    /**
+    Drop properties whose name matches `key`.
+    */
+    pub fn drop_key(self, key: impl Into<String>) -> Self {
+        self.rule(key, Action::Drop)
+    }
+
+    /**
+    Replace the value of properties whose name matches `key` with `"***"`.
+    */
+    pub fn mask_key(self, key: impl Into<String>) -> Self {
+        self.rule(key, Action::Mask)
+    }
+
+    /**
+    Replace the value of properties whose name matches `key` with a hash of their string representation.
+
+    The hash is a 64-bit FNV-1a, formatted as hex. It's stable across processes, so the same value will always produce the same hash. The hash is _not_ cryptographically secure. Values with a small number of possibilities, like card numbers or short pins, may be recoverable from their hash. Use [`Redact::mask_key`] for these instead.
+    */
+    pub fn hash_key(self, key: impl Into<String>) -> Self {
+        self.rule(key, Action::Hash)
+    }
+
+    /**
+    Replace parts of the value of properties whose name matches `key` that match `regex` with `"***"`.
+
+    The regular expression is applied to the string representation of the value, so structured values will be converted into strings. Use a `key` of `*` to mask values regardless of their name, such as anything that looks like a card number. A `key` of `*` doesn't match well-known properties like `trace_id` or `lvl`, so they're left unchanged.
+    */
+    #[cfg(feature = "regex")]
+    pub fn mask_regex_key(self, key: impl Into<String>, regex: regex::Regex) -> Self {
+        self.rule(key, Action::MaskRegex(regex))
+    }
+
+    fn rule(mut self, key: impl Into<String>, action: Action) -> Self {
+        self.rules.push(Rule {
+            key: key.into(),
+            action,
+        });
+
+        self
+    }
+
+    /**
+    Wrap an [`Emitter`], redacting properties on events before they're emitted.
+    */
+    pub fn wrap_emitter<E>(self, emitter: E) -> RedactEmitter<E> {
+        RedactEmitter {
+            redact: self,
+            emitter,
+        }
+    }
+
+    fn action(&self, key: &str) -> Option<&Action> {
+        self.rules
+            .iter()
+            .find(|rule| {
+                // Wildcards don't match well-known properties, so catch-all
+                // rules don't break emitters that interpret them
+                if is_well_known(key) && rule.key.contains('*') {
+                    return false;
+                }
+
+                matches_key(&rule.key, key)
+            })
+            .map(|rule| &rule.action)
+    }
+}
+
+impl fmt::Debug for Redact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.rules.iter().map(|rule| &rule.key))
+            .finish()
+    }
+}
+
+/**
+An [`Emitter`] that redacts properties on events before forwarding them.
+
+Use [`Redact::wrap_emitter`] to create a redacting emitter.
+*/
+pub struct RedactEmitter<E> {
+    redact: Redact,
+    emitter: E,
+}
+
+impl<E: Emitter> Emitter for RedactEmitter<E> {
+    fn emit<T: ToEvent>(&self, evt: T) {
+        let evt = evt.to_event();
+
+        // Avoid allocating if there's nothing to redact
+        let mut any = false;
+        let _ = evt.props().for_each(|k, _| {
+            if self.redact.action(k.get()).is_some() {
+                any = true;
+
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+
+        if !any {
+            self.emitter.emit(evt);
+            return;
+        }
+
+        let mut redacted = Vec::new();
+        let _ = evt.props().for_each(|k, v| {
+            match self.redact.action(k.get()) {
+                None => redacted.push((k, Redacted::Value(v))),
+                Some(Action::Drop) => (),
+                Some(Action::Mask) => redacted.push((k, Redacted::Mask)),
+                Some(Action::Hash) => redacted.push((k, Redacted::Owned(hash(v)))),
+                #[cfg(feature = "regex")]
+                Some(Action::MaskRegex(regex)) => {
+                    let v = v.to_string();
+
+                    redacted.push((k, Redacted::Owned(regex.replace_all(&v, MASK).into_owned())))
+                }
+            }
+
+            ControlFlow::Continue(())
+        });
+
+        self.emitter
+            .emit(evt.by_ref().with_props(RedactedProps(&redacted)))
+    }
+
+    fn blocking_flush(&self, timeout: Duration) -> bool {
+        self.emitter.blocking_flush(timeout)
+    }
+}
+
+impl<E: InternalEmitter> InternalEmitter for RedactEmitter<E> {}
+
+enum Redacted<'kv> {
+    Value(Value<'kv>),
+    Mask,
+    Owned(String),
+}
+
+impl<'kv> ToValue for Redacted<'kv> {
+    fn to_value(&self) -> Value<'_> {
+        match self {
+            Redacted::Value(v) => v.by_ref(),
+            Redacted::Mask => Value::from(MASK),
+            Redacted::Owned(v) => Value::from(&**v),
+        }
+    }
+}
+
+struct RedactedProps<'a, 'kv>(&'a [(Str<'kv>, Redacted<'kv>)]);
+
+impl<'a, 'kv> Props for RedactedProps<'a, 'kv> {
+    fn for_each<'b, F: FnMut(Str<'b>, Value<'b>) -> ControlFlow<()>>(
+        &'b self,
+        mut for_each: F,
+    ) -> ControlFlow<()> {
+        for (k, v) in self.0 {
+            for_each(k.by_ref(), v.to_value())?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+fn hash(value: Value) -> String {
+    struct Fnv1a(u64);
+
+    impl fmt::Write for Fnv1a {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            for b in s.as_bytes() {
+                self.0 ^= *b as u64;
+                self.0 = self.0.wrapping_mul(0x100000001b3);
+            }
+
+            Ok(())
+        }
+    }
+
+    let mut hasher = Fnv1a(0xcbf29ce484222325);
+    let _ = fmt::write(&mut hasher, format_args!("{}", value));
+
+    format!("{:016x}", hasher.0)
+}
+
+fn matches_key(pattern: &str, key: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let key = key.as_bytes();
+
+    // Greedy wildcard matching, backtracking to the last `*` on mismatch
+    let (mut p, mut k) = (0, 0);
+    let mut backtrack = None;
+
+    while k < key.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, k));
+                p += 1;
+            }
+            Some(c) if c.eq_ignore_ascii_case(&key[k]) => {
+                p += 1;
+                k += 1;
+            }
+            _ => match backtrack {
+                Some((bp, bk)) => {
+                    backtrack = Some((bp, bk + 1));
+                    p = bp + 1;
+                    k = bk + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_patterns() {
+        for (pattern, key, expected) in [
+            ("password", "password", true),
+            ("password", "Password", true),
+            ("password", "passwords", false),
+            ("*token*", "token", true),
+            ("*token*", "access_token", true),
+            ("*token*", "token_expiry", true),
+            ("*token*", "tok", false),
+            ("card_*", "card_number", true),
+            ("card_*", "discard_number", false),
+            ("*", "anything", true),
+            ("a*b*c", "abbbc", true),
+            ("a*b*c", "abbbcd", false),
+        ] {
+            assert_eq!(
+                expected,
+                matches_key(pattern, key),
+                "{pattern} {key} expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn wildcards_skip_well_known() {
+        let redact = Redact::new().drop_key("err").mask_key("*");
+
+        assert!(matches!(redact.action("password"), Some(Action::Mask)));
+        assert!(matches!(redact.action("err"), Some(Action::Drop)));
+
+        for key in ["lvl", "trace_id", "span_id", "event_kind"] {
+            assert!(redact.action(key).is_none(), "{key}");
+        }
+    }
+}
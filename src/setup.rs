@@ -82,14 +82,19 @@ use core::time::Duration;
 
 use emit_core::{
     and::And,
+    clock::Clock,
     ctxt::Ctxt,
     emitter::Emitter,
     empty::Empty,
     filter::Filter,
-    runtime::{InternalCtxt, InternalEmitter, InternalFilter},
+    rng::Rng,
+    runtime::{InternalClock, InternalCtxt, InternalEmitter, InternalFilter, InternalRng},
 };
 
-use crate::platform::{self, Platform};
+use crate::{
+    level::{MinLevelPathMap, ParseMinLevelPathMapError},
+    platform,
+};
 
 /**
 Configure `emit` with [`Emitter`]s, [`Filter`]s, and [`Ctxt`].
@@ -100,7 +105,7 @@ pub fn setup() -> Setup {
     Setup::default()
 }
 
-pub use platform::DefaultCtxt;
+pub use platform::{DefaultClock, DefaultCtxt, DefaultRng};
 
 /**
 The default [`crate::Emitter`] to use in [`crate::setup()`].
@@ -116,11 +121,19 @@ pub type DefaultFilter = Empty;
 A configuration builder for an `emit` runtime.
 */
 #[must_use = "call `.init()` to finish setup"]
-pub struct Setup<TEmitter = DefaultEmitter, TFilter = DefaultFilter, TCtxt = DefaultCtxt> {
+pub struct Setup<
+    TEmitter = DefaultEmitter,
+    TFilter = DefaultFilter,
+    TCtxt = DefaultCtxt,
+    TClock = DefaultClock,
+    TRng = DefaultRng,
+> {
     emitter: TEmitter,
     filter: TFilter,
     ctxt: TCtxt,
-    platform: Platform,
+    clock: TClock,
+    rng: TRng,
+    span_begin: bool,
 }
 
 impl Default for Setup {
@@ -138,21 +151,30 @@ impl Setup {
             emitter: Default::default(),
             filter: Default::default(),
             ctxt: Default::default(),
-            platform: Default::default(),
+            clock: Default::default(),
+            rng: Default::default(),
+            span_begin: false,
         }
     }
 }
 
-impl<TEmitter: Emitter, TFilter: Filter, TCtxt: Ctxt> Setup<TEmitter, TFilter, TCtxt> {
+impl<TEmitter: Emitter, TFilter: Filter, TCtxt: Ctxt, TClock: Clock, TRng: Rng>
+    Setup<TEmitter, TFilter, TCtxt, TClock, TRng>
+{
     /**
     Set the [`Emitter`] that will receive diagnostic events.
     */
-    pub fn emit_to<UEmitter: Emitter>(self, emitter: UEmitter) -> Setup<UEmitter, TFilter, TCtxt> {
+    pub fn emit_to<UEmitter: Emitter>(
+        self,
+        emitter: UEmitter,
+    ) -> Setup<UEmitter, TFilter, TCtxt, TClock, TRng> {
         Setup {
             emitter,
             filter: self.filter,
             ctxt: self.ctxt,
-            platform: self.platform,
+            clock: self.clock,
+            rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
@@ -162,12 +184,14 @@ impl<TEmitter: Emitter, TFilter: Filter, TCtxt: Ctxt> Setup<TEmitter, TFilter, T
     pub fn and_emit_to<UEmitter: Emitter>(
         self,
         emitter: UEmitter,
-    ) -> Setup<And<TEmitter, UEmitter>, TFilter, TCtxt> {
+    ) -> Setup<And<TEmitter, UEmitter>, TFilter, TCtxt, TClock, TRng> {
         Setup {
             emitter: self.emitter.and_to(emitter),
             filter: self.filter,
             ctxt: self.ctxt,
-            platform: self.platform,
+            clock: self.clock,
+            rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
@@ -177,36 +201,71 @@ impl<TEmitter: Emitter, TFilter: Filter, TCtxt: Ctxt> Setup<TEmitter, TFilter, T
     pub fn map_emitter<UEmitter: Emitter>(
         self,
         map: impl FnOnce(TEmitter) -> UEmitter,
-    ) -> Setup<UEmitter, TFilter, TCtxt> {
+    ) -> Setup<UEmitter, TFilter, TCtxt, TClock, TRng> {
         Setup {
             emitter: map(self.emitter),
             filter: self.filter,
             ctxt: self.ctxt,
-            platform: self.platform,
+            clock: self.clock,
+            rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
     /**
     Set the [`Filter`] that will be applied before diagnostic events are emitted.
     */
-    pub fn emit_when<UFilter: Filter>(self, filter: UFilter) -> Setup<TEmitter, UFilter, TCtxt> {
+    pub fn emit_when<UFilter: Filter>(
+        self,
+        filter: UFilter,
+    ) -> Setup<TEmitter, UFilter, TCtxt, TClock, TRng> {
         Setup {
             emitter: self.emitter,
             filter,
             ctxt: self.ctxt,
-            platform: self.platform,
+            clock: self.clock,
+            rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
+    /**
+    Set the [`Filter`] to a [`crate::level::MinLevelPathMap`] parsed from the environment variable `var`.
+
+    The value of the variable uses the syntax described in [`crate::level::MinLevelPathMap::parse`], like `info,my_app::db=debug`. If the variable isn't set then all events will match. If the variable is set, but isn't valid, then this method will return an error describing the problem.
+
+    ```
+    # fn main() -> Result<(), emit::level::ParseMinLevelPathMapError> {
+    let rt = emit::setup()
+        .emit_when_from_env("EMIT_FILTER")?
+        .init();
+    # let _ = rt;
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn emit_when_from_env(
+        self,
+        var: &str,
+    ) -> Result<Setup<TEmitter, MinLevelPathMap, TCtxt, TClock, TRng>, ParseMinLevelPathMapError>
+    {
+        Ok(self.emit_when(MinLevelPathMap::from_env(var)?))
+    }
+
     /**
     Set the [`Ctxt`] that will store ambient properties and attach them to diagnostic events.
     */
-    pub fn with_ctxt<UCtxt: Ctxt>(self, ctxt: UCtxt) -> Setup<TEmitter, TFilter, UCtxt> {
+    pub fn with_ctxt<UCtxt: Ctxt>(
+        self,
+        ctxt: UCtxt,
+    ) -> Setup<TEmitter, TFilter, UCtxt, TClock, TRng> {
         Setup {
             emitter: self.emitter,
             filter: self.filter,
             ctxt,
-            platform: self.platform,
+            clock: self.clock,
+            rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
 
@@ -216,21 +275,92 @@ impl<TEmitter: Emitter, TFilter: Filter, TCtxt: Ctxt> Setup<TEmitter, TFilter, T
     pub fn map_ctxt<UCtxt: Ctxt>(
         self,
         map: impl FnOnce(TCtxt) -> UCtxt,
-    ) -> Setup<TEmitter, TFilter, UCtxt> {
+    ) -> Setup<TEmitter, TFilter, UCtxt, TClock, TRng> {
         Setup {
             emitter: self.emitter,
             filter: self.filter,
             ctxt: map(self.ctxt),
-            platform: self.platform,
+            clock: self.clock,
+            rng: self.rng,
+            span_begin: self.span_begin,
         }
     }
+
+    /**
+    Set the [`Clock`] used to assign timestamps and run timers.
+    */
+    pub fn with_clock<UClock: Clock>(
+        self,
+        clock: UClock,
+    ) -> Setup<TEmitter, TFilter, TCtxt, UClock, TRng> {
+        Setup {
+            emitter: self.emitter,
+            filter: self.filter,
+            ctxt: self.ctxt,
+            clock,
+            rng: self.rng,
+            span_begin: self.span_begin,
+        }
+    }
+
+    /**
+    Set the [`Rng`] used to generate ids and other random values.
+    */
+    pub fn with_rng<URng: Rng>(self, rng: URng) -> Setup<TEmitter, TFilter, TCtxt, TClock, URng> {
+        Setup {
+            emitter: self.emitter,
+            filter: self.filter,
+            ctxt: self.ctxt,
+            clock: self.clock,
+            rng,
+            span_begin: self.span_begin,
+        }
+    }
+
+    /**
+    Whether span macros should emit an event when their span begins, as well as when it completes.
+
+    Span begin events carry a [`crate::Kind::SpanBegin`] along with the trace id, span id, and name of the span, and have a point extent at the time the span began. They make it possible to see operations that are still in progress, or that never complete. Individual span macros can override this setting with their `begin` control parameter.
+
+    This setting only applies to span macros that use the runtime initialized by this builder. See [`crate::runtime::Runtime::with_span_begin`].
+
+    ```
+    # #[cfg(not(feature = "std"))] fn main() {}
+    # #[cfg(feature = "std")] fn main() {
+    static RT: emit::runtime::AmbientSlot = emit::runtime::AmbientSlot::new();
+
+    #[emit::span(rt: RT.get(), "work")]
+    fn work() {
+        // Your code goes here
+    }
+
+    let rt = emit::setup()
+        .emit_to(emit::testing::CaptureEmitter::new())
+        .emit_span_begin(true)
+        .init_slot(&RT);
+
+    work();
+
+    let events = rt.emitter().events();
+
+    assert_eq!(2, events.len());
+    assert_eq!(emit::Kind::SpanBegin, emit::Props::pull::<emit::Kind, _>(&events[0], "event_kind").unwrap());
+    # }
+    ```
+    */
+    pub fn emit_span_begin(mut self, enabled: bool) -> Self {
+        self.span_begin = enabled;
+        self
+    }
 }
 
 impl<
         TEmitter: Emitter + Send + Sync + 'static,
         TFilter: Filter + Send + Sync + 'static,
         TCtxt: Ctxt + Send + Sync + 'static,
-    > Setup<TEmitter, TFilter, TCtxt>
+        TClock: Clock + Send + Sync + 'static,
+        TRng: Rng + Send + Sync + 'static,
+    > Setup<TEmitter, TFilter, TCtxt, TClock, TRng>
 where
     TCtxt::Frame: Send + 'static,
 {
@@ -256,11 +386,12 @@ where
         let ambient = slot
             .init(
                 emit_core::runtime::Runtime::new()
+                    .with_span_begin(self.span_begin)
                     .with_emitter(self.emitter)
                     .with_filter(self.filter)
                     .with_ctxt(self.ctxt)
-                    .with_clock(self.platform.clock)
-                    .with_rng(self.platform.rng),
+                    .with_clock(self.clock)
+                    .with_rng(self.rng),
             )
             .expect("already initialized");
 
@@ -275,7 +406,9 @@ impl<
         TEmitter: InternalEmitter + Send + Sync + 'static,
         TFilter: InternalFilter + Send + Sync + 'static,
         TCtxt: InternalCtxt + Send + Sync + 'static,
-    > Setup<TEmitter, TFilter, TCtxt>
+        TClock: InternalClock + Send + Sync + 'static,
+        TRng: InternalRng + Send + Sync + 'static,
+    > Setup<TEmitter, TFilter, TCtxt, TClock, TRng>
 where
     TCtxt::Frame: Send + 'static,
 {
@@ -290,11 +423,12 @@ where
         let ambient = emit_core::runtime::internal_slot()
             .init(
                 emit_core::runtime::Runtime::new()
+                    .with_span_begin(self.span_begin)
                     .with_emitter(self.emitter)
                     .with_filter(self.filter)
                     .with_ctxt(self.ctxt)
-                    .with_clock(self.platform.clock)
-                    .with_rng(self.platform.rng),
+                    .with_clock(self.clock)
+                    .with_rng(self.rng),
             )
             .expect("already initialized");
 
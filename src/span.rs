@@ -160,6 +160,12 @@ The data model of spans is an extension of `emit`'s events. Span events include
 - `span_id`: an identifier for this specific invocation of the operation.
 - `parent_id`: the `span_id` of the operation that invoked this one.
 - `trace_id`: an identifier shared by all events in a distributed trace. A `trace_id` is assigned by the first operation.
+- `span_kind`: the relationship of the span to other spans, like whether it's handling a request from a remote client. Spans without a kind are internal. See [`SpanKind`].
+- `span_status`: optionally, whether the operation succeeded or failed. Spans that carry an `err`, or have a `lvl` of `error`, are treated as failed. See [`SpanStatus`].
+- `span_status_description`: optionally, a description of the span's status.
+- `span_links`: optionally, links to spans in other traces that are related to this one, like the producers of messages processed in a batch. See [`SpanLink`].
+- `trace_flags`: optional flags propagated with the trace, like whether the caller sampled it.
+- `trace_state`: optional vendor-specific data propagated with the trace.
 
 # Contextual properties
 
@@ -265,7 +271,7 @@ Notice the `span_parent` of `inner_span` is the same as the `span_id` of `outer_
 
 # Propagating span context across threads
 
-Ambient span properties are not shared across threads by default. This context needs to be fetched and sent across threads manually:
+Ambient span properties are not shared across threads by default. This context needs to be fetched and sent across threads manually, or through helpers like [`crate::frame::spawn_in_current`]:
 
 ```
 # #[cfg(not(feature = "std"))] fn main() {}
@@ -298,23 +304,28 @@ tokio::spawn(
 
 Async functions that simply migrate across threads in work-stealing runtimes don't need any manual work to keep their context across those threads.
 
+With the `tokio` Cargo feature, [`crate::platform::task_local_ctxt::spawn`] can be used instead of `tokio::spawn` to do this automatically.
+
 # Propagating span context across services
 
-`emit` doesn't implement any distributed trace propagation itself. This is the responsibility of end-users through their web framework and clients to manage.
+Span context can be propagated across services using [W3C Trace Context](https://www.w3.org/TR/trace-context/) headers. The [`propagation`] module can read and write the `traceparent` and `tracestate` headers through any header map that implements its [`propagation::Extractor`] and [`propagation::Injector`] traits.
 
-When an incoming request arrives, you can parse the trace and span ids from its traceparent header and push them onto the current context:
+When an incoming request arrives, you can extract its traceparent and push it onto the current context:
 
 ```
 # #[cfg(not(feature = "std"))] fn main() {}
 # #[cfg(feature = "std")] fn main() {
-// Parsed from a traceparent header
-let trace_id = "12b2fde225aebfa6758ede9cac81bf4d";
-let span_id = "23995f85b4610391";
+# use std::collections::HashMap;
+let mut headers = HashMap::new();
+headers.insert(
+    "traceparent".to_owned(),
+    "00-12b2fde225aebfa6758ede9cac81bf4d-23995f85b4610391-01".to_owned(),
+);
 
-let frame = emit::Frame::push(emit::runtime::shared().ctxt(), emit::props! {
-    trace_id,
-    span_id,
-});
+let frame = emit::Frame::push(
+    emit::runtime::shared().ctxt(),
+    emit::span::propagation::extract(&headers),
+);
 
 frame.call(handle_request);
 
@@ -338,34 +349,180 @@ Event {
         "span_parent": 23995f85b4610391,
         "trace_id": 12b2fde225aebfa6758ede9cac81bf4d,
         "span_id": 641a578cc05c9db2,
+        "trace_flags": 01,
     },
 }
 ```
 
-This pattern of pushing the incoming traceparent onto the context and then immediately calling a span annotated function ensures the `span_id` parsed from the traceparent becomes the `span_parent` in the events emitted by your application, without emitting a span event for the calling service itself.
+This pattern of pushing the incoming traceparent onto the context and then immediately calling a span annotated function ensures the `span_id` parsed from the traceparent becomes the `span_parent` in the events emitted by your application, without emitting a span event for the calling service itself. The `trace_flags` from the traceparent, and any `trace_state` from the tracestate header, are carried along with it.
 
-When making outbound requests, you can pull the current trace and span ids from the current context and format them into a traceparent header:
+When making outbound requests, you can inject the current context into its headers:
 
 ```
 # #[cfg(not(feature = "std"))] fn main() {}
 # #[cfg(feature = "std")] fn main() {
-use emit::{well_known::{KEY_SPAN_ID, KEY_TRACE_ID}, Ctxt, Props};
+# use std::collections::HashMap;
+let mut headers = HashMap::new();
 
-let (trace_id, span_id) = emit::runtime::shared().ctxt().with_current(|props| {
-    (
-        props.pull::<emit::span::TraceId, _>(KEY_TRACE_ID),
-        props.pull::<emit::span::SpanId, _>(KEY_SPAN_ID),
-    )
-});
+emit::span::propagation::inject(emit::runtime::shared().ctxt(), &mut headers);
+
+// Send the headers with the request
+# }
+```
+
+The [`Traceparent`] type can also be used directly to parse and format traceparent headers.
+
+Application-specific properties can also be propagated alongside the trace as W3C Baggage through [`propagation::extract_baggage`] and [`propagation::inject_baggage`].
+
+# Span events
+
+Events emitted while a span is active are correlated with it through the `trace_id` and `span_id` on the ambient context. Lightweight annotations that belong to the span itself, rather than being standalone log events, can be emitted as span events by giving them an `event_kind` of `"span_event"`:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+# fn try_get_cached() -> Option<i32> { None }
+#[emit::span("get value")]
+fn get_value() -> i32 {
+    if let Some(value) = try_get_cached() {
+        return value;
+    }
+
+    emit::emit!("cache miss", event_kind: emit::Kind::SpanEvent);
+
+    // Your code goes here
+    # 42
+}
+
+get_value();
+# }
+```
+
+The extent of a span event is the point in time it was emitted. Emitters that understand span events, like `emit_otlp` and `emit_opentelemetry`, attach them to the span they belong to when it completes. Other emitters treat them like any other event.
+
+# Span begin events
+
+Span macros only emit an event when their span completes. Operations that are still running, or that never complete because they've hung, won't show up at all. The `begin` control parameter can be used to also emit an event when the span begins:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+#[emit::span(begin: true, "wait for {id}", id)]
+fn wait_for(id: i32) {
+    // Your code goes here
+}
+
+wait_for(42);
+# }
+```
+
+The begin event carries an `event_kind` of [`crate::Kind::SpanBegin`], along with the `trace_id`, `span_id`, `span_parent`, and `span_name` of the span, and has a point extent at the time the span began:
+
+```text
+Event {
+    module: "my_app",
+    tpl: "wait for {id}",
+    extent: Some(
+        "2024-04-29T05:37:05.278488400Z",
+    ),
+    props: {
+        "event_kind": span_begin,
+        "span_name": "wait for {id}",
+        "id": 42,
+        "trace_id": 6d6bb9c23a5f76e7185fb3957c2f5527,
+        "span_id": 4c2a1b0bc3be2c4e,
+    },
+}
+```
+
+Begin events can be enabled for all span macros using a runtime through [`crate::runtime::Runtime::with_span_begin`], or [`crate::Setup::emit_span_begin`] for the runtime it initializes. Individual span macros can still opt out with `begin: false`:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::{testing::CaptureRuntime, Kind, Props};
+
+#[emit::span(rt: rt, "load {id}", id)]
+fn load(rt: &CaptureRuntime, id: i32) {
+    // Your code goes here
+}
+
+#[emit::span(rt: rt, begin: false, "save {id}", id)]
+fn save(rt: &CaptureRuntime, id: i32) {
+    // Your code goes here
+}
+
+// Begin events are disabled unless enabled on the runtime
+let rt = emit::testing::runtime();
+load(&rt, 42);
+
+assert_eq!(1, rt.emitter().take().len());
+
+let rt = rt.with_span_begin(true);
+load(&rt, 42);
+save(&rt, 42);
+
+let kinds = rt
+    .emitter()
+    .events()
+    .iter()
+    .map(|evt| evt.props().pull::<Kind, _>("event_kind"))
+    .collect::<Vec<_>>();
+
+assert_eq!(
+    vec![Some(Kind::SpanBegin), Some(Kind::Span), Some(Kind::Span)],
+    kinds
+);
+# }
+```
+
+Begin events are only emitted for spans that were matched by their filter. They're also filtered by the runtime's [`crate::Filter`], like any other event, so they can be excluded without affecting the spans themselves:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::{runtime::Runtime, Clock, Ctxt, Emitter, Filter, Kind, Props, Rng};
+
+#[emit::span(rt: rt, when: emit::filter::always(), "work")]
+fn work(rt: &Runtime<impl Emitter, impl Filter, impl Ctxt, impl Clock, impl Rng>) {
+    // Your code goes here
+}
+
+let rt = emit::testing::runtime()
+    .with_span_begin(true)
+    .with_filter(emit::kind::is_span_filter());
+
+work(&rt);
+
+let events = rt.emitter().events();
+
+assert_eq!(1, events.len());
+assert_eq!(Some(Kind::Span), events[0].props().pull::<Kind, _>("event_kind"));
+# }
+```
+
+# Sampling spans
 
-if let (Some(trace_id), Some(span_id)) = (trace_id, span_id) {
-    let traceparent = format!("00-{trace_id}-{span_id}-00");
+Span macros accept a `when` control parameter with a [`crate::Filter`] that decides whether to record the span. The [`sampler`] module contains filters that make consistent sampling decisions across a whole trace, so traces aren't left with missing spans:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::span::sampler;
 
-    // Push the traceparent header onto the request
+#[emit::span(when: sampler::parent_based(sampler::trace_id_ratio(0.1)), "incoming request")]
+fn handle_request() {
+    // Your code goes here
 }
+
+handle_request();
 # }
 ```
 
+This sampler will follow the decision of the caller, as propagated through its traceparent header, and keep 10% of traces that start in this service.
+
+A span that's discarded by a sampler is never emitted, but its context is still pushed with [`TraceFlags`] that aren't sampled. That way spans within it follow its decision, and events within it are still correlated with the trace. Any other filter given through `when`, or configured on the runtime, disables a span it doesn't match instead, so nothing is pushed for it.
+
 # Completing spans manually
 
 The `arg` control parameter can be applied to span macros to bind an identifier in the body of the annotated function for the [`Span`] that's created for it. This span can be completed manually, changing properties of the span along the way:
@@ -448,11 +605,17 @@ use emit_core::{
     str::{Str, ToStr},
     template::{self, Template},
     value::FromValue,
-    well_known::{KEY_EVENT_KIND, KEY_SPAN_ID, KEY_SPAN_NAME, KEY_SPAN_PARENT, KEY_TRACE_ID},
+    well_known::{
+        KEY_ERR, KEY_EVENT_KIND, KEY_LVL, KEY_SPAN_ID, KEY_SPAN_NAME, KEY_SPAN_PARENT,
+        KEY_SPAN_STATUS, KEY_TRACE_FLAGS, KEY_TRACE_ID, SPAN_KIND_CLIENT, SPAN_KIND_CONSUMER,
+        SPAN_KIND_INTERNAL, SPAN_KIND_PRODUCER, SPAN_KIND_SERVER, SPAN_STATUS_ERROR,
+        SPAN_STATUS_OK,
+    },
 };
 
 use crate::{
     kind::Kind,
+    level::Level,
     value::{ToValue, Value},
     Frame, Timer,
 };
@@ -463,6 +626,10 @@ use core::{
     str::{self, FromStr},
 };
 
+#[cfg(feature = "alloc")]
+pub mod propagation;
+pub mod sampler;
+
 /**
 A [W3C Trace Id](https://www.w3.org/TR/trace-context/#trace-id).
 */
@@ -597,174 +764,1185 @@ impl TraceId {
             i += 1;
         }
 
-        Ok(TraceId::new(
-            NonZeroU128::new(u128::from_be_bytes(dst)).ok_or_else(|| ParseIdError {})?,
-        ))
+        Ok(TraceId::new(
+            NonZeroU128::new(u128::from_be_bytes(dst)).ok_or_else(|| ParseIdError {})?,
+        ))
+    }
+
+    /**
+    Try parse ASCII hex characters into a trace id.
+
+    If `hex` is not exactly 32 valid hex characters (`[a-fA-F0-9]`) then this method will fail.
+    */
+    pub fn try_from_hex(hex: impl fmt::Display) -> Result<Self, ParseIdError> {
+        let mut buf = Buffer::<32>::new();
+
+        Self::try_from_hex_slice(buf.buffer(hex)?)
+    }
+}
+
+/**
+A [W3C Span Id](https://www.w3.org/TR/trace-context/#parent-id).
+*/
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SpanId(NonZeroU64);
+
+impl fmt::Debug for SpanId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(str::from_utf8(&self.to_hex()).unwrap(), f)
+    }
+}
+
+impl fmt::Display for SpanId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(str::from_utf8(&self.to_hex()).unwrap())
+    }
+}
+
+impl FromStr for SpanId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_hex_slice(s.as_bytes())
+    }
+}
+
+impl ToValue for SpanId {
+    fn to_value(&self) -> Value {
+        Value::capture_display(self)
+    }
+}
+
+impl<'v> FromValue<'v> for SpanId {
+    fn from_value(value: Value<'v>) -> Option<Self> {
+        value
+            .downcast_ref::<SpanId>()
+            .copied()
+            .or_else(|| SpanId::try_from_hex(value).ok())
+    }
+}
+
+impl SpanId {
+    /**
+    Create a new random span id.
+
+    This method will return `None` if the given [`Rng`] fails to produce a random value, or if it produces the value `0`.
+    */
+    pub fn random<R: Rng>(rng: R) -> Option<Self> {
+        Some(SpanId::new(NonZeroU64::new(rng.gen_u64()?)?))
+    }
+
+    /**
+    Create a span id from a non-zero integer.
+    */
+    pub const fn new(v: NonZeroU64) -> Self {
+        SpanId(v)
+    }
+
+    /**
+    Create a span id from an integer.
+
+    This method will return `None` if `v` is `0`.
+    */
+    pub fn from_u64(v: u64) -> Option<Self> {
+        Some(SpanId(NonZeroU64::new(v)?))
+    }
+
+    /**
+    Get the value of the span id as an integer.
+    */
+    pub const fn to_u64(&self) -> u64 {
+        self.0.get()
+    }
+
+    /**
+    Get a span id from an 8 byte big-endian array.
+    */
+    pub fn from_bytes(v: [u8; 8]) -> Option<Self> {
+        Self::from_u64(u64::from_be_bytes(v))
+    }
+
+    /**
+    Convert the span id into an 8 byte big-endian array.
+    */
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.0.get().to_be_bytes()
+    }
+
+    /**
+    Convert the span id into a 16 byte ASCII-compatible hex string, like `00f067aa0ba902b7`.
+    */
+    pub fn to_hex(&self) -> [u8; 16] {
+        let mut dst = [0; 16];
+        let src: [u8; 8] = self.0.get().to_be_bytes();
+
+        for i in 0..src.len() {
+            let b = src[i];
+
+            dst[i * 2] = HEX_ENCODE_TABLE[(b >> 4) as usize];
+            dst[i * 2 + 1] = HEX_ENCODE_TABLE[(b & 0x0f) as usize];
+        }
+
+        dst
+    }
+
+    /**
+    Try parse a slice of ASCII hex bytes into a span id.
+
+    If `hex` is not a 16 byte array of valid hex characters (`[a-fA-F0-9]`) then this method will fail.
+    */
+    pub fn try_from_hex_slice(hex: &[u8]) -> Result<Self, ParseIdError> {
+        let hex: &[u8; 16] = hex.try_into().map_err(|_| ParseIdError {})?;
+
+        let mut dst = [0; 8];
+
+        let mut i = 0;
+        while i < 8 {
+            // Convert a two-char hex value (like `A8`)
+            // into a byte (like `10101000`)
+            let h1 = HEX_DECODE_TABLE[hex[i * 2] as usize];
+            let h2 = HEX_DECODE_TABLE[hex[i * 2 + 1] as usize];
+
+            // We use `0xff` as a sentinel value to indicate
+            // an invalid hex character sequence (like the letter `G`)
+            if h1 | h2 == 0xff {
+                return Err(ParseIdError {});
+            }
+
+            // The upper nibble needs to be shifted into position
+            // to produce the final byte value
+            dst[i] = SHL4_TABLE[h1 as usize] | h2;
+            i += 1;
+        }
+
+        Ok(SpanId::new(
+            NonZeroU64::new(u64::from_be_bytes(dst)).ok_or_else(|| ParseIdError {})?,
+        ))
+    }
+
+    /**
+    Try parse ASCII hex characters into a span id.
+
+    If `hex` is not exactly 16 valid hex characters (`[a-fA-F0-9]`) then this method will fail.
+    */
+    pub fn try_from_hex(hex: impl fmt::Display) -> Result<Self, ParseIdError> {
+        let mut buf = Buffer::<16>::new();
+
+        Self::try_from_hex_slice(buf.buffer(hex)?)
+    }
+}
+
+/**
+[W3C Trace Flags](https://www.w3.org/TR/trace-context/#trace-flags).
+
+Trace flags are propagated along with the trace and span ids of a distributed trace. The only flag currently defined is [`TraceFlags::SAMPLED`].
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TraceFlags(u8);
+
+impl fmt::Debug for TraceFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(str::from_utf8(&self.to_hex()).unwrap(), f)
+    }
+}
+
+impl fmt::Display for TraceFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(str::from_utf8(&self.to_hex()).unwrap())
+    }
+}
+
+impl FromStr for TraceFlags {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_hex_slice(s.as_bytes())
+    }
+}
+
+impl ToValue for TraceFlags {
+    fn to_value(&self) -> Value<'_> {
+        Value::capture_display(self)
+    }
+}
+
+impl<'v> FromValue<'v> for TraceFlags {
+    fn from_value(value: Value<'v>) -> Option<Self> {
+        value
+            .downcast_ref::<TraceFlags>()
+            .copied()
+            .or_else(|| TraceFlags::try_from_hex(value).ok())
+    }
+}
+
+impl TraceFlags {
+    /**
+    No flags are set.
+
+    A trace without the [`TraceFlags::SAMPLED`] flag may not have been recorded by the caller.
+    */
+    pub const EMPTY: Self = TraceFlags(0);
+
+    /**
+    The trace may have been recorded by the caller.
+    */
+    pub const SAMPLED: Self = TraceFlags(0x01);
+
+    /**
+    Create trace flags from their raw byte representation.
+    */
+    pub const fn from_u8(v: u8) -> Self {
+        TraceFlags(v)
+    }
+
+    /**
+    Get the raw byte representation of the trace flags.
+    */
+    pub const fn to_u8(&self) -> u8 {
+        self.0
+    }
+
+    /**
+    Whether the [`TraceFlags::SAMPLED`] flag is set.
+    */
+    pub const fn is_sampled(&self) -> bool {
+        self.0 & Self::SAMPLED.0 != 0
+    }
+
+    /**
+    Set or unset the [`TraceFlags::SAMPLED`] flag.
+    */
+    pub const fn with_sampled(self, sampled: bool) -> Self {
+        if sampled {
+            TraceFlags(self.0 | Self::SAMPLED.0)
+        } else {
+            TraceFlags(self.0 & !Self::SAMPLED.0)
+        }
+    }
+
+    /**
+    Convert the trace flags into a 2 byte ASCII-compatible hex string, like `01`.
+    */
+    pub fn to_hex(&self) -> [u8; 2] {
+        [
+            HEX_ENCODE_TABLE[(self.0 >> 4) as usize],
+            HEX_ENCODE_TABLE[(self.0 & 0x0f) as usize],
+        ]
+    }
+
+    /**
+    Try parse a slice of ASCII hex bytes into trace flags.
+
+    If `hex` is not a 2 byte array of valid hex characters (`[a-fA-F0-9]`) then this method will fail.
+    */
+    pub fn try_from_hex_slice(hex: &[u8]) -> Result<Self, ParseIdError> {
+        let hex: &[u8; 2] = hex.try_into().map_err(|_| ParseIdError {})?;
+
+        let h1 = HEX_DECODE_TABLE[hex[0] as usize];
+        let h2 = HEX_DECODE_TABLE[hex[1] as usize];
+
+        if h1 | h2 == 0xff {
+            return Err(ParseIdError {});
+        }
+
+        Ok(TraceFlags(SHL4_TABLE[h1 as usize] | h2))
+    }
+
+    /**
+    Try parse ASCII hex characters into trace flags.
+
+    If `hex` is not exactly 2 valid hex characters (`[a-fA-F0-9]`) then this method will fail.
+    */
+    pub fn try_from_hex(hex: impl fmt::Display) -> Result<Self, ParseIdError> {
+        let mut buf = Buffer::<2>::new();
+
+        Self::try_from_hex_slice(buf.buffer(hex)?)
+    }
+}
+
+/**
+A [W3C Traceparent](https://www.w3.org/TR/trace-context/#traceparent-header).
+
+A traceparent carries the [`TraceId`], [`SpanId`], and [`TraceFlags`] of a span across service boundaries, formatted like `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`.
+
+A traceparent can be converted into a [`SpanCtxt`] with [`Traceparent::to_span_ctxt`] and pushed onto the ambient context. Its span id will become the parent of any spans created within that context. See the [`propagation`] module for helpers that extract and inject traceparents from request headers.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Traceparent {
+    trace_id: TraceId,
+    span_id: SpanId,
+    trace_flags: TraceFlags,
+}
+
+impl fmt::Display for Traceparent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(str::from_utf8(&self.to_bytes()).unwrap())
+    }
+}
+
+impl FromStr for Traceparent {
+    type Err = ParseTraceparentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl ToValue for Traceparent {
+    fn to_value(&self) -> Value<'_> {
+        Value::capture_display(self)
+    }
+}
+
+impl Traceparent {
+    /**
+    Create a traceparent from its parts.
+    */
+    pub const fn new(trace_id: TraceId, span_id: SpanId, trace_flags: TraceFlags) -> Self {
+        Traceparent {
+            trace_id,
+            span_id,
+            trace_flags,
+        }
+    }
+
+    /**
+    Get a traceparent for the span in a [`SpanCtxt`].
+
+    This method will return `None` if the context doesn't have a trace id or span id. If the context doesn't have any [`TraceFlags`] then [`TraceFlags::SAMPLED`] is used.
+    */
+    pub fn from_span_ctxt(span_ctxt: &SpanCtxt) -> Option<Self> {
+        Some(Traceparent::new(
+            *span_ctxt.trace_id()?,
+            *span_ctxt.span_id()?,
+            span_ctxt
+                .trace_flags()
+                .copied()
+                .unwrap_or(TraceFlags::SAMPLED),
+        ))
+    }
+
+    /**
+    Read a traceparent for the current span from an ambient [`Ctxt`].
+
+    See [`Traceparent::from_span_ctxt`] for details.
+    */
+    pub fn current(ctxt: impl Ctxt) -> Option<Self> {
+        Self::from_span_ctxt(&SpanCtxt::current(ctxt))
+    }
+
+    /**
+    Convert the traceparent into a [`SpanCtxt`].
+
+    The span id of the traceparent is the span id of the returned context, so it will become the parent of any spans created from it through [`SpanCtxt::new_child`].
+    */
+    pub const fn to_span_ctxt(&self) -> SpanCtxt {
+        SpanCtxt::new(Some(self.trace_id), None, Some(self.span_id))
+            .with_trace_flags(Some(self.trace_flags))
+    }
+
+    /**
+    Get the trace id.
+    */
+    pub const fn trace_id(&self) -> &TraceId {
+        &self.trace_id
+    }
+
+    /**
+    Get the span id.
+    */
+    pub const fn span_id(&self) -> &SpanId {
+        &self.span_id
+    }
+
+    /**
+    Get the trace flags.
+    */
+    pub const fn trace_flags(&self) -> &TraceFlags {
+        &self.trace_flags
+    }
+
+    /**
+    Try parse a traceparent.
+
+    Parsing follows the W3C Trace Context specification. The version must be `00` or a later version, which may carry additional trailing data that's ignored. Ids and flags must be lowercase hex, and ids must not be all zeroes.
+    */
+    pub fn try_from_str(traceparent: &str) -> Result<Self, ParseTraceparentError> {
+        let traceparent = traceparent.trim_matches(|c| c == ' ' || c == '\t');
+
+        let bytes = traceparent.as_bytes();
+
+        if bytes.len() < 55 {
+            return Err(ParseTraceparentError {});
+        }
+
+        let version = &bytes[0..2];
+
+        match version {
+            // Version `ff` is forbidden
+            b"ff" => return Err(ParseTraceparentError {}),
+            // Version `00` has exactly 4 parts
+            b"00" if bytes.len() != 55 => return Err(ParseTraceparentError {}),
+            // Later versions may have additional parts
+            _ if bytes.len() > 55 && bytes[55] != b'-' => return Err(ParseTraceparentError {}),
+            _ => (),
+        }
+
+        if bytes[2] != b'-' || bytes[35] != b'-' || bytes[52] != b'-' {
+            return Err(ParseTraceparentError {});
+        }
+
+        let trace_id = &bytes[3..35];
+        let span_id = &bytes[36..52];
+        let trace_flags = &bytes[53..55];
+
+        if !is_lower_hex(version)
+            || !is_lower_hex(trace_id)
+            || !is_lower_hex(span_id)
+            || !is_lower_hex(trace_flags)
+        {
+            return Err(ParseTraceparentError {});
+        }
+
+        Ok(Traceparent::new(
+            TraceId::try_from_hex_slice(trace_id).map_err(|_| ParseTraceparentError {})?,
+            SpanId::try_from_hex_slice(span_id).map_err(|_| ParseTraceparentError {})?,
+            TraceFlags::try_from_hex_slice(trace_flags).map_err(|_| ParseTraceparentError {})?,
+        ))
+    }
+
+    /**
+    Convert the traceparent into a 55 byte ASCII-compatible string, like `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`.
+    */
+    pub fn to_bytes(&self) -> [u8; 55] {
+        let mut dst = [b'-'; 55];
+
+        dst[0..2].copy_from_slice(b"00");
+        dst[3..35].copy_from_slice(&self.trace_id.to_hex());
+        dst[36..52].copy_from_slice(&self.span_id.to_hex());
+        dst[53..55].copy_from_slice(&self.trace_flags.to_hex());
+
+        dst
+    }
+}
+
+fn is_lower_hex(hex: &[u8]) -> bool {
+    hex.iter().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/**
+An error encountered attempting to parse a [`Traceparent`].
+*/
+#[derive(Debug)]
+pub struct ParseTraceparentError {}
+
+impl fmt::Display for ParseTraceparentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the input was not a valid traceparent")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTraceparentError {}
+
+/**
+The relationship of a span to other spans in its trace.
+
+If a span event has a kind associated with it, it can be pulled from its props using [`crate::well_known::KEY_SPAN_KIND`]. Spans without a kind are treated as [`SpanKind::Internal`].
+
+The kind of spans created through the span macros can be set with the `kind` control parameter:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+#[emit::span(kind: emit::span::SpanKind::Server, "handle request")]
+fn handle_request() {
+    // Your code goes here
+}
+# }
+```
+
+The kind is attached to the events of the span itself. It isn't pushed to the ambient context, so events and nested spans inside the span don't inherit it:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::{platform::system_clock::SystemClock, testing::CaptureRuntime, Props};
+
+#[emit::span(rt: rt, kind: emit::span::SpanKind::Server, "handle request")]
+fn handle_request(rt: &CaptureRuntime<SystemClock>) {
+    emit::info!(rt: rt, "handling request");
+
+    query(rt);
+}
+
+#[emit::span(rt: rt, "query")]
+fn query(rt: &CaptureRuntime<SystemClock>) {}
+
+let rt = emit::testing::runtime();
+handle_request(&rt);
+
+let events = rt.emitter().take();
+let kind = |i: usize| events[i].props().pull::<emit::span::SpanKind, _>(emit::well_known::KEY_SPAN_KIND);
+
+// The log and the nested span don't have a kind
+assert_eq!(None, kind(0));
+assert_eq!(None, kind(1));
+assert_eq!(Some(emit::span::SpanKind::Server), kind(2));
+# }
+```
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpanKind {
+    /**
+    The span is an internal operation within an application.
+
+    This variant is equal to [`SPAN_KIND_INTERNAL`].
+    */
+    #[default]
+    Internal,
+    /**
+    The span handles an incoming request from a remote caller.
+
+    This variant is equal to [`SPAN_KIND_SERVER`].
+    */
+    Server,
+    /**
+    The span makes an outgoing request to a remote service.
+
+    This variant is equal to [`SPAN_KIND_CLIENT`].
+    */
+    Client,
+    /**
+    The span sends a message to a broker.
+
+    This variant is equal to [`SPAN_KIND_PRODUCER`].
+    */
+    Producer,
+    /**
+    The span receives a message from a broker.
+
+    This variant is equal to [`SPAN_KIND_CONSUMER`].
+    */
+    Consumer,
+}
+
+impl fmt::Debug for SpanKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self)
+    }
+}
+
+impl fmt::Display for SpanKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SpanKind::Internal => SPAN_KIND_INTERNAL,
+            SpanKind::Server => SPAN_KIND_SERVER,
+            SpanKind::Client => SPAN_KIND_CLIENT,
+            SpanKind::Producer => SPAN_KIND_PRODUCER,
+            SpanKind::Consumer => SPAN_KIND_CONSUMER,
+        })
+    }
+}
+
+impl FromStr for SpanKind {
+    type Err = ParseSpanKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for (expected, kind) in [
+            (SPAN_KIND_INTERNAL, SpanKind::Internal),
+            (SPAN_KIND_SERVER, SpanKind::Server),
+            (SPAN_KIND_CLIENT, SpanKind::Client),
+            (SPAN_KIND_PRODUCER, SpanKind::Producer),
+            (SPAN_KIND_CONSUMER, SpanKind::Consumer),
+        ] {
+            if s.eq_ignore_ascii_case(expected) {
+                return Ok(kind);
+            }
+        }
+
+        Err(ParseSpanKindError {})
+    }
+}
+
+impl ToValue for SpanKind {
+    fn to_value(&self) -> Value<'_> {
+        Value::capture_display(self)
+    }
+}
+
+impl<'v> FromValue<'v> for SpanKind {
+    fn from_value(value: Value<'v>) -> Option<Self> {
+        value
+            .downcast_ref::<SpanKind>()
+            .copied()
+            .or_else(|| value.parse())
+    }
+}
+
+/**
+An error attempting to parse a [`SpanKind`] from text.
+*/
+#[derive(Debug)]
+pub struct ParseSpanKindError {}
+
+impl fmt::Display for ParseSpanKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the input was not a valid span kind")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSpanKindError {}
+
+/**
+Whether the operation a span represents succeeded or failed.
+
+The status of a span event is determined by [`SpanStatus::from_props`]:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::span::SpanStatus;
+
+// An explicit status
+assert_eq!(
+    Some(SpanStatus::Ok),
+    SpanStatus::from_props(emit::props! { span_status: SpanStatus::Ok }),
+);
+
+// An error
+assert_eq!(
+    Some(SpanStatus::Error),
+    SpanStatus::from_props(emit::props! { err: "failed" }),
+);
+
+// No status
+assert_eq!(None, SpanStatus::from_props(emit::props! { lvl: emit::Level::Warn }));
+# }
+```
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpanStatus {
+    /**
+    The operation succeeded.
+
+    This variant is equal to [`SPAN_STATUS_OK`].
+    */
+    Ok,
+    /**
+    The operation failed.
+
+    This variant is equal to [`SPAN_STATUS_ERROR`].
+    */
+    Error,
+}
+
+impl SpanStatus {
+    /**
+    Get the status of a span from its properties.
+
+    If the [`crate::well_known::KEY_SPAN_STATUS`] property is present then its value is used. If it's not present, but the [`KEY_ERR`] property is, or the [`KEY_LVL`] property is [`crate::Level::Error`], then the status is [`SpanStatus::Error`]. Otherwise the status is unset, and this method returns `None`.
+    */
+    pub fn from_props(props: impl Props) -> Option<Self> {
+        if let Some(status) = props.pull::<SpanStatus, _>(KEY_SPAN_STATUS) {
+            return Some(status);
+        }
+
+        if props.get(KEY_ERR).is_some() || props.pull::<Level, _>(KEY_LVL) == Some(Level::Error) {
+            return Some(SpanStatus::Error);
+        }
+
+        None
+    }
+}
+
+impl fmt::Debug for SpanStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self)
+    }
+}
+
+impl fmt::Display for SpanStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SpanStatus::Ok => SPAN_STATUS_OK,
+            SpanStatus::Error => SPAN_STATUS_ERROR,
+        })
+    }
+}
+
+impl FromStr for SpanStatus {
+    type Err = ParseSpanStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case(SPAN_STATUS_OK) {
+            Ok(SpanStatus::Ok)
+        } else if s.eq_ignore_ascii_case(SPAN_STATUS_ERROR) {
+            Ok(SpanStatus::Error)
+        } else {
+            Err(ParseSpanStatusError {})
+        }
+    }
+}
+
+impl ToValue for SpanStatus {
+    fn to_value(&self) -> Value<'_> {
+        Value::capture_display(self)
     }
+}
 
-    /**
-    Try parse ASCII hex characters into a trace id.
+impl<'v> FromValue<'v> for SpanStatus {
+    fn from_value(value: Value<'v>) -> Option<Self> {
+        value
+            .downcast_ref::<SpanStatus>()
+            .copied()
+            .or_else(|| value.parse())
+    }
+}
 
-    If `hex` is not exactly 32 valid hex characters (`[a-fA-F0-9]`) then this method will fail.
-    */
-    pub fn try_from_hex(hex: impl fmt::Display) -> Result<Self, ParseIdError> {
-        let mut buf = Buffer::<32>::new();
+/**
+An error attempting to parse a [`SpanStatus`] from text.
+*/
+#[derive(Debug)]
+pub struct ParseSpanStatusError {}
 
-        Self::try_from_hex_slice(buf.buffer(hex)?)
+impl fmt::Display for ParseSpanStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the input was not a valid span status")
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSpanStatusError {}
+
 /**
-A [W3C Span Id](https://www.w3.org/TR/trace-context/#parent-id).
+A link from a span to a span in another trace.
+
+Links let a span reference other operations that are causally related to it, but aren't its parent, like each of the messages processed by a batch job. A span can be linked to any number of other spans through the [`crate::well_known::KEY_SPAN_LINKS`] property. See [`SpanLinks`] for details.
+
+A `SpanLink` is formatted as its [`TraceId`] and [`SpanId`] separated by a `-`, like `4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7`.
 */
 #[derive(Clone, Copy, PartialEq, Eq)]
-pub struct SpanId(NonZeroU64);
+pub struct SpanLink {
+    trace_id: TraceId,
+    span_id: SpanId,
+}
 
-impl fmt::Debug for SpanId {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(str::from_utf8(&self.to_hex()).unwrap(), f)
+impl fmt::Debug for SpanLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self)
     }
 }
 
-impl fmt::Display for SpanId {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(str::from_utf8(&self.to_hex()).unwrap())
+impl fmt::Display for SpanLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.trace_id, self.span_id)
     }
 }
 
-impl FromStr for SpanId {
-    type Err = ParseIdError;
+impl FromStr for SpanLink {
+    type Err = ParseSpanLinkError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::try_from_hex_slice(s.as_bytes())
+        Self::try_from_str(s)
     }
 }
 
-impl ToValue for SpanId {
-    fn to_value(&self) -> Value {
+impl ToValue for SpanLink {
+    fn to_value(&self) -> Value<'_> {
         Value::capture_display(self)
     }
 }
 
-impl<'v> FromValue<'v> for SpanId {
+impl<'v> FromValue<'v> for SpanLink {
     fn from_value(value: Value<'v>) -> Option<Self> {
         value
-            .downcast_ref::<SpanId>()
+            .downcast_ref::<SpanLink>()
             .copied()
-            .or_else(|| SpanId::try_from_hex(value).ok())
+            .or_else(|| value.parse())
     }
 }
 
-impl SpanId {
-    /**
-    Create a new random span id.
+impl From<Traceparent> for SpanLink {
+    fn from(value: Traceparent) -> Self {
+        SpanLink::new(value.trace_id, value.span_id)
+    }
+}
 
-    This method will return `None` if the given [`Rng`] fails to produce a random value, or if it produces the value `0`.
+impl SpanLink {
+    /**
+    Create a new link to the span `span_id` in the trace `trace_id`.
     */
-    pub fn random<R: Rng>(rng: R) -> Option<Self> {
-        Some(SpanId::new(NonZeroU64::new(rng.gen_u64()?)?))
+    pub const fn new(trace_id: TraceId, span_id: SpanId) -> Self {
+        SpanLink { trace_id, span_id }
     }
 
     /**
-    Create a span id from a non-zero integer.
+    Get a link to the span in a [`SpanCtxt`].
+
+    If the span context doesn't have both a trace id and span id then this method will return `None`.
     */
-    pub const fn new(v: NonZeroU64) -> Self {
-        SpanId(v)
+    pub fn from_span_ctxt(span_ctxt: &SpanCtxt) -> Option<Self> {
+        Some(SpanLink::new(*span_ctxt.trace_id()?, *span_ctxt.span_id()?))
     }
 
     /**
-    Create a span id from an integer.
+    Get a link to the current span in the ambient context.
 
-    This method will return `None` if `v` is `0`.
+    If there's no current span then this method will return `None`.
     */
-    pub fn from_u64(v: u64) -> Option<Self> {
-        Some(SpanId(NonZeroU64::new(v)?))
+    pub fn current(ctxt: impl Ctxt) -> Option<Self> {
+        Self::from_span_ctxt(&SpanCtxt::current(ctxt))
     }
 
     /**
-    Get the value of the span id as an integer.
+    Get the trace id of the linked span.
     */
-    pub const fn to_u64(&self) -> u64 {
-        self.0.get()
+    pub const fn trace_id(&self) -> &TraceId {
+        &self.trace_id
     }
 
     /**
-    Get a span id from an 8 byte big-endian array.
+    Get the span id of the linked span.
     */
-    pub fn from_bytes(v: [u8; 8]) -> Option<Self> {
-        Self::from_u64(u64::from_be_bytes(v))
+    pub const fn span_id(&self) -> &SpanId {
+        &self.span_id
     }
 
     /**
-    Convert the span id into an 8 byte big-endian array.
+    Try parse a link from its trace id and span id separated by a `-`.
     */
-    pub fn to_bytes(&self) -> [u8; 8] {
-        self.0.get().to_be_bytes()
+    pub fn try_from_str(link: &str) -> Result<Self, ParseSpanLinkError> {
+        let (trace_id, span_id) = link.split_once('-').ok_or(ParseSpanLinkError {})?;
+
+        Ok(SpanLink::new(
+            TraceId::try_from_hex_slice(trace_id.as_bytes()).map_err(|_| ParseSpanLinkError {})?,
+            SpanId::try_from_hex_slice(span_id.as_bytes()).map_err(|_| ParseSpanLinkError {})?,
+        ))
+    }
+}
+
+/**
+An error attempting to parse a [`SpanLink`] or [`SpanLinks`] from text.
+*/
+#[derive(Debug)]
+pub struct ParseSpanLinkError {}
+
+impl fmt::Display for ParseSpanLinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the input was not a valid span link")
     }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSpanLinkError {}
+
+#[cfg(feature = "alloc")]
+mod alloc_support {
+    use super::*;
+
+    use alloc::vec::Vec;
+
+    #[cfg(any(feature = "sval", feature = "serde"))]
+    use alloc::string::ToString;
+
+    use emit_core::value::OwnedValue;
 
     /**
-    Convert the span id into a 16 byte ASCII-compatible hex string, like `00f067aa0ba902b7`.
+    A set of links from a span to spans in other traces, along with any attributes on them.
+
+    Links can be attached to spans created through the span macros with the `links` control parameter:
+
+    ```
+    # #[cfg(not(feature = "std"))] fn main() {}
+    # #[cfg(feature = "std")] fn main() {
+    use emit::span::{SpanLink, SpanLinks};
+
+    #[emit::span(links: links, "process batch")]
+    fn process_batch(links: SpanLinks) {
+        // Your code goes here
+    }
+
+    // The spans that produced each message in the batch
+    let producers: [SpanLink; 2] = [
+        "4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7".parse().unwrap(),
+        "0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331".parse().unwrap(),
+    ];
+
+    process_batch(SpanLinks::from_iter(producers));
+    # }
+    ```
+
+    The links are only added to the completed span event. They aren't pushed to the ambient context, so events and nested spans inside the span don't inherit them:
+
+    ```
+    # #[cfg(not(feature = "std"))] fn main() {}
+    # #[cfg(feature = "std")] fn main() {
+    use emit::{platform::system_clock::SystemClock, span::{SpanLink, SpanLinks}, testing::CaptureRuntime, Props};
+
+    #[emit::span(rt: rt, links: links, "process batch")]
+    fn process_batch(rt: &CaptureRuntime<SystemClock>, links: SpanLinks) {
+        emit::info!(rt: rt, "processing {count: links.len()} messages");
+
+        process_message(rt);
+    }
+
+    #[emit::span(rt: rt, "process message")]
+    fn process_message(rt: &CaptureRuntime<SystemClock>) {}
+
+    let rt = emit::testing::runtime();
+
+    let producer: SpanLink = "4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7".parse().unwrap();
+    process_batch(&rt, SpanLinks::new().with_link(producer));
+
+    let events = rt.emitter().take();
+    let links = |i: usize| events[i].props().pull::<SpanLinks, _>(emit::well_known::KEY_SPAN_LINKS);
+
+    assert!(links(0).is_none());
+    assert!(links(1).is_none());
+    assert_eq!(1, links(2).unwrap().len());
+    # }
+    ```
+
+    If a span event has links associated with it, they can be pulled from its props using [`crate::well_known::KEY_SPAN_LINKS`]. A single [`SpanLink`] can also be used as the value of the property.
+
+    `SpanLinks` is formatted as a comma-separated list of [`SpanLink`]s, without their attributes. When the `sval` or `serde` Cargo features are enabled, `SpanLinks` are captured as a sequence of records, each with a `trace_id`, `span_id`, and map of `attributes`, so emitters can serialize them as structured data.
     */
-    pub fn to_hex(&self) -> [u8; 16] {
-        let mut dst = [0; 16];
-        let src: [u8; 8] = self.0.get().to_be_bytes();
+    #[derive(Clone, Debug, Default)]
+    pub struct SpanLinks {
+        links: Vec<(SpanLink, Vec<(Str<'static>, OwnedValue)>)>,
+    }
 
-        for i in 0..src.len() {
-            let b = src[i];
+    impl SpanLinks {
+        /**
+        Create an empty set of links.
+        */
+        pub const fn new() -> Self {
+            SpanLinks { links: Vec::new() }
+        }
 
-            dst[i * 2] = HEX_ENCODE_TABLE[(b >> 4) as usize];
-            dst[i * 2 + 1] = HEX_ENCODE_TABLE[(b & 0x0f) as usize];
+        /**
+        Add a link without any attributes.
+        */
+        pub fn with_link(mut self, link: SpanLink) -> Self {
+            self.push(link, crate::Empty);
+            self
         }
 
-        dst
+        /**
+        Add a link with the properties in `attributes`.
+        */
+        pub fn with_link_attributes(mut self, link: SpanLink, attributes: impl Props) -> Self {
+            self.push(link, attributes);
+            self
+        }
+
+        /**
+        Add a link with the properties in `attributes`.
+
+        See [`SpanLinks::with_link_attributes`] for details.
+        */
+        pub fn push(&mut self, link: SpanLink, attributes: impl Props) {
+            let mut owned = Vec::new();
+
+            let _ = attributes.for_each(|k, v| {
+                owned.push((k.to_owned(), v.to_owned()));
+
+                ControlFlow::Continue(())
+            });
+
+            self.links.push((link, owned));
+        }
+
+        /**
+        Iterate over the links, along with their attributes.
+        */
+        pub fn iter(&self) -> impl Iterator<Item = (&SpanLink, &[(Str<'static>, OwnedValue)])> {
+            self.links
+                .iter()
+                .map(|(link, attributes)| (link, &**attributes))
+        }
+
+        /**
+        Get the number of links.
+        */
+        pub fn len(&self) -> usize {
+            self.links.len()
+        }
+
+        /**
+        Whether there are no links.
+        */
+        pub fn is_empty(&self) -> bool {
+            self.links.is_empty()
+        }
     }
 
-    /**
-    Try parse a slice of ASCII hex bytes into a span id.
+    impl FromIterator<SpanLink> for SpanLinks {
+        fn from_iter<T: IntoIterator<Item = SpanLink>>(iter: T) -> Self {
+            let mut links = SpanLinks::new();
 
-    If `hex` is not a 16 byte array of valid hex characters (`[a-fA-F0-9]`) then this method will fail.
-    */
-    pub fn try_from_hex_slice(hex: &[u8]) -> Result<Self, ParseIdError> {
-        let hex: &[u8; 16] = hex.try_into().map_err(|_| ParseIdError {})?;
+            for link in iter {
+                links.push(link, crate::Empty);
+            }
 
-        let mut dst = [0; 8];
+            links
+        }
+    }
 
-        let mut i = 0;
-        while i < 8 {
-            // Convert a two-char hex value (like `A8`)
-            // into a byte (like `10101000`)
-            let h1 = HEX_DECODE_TABLE[hex[i * 2] as usize];
-            let h2 = HEX_DECODE_TABLE[hex[i * 2 + 1] as usize];
+    impl fmt::Display for SpanLinks {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut first = true;
 
-            // We use `0xff` as a sentinel value to indicate
-            // an invalid hex character sequence (like the letter `G`)
-            if h1 | h2 == 0xff {
-                return Err(ParseIdError {});
+            for (link, _) in &self.links {
+                if !first {
+                    f.write_str(",")?;
+                }
+                first = false;
+
+                fmt::Display::fmt(link, f)?;
             }
 
-            // The upper nibble needs to be shifted into position
-            // to produce the final byte value
-            dst[i] = SHL4_TABLE[h1 as usize] | h2;
-            i += 1;
+            Ok(())
         }
+    }
 
-        Ok(SpanId::new(
-            NonZeroU64::new(u64::from_be_bytes(dst)).ok_or_else(|| ParseIdError {})?,
-        ))
+    impl ToValue for SpanLinks {
+        fn to_value(&self) -> Value<'_> {
+            #[cfg(feature = "sval")]
+            {
+                Value::capture_sval(self)
+            }
+            #[cfg(all(feature = "serde", not(feature = "sval")))]
+            {
+                Value::capture_serde(self)
+            }
+            #[cfg(not(any(feature = "sval", feature = "serde")))]
+            {
+                Value::capture_display(self)
+            }
+        }
     }
 
-    /**
-    Try parse ASCII hex characters into a span id.
+    impl<'v> FromValue<'v> for SpanLinks {
+        fn from_value(value: Value<'v>) -> Option<Self> {
+            value
+                .downcast_ref::<SpanLinks>()
+                .cloned()
+                .or_else(|| Some(SpanLinks::new().with_link(value.cast()?)))
+        }
+    }
 
-    If `hex` is not exactly 16 valid hex characters (`[a-fA-F0-9]`) then this method will fail.
-    */
-    pub fn try_from_hex(hex: impl fmt::Display) -> Result<Self, ParseIdError> {
-        let mut buf = Buffer::<16>::new();
+    #[cfg(any(feature = "sval", feature = "serde"))]
+    const LINK_ATTRIBUTES: &'static str = "attributes";
 
-        Self::try_from_hex_slice(buf.buffer(hex)?)
+    #[cfg(feature = "sval")]
+    mod sval_support {
+        use super::*;
+
+        impl sval::Value for SpanLinks {
+            fn stream<'sval, S: sval::Stream<'sval> + ?Sized>(
+                &'sval self,
+                stream: &mut S,
+            ) -> sval::Result {
+                const LABEL: sval::Label = sval::Label::new("SpanLink");
+                const TRACE_ID: sval::Label = sval::Label::new(KEY_TRACE_ID);
+                const SPAN_ID: sval::Label = sval::Label::new(KEY_SPAN_ID);
+                const ATTRIBUTES: sval::Label = sval::Label::new(LINK_ATTRIBUTES);
+
+                stream.seq_begin(Some(self.links.len()))?;
+
+                for (link, attributes) in &self.links {
+                    stream.seq_value_begin()?;
+                    stream.record_begin(None, Some(&LABEL), None, Some(3))?;
+
+                    stream.record_value_begin(None, &TRACE_ID)?;
+                    stream.value_computed(&*link.trace_id().to_string())?;
+                    stream.record_value_end(None, &TRACE_ID)?;
+
+                    stream.record_value_begin(None, &SPAN_ID)?;
+                    stream.value_computed(&*link.span_id().to_string())?;
+                    stream.record_value_end(None, &SPAN_ID)?;
+
+                    stream.record_value_begin(None, &ATTRIBUTES)?;
+                    stream.map_begin(Some(attributes.len()))?;
+
+                    for (k, v) in attributes {
+                        stream.map_key_begin()?;
+                        stream.value_computed(k)?;
+                        stream.map_key_end()?;
+
+                        stream.map_value_begin()?;
+                        stream.value_computed(v)?;
+                        stream.map_value_end()?;
+                    }
+
+                    stream.map_end()?;
+                    stream.record_value_end(None, &ATTRIBUTES)?;
+
+                    stream.record_end(None, Some(&LABEL), None)?;
+                    stream.seq_value_end()?;
+                }
+
+                stream.seq_end()
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for SpanLinks {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::{SerializeMap as _, SerializeSeq as _, SerializeStruct as _};
+
+            struct Link<'a>(&'a SpanLink, &'a [(Str<'static>, OwnedValue)]);
+
+            struct Attributes<'a>(&'a [(Str<'static>, OwnedValue)]);
+
+            impl<'a> serde::Serialize for Link<'a> {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let mut link = serializer.serialize_struct("SpanLink", 3)?;
+
+                    link.serialize_field(KEY_TRACE_ID, &*self.0.trace_id().to_string())?;
+                    link.serialize_field(KEY_SPAN_ID, &*self.0.span_id().to_string())?;
+                    link.serialize_field(LINK_ATTRIBUTES, &Attributes(self.1))?;
+
+                    link.end()
+                }
+            }
+
+            impl<'a> serde::Serialize for Attributes<'a> {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let mut attributes = serializer.serialize_map(Some(self.0.len()))?;
+
+                    for (k, v) in self.0 {
+                        attributes.serialize_entry(k, v)?;
+                    }
+
+                    attributes.end()
+                }
+            }
+
+            let mut links = serializer.serialize_seq(Some(self.links.len()))?;
+
+            for (link, attributes) in &self.links {
+                links.serialize_element(&Link(link, attributes))?;
+            }
+
+            links.end()
+        }
     }
 }
 
+#[cfg(feature = "alloc")]
+pub use self::alloc_support::*;
+
 /*
 Original implementation: https://github.com/uuid-rs/uuid/blob/main/src/parser.rs
 
@@ -811,7 +1989,7 @@ const SHL4_TABLE: &[u8; 256] = &{
 };
 
 /**
-An error encountered attempting to parse a [`TraceId`] or [`SpanId`].
+An error encountered attempting to parse a [`TraceId`], [`SpanId`], or [`TraceFlags`].
 */
 #[derive(Debug)]
 pub struct ParseIdError {}
@@ -880,6 +2058,7 @@ This type manages the lifecycle of a span, which includes:
 pub struct Span<'a, C: Clock, P: Props, F: FnOnce(SpanEvent<'a, P>)> {
     value: Option<ActiveSpanEvent<'a, C, P>>,
     on_drop: Option<F>,
+    unsampled_ctxt: Option<SpanCtxt>,
 }
 
 /**
@@ -1012,7 +2191,7 @@ impl<'a, P: Props> Props for SpanEvent<'a, P> {
 }
 
 /**
-The trace id, span id, and parent parent span id of a [`SpanEvent`].
+The trace id, span id, parent span id, and trace flags of a [`SpanEvent`].
 
 These ids can be used to identify the distributed trace a span belongs to, and to identify the span itself within that trace.
 
@@ -1025,6 +2204,7 @@ pub struct SpanCtxt {
     trace_id: Option<TraceId>,
     span_parent: Option<SpanId>,
     span_id: Option<SpanId>,
+    trace_flags: Option<TraceFlags>,
 }
 
 impl SpanCtxt {
@@ -1044,9 +2224,20 @@ impl SpanCtxt {
             trace_id,
             span_parent,
             span_id,
+            trace_flags: None,
         }
     }
 
+    /**
+    Set the [`TraceFlags`] for the context.
+
+    Trace flags are typically only set on contexts that were propagated from another service, like through [`Traceparent::to_span_ctxt`]. If they're `None` then spans are assumed to be sampled.
+    */
+    pub const fn with_trace_flags(mut self, trace_flags: Option<TraceFlags>) -> Self {
+        self.trace_flags = trace_flags;
+        self
+    }
+
     /**
     Create a context where all identifiers are `None`.
     */
@@ -1055,13 +2246,14 @@ impl SpanCtxt {
             trace_id: None,
             span_parent: None,
             span_id: None,
+            trace_flags: None,
         }
     }
 
     /**
     Read the current context from an ambient [`Ctxt`].
 
-    This method will pull the [`TraceId`] from [`KEY_TRACE_ID`], the `SpanId` from [`KEY_SPAN_ID`], and the parent [`SpanId`] from [`KEY_SPAN_PARENT`].
+    This method will pull the [`TraceId`] from [`KEY_TRACE_ID`], the `SpanId` from [`KEY_SPAN_ID`], the parent [`SpanId`] from [`KEY_SPAN_PARENT`], and the [`TraceFlags`] from [`KEY_TRACE_FLAGS`].
     */
     pub fn current(ctxt: impl Ctxt) -> Self {
         ctxt.with_current(|current| {
@@ -1070,13 +2262,14 @@ impl SpanCtxt {
                 current.pull::<SpanId, _>(KEY_SPAN_PARENT),
                 current.pull::<SpanId, _>(KEY_SPAN_ID),
             )
+            .with_trace_flags(current.pull::<TraceFlags, _>(KEY_TRACE_FLAGS))
         })
     }
 
     /**
     Generate a new context that is a child of `self`.
 
-    The new context will share the same trace id as `self`, use the span id of `self` as its parent span id, and generate a new random span id as its own through [`SpanId::random`].
+    The new context will share the same trace id and trace flags as `self`, use the span id of `self` as its parent span id, and generate a new random span id as its own through [`SpanId::random`].
 
     If [`Self::trace_id`] is `None` then a new trace id will be generated through [`TraceId::random`].
     */
@@ -1085,7 +2278,7 @@ impl SpanCtxt {
         let span_parent = self.span_id;
         let span_id = SpanId::random(&rng);
 
-        SpanCtxt::new(trace_id, span_parent, span_id)
+        SpanCtxt::new(trace_id, span_parent, span_id).with_trace_flags(self.trace_flags)
     }
 
     /**
@@ -1108,6 +2301,13 @@ impl SpanCtxt {
     pub fn span_id(&self) -> Option<&SpanId> {
         self.span_id.as_ref()
     }
+
+    /**
+    Get the trace flags for the span.
+    */
+    pub fn trace_flags(&self) -> Option<&TraceFlags> {
+        self.trace_flags.as_ref()
+    }
 }
 
 impl Props for SpanCtxt {
@@ -1127,6 +2327,10 @@ impl Props for SpanCtxt {
             for_each(KEY_SPAN_PARENT.to_str(), span_parent.to_value())?;
         }
 
+        if let Some(ref trace_flags) = self.trace_flags {
+            for_each(KEY_TRACE_FLAGS.to_str(), trace_flags.to_value())?;
+        }
+
         ControlFlow::Continue(())
     }
 }
@@ -1145,7 +2349,7 @@ impl<'a, C: Clock, P: Props, F: FnOnce(SpanEvent<'a, P>)> Span<'a, C, P, F> {
 
     The parameters to this method are:
 
-    - `filter`: A sampler to determine whether the span should actually be created or not. If the filter doesn't match then a [`Span::disabled`] will be returned.
+    - `filter`: A filter to determine whether the span should actually be created or not. If the filter doesn't match then a [`Span::disabled`] will be returned. See [`Span::sampled_new`] to make a sampling decision that's followed by spans within it.
     - `module`: The name of the module executing the operation the span is tracking. This will become the [`SpanEvent::module`] on the resulting span event.
     - `timer`: A timer to determine the runtime of the executing operation.
     - `ctxt`: The trace id, span id, and span parent id for the span.
@@ -1160,18 +2364,49 @@ impl<'a, C: Clock, P: Props, F: FnOnce(SpanEvent<'a, P>)> Span<'a, C, P, F> {
         ctxt: SpanCtxt,
         event_props: P,
         default_complete: F,
+    ) -> Self {
+        Self::sampled_new(
+            |span| {
+                if filter(span) {
+                    sampler::Decision::Record
+                } else {
+                    sampler::Decision::Disable
+                }
+            },
+            module,
+            timer,
+            name,
+            ctxt,
+            event_props,
+            default_complete,
+        )
+    }
+
+    /**
+    Create a span for the given `ctxt`, using a [`sampler::Sampler`] to decide whether to record it.
+
+    This method is like [`Span::filtered_new`], except if `sampler` returns [`sampler::Decision::Discard`] then a span that's never emitted will be returned, but [`Span::push_ctxt`] will still push its context as unsampled. See the [`sampler`] module for samplers that make consistent decisions across a trace.
+    */
+    pub fn sampled_new(
+        sampler: impl FnOnce(SpanEvent<&P>) -> sampler::Decision,
+        module: impl Into<Path<'a>>,
+        timer: Timer<C>,
+        name: impl Into<Str<'a>>,
+        ctxt: SpanCtxt,
+        event_props: P,
+        default_complete: F,
     ) -> Self {
         let module = module.into();
         let name = name.into();
 
-        if filter(SpanEvent::new(
+        match sampler(SpanEvent::new(
             module.by_ref(),
             timer.start_timestamp(),
             ctxt,
             name.by_ref(),
             &event_props,
         )) {
-            Span {
+            sampler::Decision::Record => Span {
                 value: Some(ActiveSpanEvent {
                     timer,
                     module,
@@ -1181,9 +2416,23 @@ impl<'a, C: Clock, P: Props, F: FnOnce(SpanEvent<'a, P>)> Span<'a, C, P, F> {
                     include_ctxt: true,
                 }),
                 on_drop: Some(default_complete),
+                unsampled_ctxt: None,
+            },
+            sampler::Decision::Discard => {
+                // The span isn't recorded, but its ctxt is still pushed so
+                // anything within it knows the trace wasn't sampled
+                let trace_flags = ctxt
+                    .trace_flags
+                    .unwrap_or(TraceFlags::EMPTY)
+                    .with_sampled(false);
+
+                Span {
+                    value: None,
+                    on_drop: None,
+                    unsampled_ctxt: Some(ctxt.with_trace_flags(Some(trace_flags))),
+                }
             }
-        } else {
-            Self::disabled()
+            sampler::Decision::Disable => Self::disabled(),
         }
     }
 
@@ -1220,6 +2469,7 @@ impl<'a, C: Clock, P: Props, F: FnOnce(SpanEvent<'a, P>)> Span<'a, C, P, F> {
         Span {
             value: None,
             on_drop: None,
+            unsampled_ctxt: None,
         }
     }
 
@@ -1272,7 +2522,7 @@ impl<'a, C: Clock, P: Props, F: FnOnce(SpanEvent<'a, P>)> Span<'a, C, P, F> {
 
     If the span is enabled, then the trace id, span id, and parent span id will be pushed to the context. This ensures diagnostics emitted during the execution of this span are properly linked to it.
 
-    If the span is disabled then this method is a no-op.
+    If the span was discarded by the sampler given to [`Span::sampled_new`] then its context will still be pushed, but with [`TraceFlags`] that aren't sampled. This ensures any spans created during the execution of this span belong to the same trace and know it wasn't sampled, so samplers like [`sampler::ParentBased`] discard them too, and [`Traceparent::current`] propagates the decision to other services. It also means events emitted during the execution of this span will carry its trace id and span id, even though the span itself is never emitted. If the span was disabled, either by [`Span::disabled`] or by the filter given to [`Span::filtered_new`], then this method is a no-op.
     */
     pub fn push_ctxt<T: Ctxt>(&mut self, ctxt: T, ctxt_props: impl Props) -> Frame<Option<T>> {
         if let Some(ref mut value) = self.value {
@@ -1281,6 +2531,8 @@ impl<'a, C: Clock, P: Props, F: FnOnce(SpanEvent<'a, P>)> Span<'a, C, P, F> {
 
         if self.is_enabled() {
             Frame::push(Some(ctxt), self.ctxt().and_props(ctxt_props))
+        } else if let Some(ref unsampled_ctxt) = self.unsampled_ctxt {
+            Frame::push(Some(ctxt), unsampled_ctxt.and_props(ctxt_props))
         } else {
             Frame::current(None)
         }
@@ -1341,4 +2593,98 @@ mod tests {
 
         assert_eq!(id, parsed, "{}", fmt);
     }
+
+    #[test]
+    fn traceparent_parse() {
+        let traceparent: Traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            0x4bf92f3577b34da6a3ce929d0e0e4736,
+            traceparent.trace_id().to_u128()
+        );
+        assert_eq!(0x00f067aa0ba902b7, traceparent.span_id().to_u64());
+        assert!(traceparent.trace_flags().is_sampled());
+        assert_eq!(
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            traceparent.to_string()
+        );
+
+        // Later versions may carry additional data
+        assert!(Traceparent::try_from_str(
+            "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00-extra"
+        )
+        .is_ok());
+
+        for invalid in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00_4bf92f3577b34da6a3ce929d0e0e4736_00f067aa0ba902b7_01",
+            "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00extra",
+        ] {
+            assert!(Traceparent::try_from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn span_kind_parse() {
+        for kind in [
+            SpanKind::Internal,
+            SpanKind::Server,
+            SpanKind::Client,
+            SpanKind::Producer,
+            SpanKind::Consumer,
+        ] {
+            let fmt = kind.to_string();
+
+            assert_eq!(kind, fmt.parse::<SpanKind>().unwrap(), "{fmt}");
+            assert_eq!(kind, SpanKind::from_value(kind.to_value()).unwrap());
+            assert_eq!(kind, SpanKind::from_value(Value::from(&*fmt)).unwrap());
+        }
+
+        assert_eq!(SpanKind::Server, "SERVER".parse::<SpanKind>().unwrap());
+        assert_eq!(SpanKind::Internal, SpanKind::default());
+
+        for invalid in ["", "serv", "server "] {
+            assert!(invalid.parse::<SpanKind>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn span_links_from_value() {
+        let link: SpanLink = "4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7"
+            .parse()
+            .unwrap();
+
+        let links = SpanLinks::new()
+            .with_link_attributes(link, [("queue", Value::from("orders"))])
+            .with_link(link);
+
+        let read = SpanLinks::from_value(links.to_value()).unwrap();
+        let read = read.iter().collect::<Vec<_>>();
+
+        assert_eq!(2, read.len());
+        assert_eq!(link, *read[0].0);
+        assert_eq!(Some("orders"), read[0].1.pull::<&str, _>("queue"));
+        assert!(read[1].1.is_empty());
+
+        assert_eq!(
+            "4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7,4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            links.to_string()
+        );
+
+        // A single link is a valid set of links
+        assert_eq!(1, SpanLinks::from_value(link.to_value()).unwrap().len());
+
+        for invalid in [Value::from(1), Value::null(), Value::from("links")] {
+            assert!(SpanLinks::from_value(invalid).is_none());
+        }
+    }
 }
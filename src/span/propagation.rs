@@ -0,0 +1,721 @@
+/*!
+Propagating span context across services using [W3C Trace Context](https://www.w3.org/TR/trace-context/) headers.
+
+When an incoming request arrives, [`extract`] reads the `traceparent` and `tracestate` headers from it. The result can be pushed onto the ambient context so any spans created while handling the request belong to the caller's trace:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use std::collections::HashMap;
+
+let mut incoming = HashMap::new();
+incoming.insert(
+    "traceparent".to_owned(),
+    "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_owned(),
+);
+incoming.insert("tracestate".to_owned(), "congo=t61rcWkgMzE".to_owned());
+
+let ctxt = emit::platform::thread_local_ctxt::ThreadLocalCtxt::new();
+
+let frame = emit::Frame::push(&ctxt, emit::span::propagation::extract(&incoming));
+
+frame.call(|| {
+    let mut outgoing = HashMap::new();
+    emit::span::propagation::inject(&ctxt, &mut outgoing);
+
+    assert_eq!(
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        outgoing["traceparent"],
+    );
+    assert_eq!("congo=t61rcWkgMzE", outgoing["tracestate"]);
+});
+# }
+```
+
+When making an outgoing request, [`inject`] writes the current span context as `traceparent` and `tracestate` headers on it.
+
+Application-specific properties, like a tenant id, can also be propagated as [W3C Baggage](https://www.w3.org/TR/baggage/). [`extract_baggage`] reads selected properties from the `baggage` header into a [`Baggage`] that can be pushed onto the ambient context, and [`inject_baggage`] writes selected properties from the ambient context to the `baggage` header:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use std::collections::HashMap;
+
+let mut incoming = HashMap::new();
+incoming.insert(
+    "baggage".to_owned(),
+    "tenant_id=acme,region=ap%20southeast".to_owned(),
+);
+
+let ctxt = emit::platform::thread_local_ctxt::ThreadLocalCtxt::new();
+
+let frame = emit::Frame::push(
+    &ctxt,
+    emit::span::propagation::extract_baggage(&incoming, &["tenant_id", "region"]),
+);
+
+frame.call(|| {
+    let mut outgoing = HashMap::new();
+    emit::span::propagation::inject_baggage(&ctxt, &["tenant_id"], &mut outgoing);
+
+    assert_eq!("tenant_id=acme", outgoing["baggage"]);
+});
+# }
+```
+
+Headers are read and written through the [`Extractor`] and [`Injector`] traits. They're implemented for maps of strings, and can be implemented for the header types of web frameworks and HTTP clients.
+*/
+
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Write as _},
+    ops::ControlFlow,
+    str::{self, FromStr},
+};
+
+use emit_core::{
+    ctxt::Ctxt,
+    props::Props,
+    str::{Str, ToStr},
+    value::{ToValue, Value},
+    well_known::{self, KEY_TRACE_STATE},
+};
+
+use crate::span::{SpanCtxt, Traceparent, HEX_DECODE_TABLE, HEX_ENCODE_TABLE, SHL4_TABLE};
+
+/**
+The name of the header carrying a [`Traceparent`].
+*/
+pub const HEADER_TRACEPARENT: &str = "traceparent";
+
+/**
+The name of the header carrying vendor-specific trace data.
+
+The value of this header is propagated through the ambient context as [`KEY_TRACE_STATE`].
+*/
+pub const HEADER_TRACESTATE: &str = "tracestate";
+
+/**
+The name of the header carrying [`Baggage`].
+*/
+pub const HEADER_BAGGAGE: &str = "baggage";
+
+/**
+The maximum length of a tracestate that will be propagated.
+*/
+const MAX_TRACESTATE_LEN: usize = 512;
+
+/**
+The maximum number of list members in a tracestate that will be propagated.
+*/
+const MAX_TRACESTATE_MEMBERS: usize = 32;
+
+/**
+The maximum length of baggage that will be propagated.
+*/
+const MAX_BAGGAGE_LEN: usize = 8192;
+
+/**
+The maximum number of list members in baggage that will be propagated.
+*/
+const MAX_BAGGAGE_MEMBERS: usize = 64;
+
+/**
+A set of headers that trace context can be read from.
+*/
+pub trait Extractor {
+    /**
+    Get the value of the header called `name`.
+
+    Names are always lowercase, like `traceparent`.
+    */
+    fn get(&self, name: &str) -> Option<&str>;
+}
+
+impl<T: Extractor + ?Sized> Extractor for &T {
+    fn get(&self, name: &str) -> Option<&str> {
+        (**self).get(name)
+    }
+}
+
+impl Extractor for BTreeMap<String, String> {
+    fn get(&self, name: &str) -> Option<&str> {
+        BTreeMap::get(self, name).map(|value| &**value)
+    }
+}
+
+/**
+A set of headers that trace context can be written to.
+*/
+pub trait Injector {
+    /**
+    Set the value of the header called `name`, replacing any existing value.
+
+    Names are always lowercase, like `traceparent`.
+    */
+    fn set(&mut self, name: &str, value: &str);
+}
+
+impl<T: Injector + ?Sized> Injector for &mut T {
+    fn set(&mut self, name: &str, value: &str) {
+        (**self).set(name, value)
+    }
+}
+
+impl Injector for BTreeMap<String, String> {
+    fn set(&mut self, name: &str, value: &str) {
+        self.insert(name.to_owned(), value.to_owned());
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_support {
+    use super::*;
+
+    use std::{collections::HashMap, hash::BuildHasher};
+
+    impl<S: BuildHasher> Extractor for HashMap<String, String, S> {
+        fn get(&self, name: &str) -> Option<&str> {
+            HashMap::get(self, name).map(|value| &**value)
+        }
+    }
+
+    impl<S: BuildHasher> Injector for HashMap<String, String, S> {
+        fn set(&mut self, name: &str, value: &str) {
+            self.insert(name.to_owned(), value.to_owned());
+        }
+    }
+}
+
+/**
+The trace context read from a set of headers by [`extract`].
+
+`Extracted` implements [`Props`], so it can be pushed onto the ambient context through [`crate::Frame::push`].
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct Extracted<'a> {
+    span_ctxt: SpanCtxt,
+    trace_state: Option<&'a str>,
+}
+
+impl<'a> Extracted<'a> {
+    /**
+    Get the [`SpanCtxt`] of the caller.
+
+    If no valid traceparent was extracted then the context will be empty.
+    */
+    pub fn span_ctxt(&self) -> &SpanCtxt {
+        &self.span_ctxt
+    }
+
+    /**
+    Get the [`Traceparent`] of the caller.
+    */
+    pub fn traceparent(&self) -> Option<Traceparent> {
+        Traceparent::from_span_ctxt(&self.span_ctxt)
+    }
+
+    /**
+    Get the tracestate of the caller.
+    */
+    pub fn trace_state(&self) -> Option<&'a str> {
+        self.trace_state
+    }
+}
+
+impl<'a> Props for Extracted<'a> {
+    fn for_each<'kv, F: FnMut(Str<'kv>, Value<'kv>) -> ControlFlow<()>>(
+        &'kv self,
+        mut for_each: F,
+    ) -> ControlFlow<()> {
+        self.span_ctxt.for_each(&mut for_each)?;
+
+        if let Some(ref trace_state) = self.trace_state {
+            for_each(KEY_TRACE_STATE.to_str(), trace_state.to_value())?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+/**
+Read the trace context from a set of incoming headers.
+
+The `traceparent` header is parsed by [`Traceparent::try_from_str`]. If it's missing or invalid then the extracted context is empty. The `tracestate` header is only extracted along with a valid traceparent, and is discarded if it's longer than 512 characters or contains more than 32 list members.
+*/
+pub fn extract<E: Extractor + ?Sized>(headers: &E) -> Extracted<'_> {
+    let Some(traceparent) = headers
+        .get(HEADER_TRACEPARENT)
+        .and_then(|traceparent| Traceparent::try_from_str(traceparent).ok())
+    else {
+        return Extracted {
+            span_ctxt: SpanCtxt::empty(),
+            trace_state: None,
+        };
+    };
+
+    let trace_state = headers
+        .get(HEADER_TRACESTATE)
+        .map(|trace_state| trace_state.trim_matches(|c| c == ' ' || c == '\t'))
+        .filter(|trace_state| is_valid_trace_state(trace_state));
+
+    Extracted {
+        span_ctxt: traceparent.to_span_ctxt(),
+        trace_state,
+    }
+}
+
+/**
+Write the trace context from an ambient [`Ctxt`] to a set of outgoing headers.
+
+The `traceparent` header is formatted from [`Traceparent::current`]. If there's no current trace or span id then no headers are written. The `tracestate` header is written from the [`KEY_TRACE_STATE`] property if there is one.
+*/
+pub fn inject<C: Ctxt, I: Injector + ?Sized>(ctxt: C, headers: &mut I) {
+    let Some(traceparent) = Traceparent::current(&ctxt) else {
+        return;
+    };
+
+    headers.set(
+        HEADER_TRACEPARENT,
+        str::from_utf8(&traceparent.to_bytes()).unwrap(),
+    );
+
+    ctxt.with_current(|props| {
+        if let Some(trace_state) = props
+            .get(KEY_TRACE_STATE)
+            .and_then(|trace_state| trace_state.to_cow_str())
+        {
+            if is_valid_trace_state(&trace_state) {
+                headers.set(HEADER_TRACESTATE, &trace_state);
+            }
+        }
+    });
+}
+
+fn is_valid_trace_state(trace_state: &str) -> bool {
+    if trace_state.is_empty() || trace_state.len() > MAX_TRACESTATE_LEN {
+        return false;
+    }
+
+    if !trace_state.bytes().all(|b| matches!(b, b' '..=b'~')) {
+        return false;
+    }
+
+    let mut members = 0;
+    for member in trace_state.split(',') {
+        let member = member.trim_matches(|c| c == ' ' || c == '\t');
+
+        // Empty list members are allowed and ignored
+        if member.is_empty() {
+            continue;
+        }
+
+        if !member.contains('=') {
+            return false;
+        }
+
+        members += 1;
+    }
+
+    members > 0 && members <= MAX_TRACESTATE_MEMBERS
+}
+
+/**
+A set of [W3C Baggage](https://www.w3.org/TR/baggage/) key-values.
+
+Baggage carries application-specific properties across service boundaries. Values are always strings.
+
+`Baggage` implements [`Props`], so it can be pushed onto the ambient context through [`crate::Frame::push`]. Key-values that collide with [well-known](crate::well_known) properties, like `trace_id` or `lvl`, are never yielded as properties, so baggage can't change the way events are interpreted. Its [`fmt::Display`] implementation formats it as a `baggage` header, dropping any key-values that would exceed the limit of 64 list members or 8192 bytes.
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baggage {
+    entries: Vec<(String, String)>,
+}
+
+impl Baggage {
+    /**
+    Create an empty set of baggage.
+    */
+    pub const fn new() -> Self {
+        Baggage {
+            entries: Vec::new(),
+        }
+    }
+
+    /**
+    Collect baggage from the properties in `props` named by `keys`.
+
+    Values are converted into strings. Keys that aren't valid baggage keys, or that aren't present in `props`, are ignored.
+    */
+    pub fn from_props(props: impl Props, keys: &[&str]) -> Self {
+        let mut baggage = Baggage::new();
+
+        for key in keys {
+            if !is_token(key) {
+                continue;
+            }
+
+            if let Some(value) = props.get(*key) {
+                baggage.entries.push(((*key).to_owned(), value.to_string()));
+            }
+        }
+
+        baggage
+    }
+
+    /**
+    Try parse a `baggage` header.
+
+    Values are percent-decoded, and any properties on list members are discarded. Parsing will fail if the header is longer than 8192 bytes, has more than 64 list members, or any list members are invalid.
+    */
+    pub fn try_from_str(baggage: &str) -> Result<Self, ParseBaggageError> {
+        if baggage.len() > MAX_BAGGAGE_LEN {
+            return Err(ParseBaggageError {});
+        }
+
+        let mut entries = Vec::new();
+
+        for member in baggage.split(',') {
+            let member = trim_ows(member);
+
+            if member.is_empty() {
+                continue;
+            }
+
+            if entries.len() == MAX_BAGGAGE_MEMBERS {
+                return Err(ParseBaggageError {});
+            }
+
+            // Properties on list members, like `key=value;property`, are discarded
+            let (key, value) = member
+                .split(';')
+                .next()
+                .and_then(|member| member.split_once('='))
+                .ok_or(ParseBaggageError {})?;
+
+            let key = trim_ows(key);
+
+            if !is_token(key) {
+                return Err(ParseBaggageError {});
+            }
+
+            entries.push((key.to_owned(), percent_decode(trim_ows(value))?));
+        }
+
+        Ok(Baggage { entries })
+    }
+
+    /**
+    Keep only the key-values named by `keys`.
+    */
+    pub fn retain_keys(&mut self, keys: &[&str]) {
+        self.entries.retain(|(k, _)| keys.contains(&&**k));
+    }
+
+    /**
+    Get the value of the key-value called `key`.
+    */
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| &**v)
+    }
+
+    /**
+    Iterate over the key-values in the baggage.
+    */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (&**k, &**v))
+    }
+
+    /**
+    Get the number of key-values in the baggage.
+    */
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /**
+    Whether the baggage contains no key-values.
+    */
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl fmt::Display for Baggage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut len = 0;
+        let mut members = 0;
+
+        for (key, value) in &self.entries {
+            if members == MAX_BAGGAGE_MEMBERS {
+                break;
+            }
+
+            let sep = if members > 0 { 1 } else { 0 };
+            let member_len = key.len() + 1 + percent_encoded_len(value);
+
+            // Key-values that don't fit are skipped entirely
+            // rather than truncated
+            if len + sep + member_len > MAX_BAGGAGE_LEN {
+                continue;
+            }
+
+            if sep > 0 {
+                f.write_char(',')?;
+            }
+
+            f.write_str(key)?;
+            f.write_char('=')?;
+            percent_encode(value, f)?;
+
+            len += sep + member_len;
+            members += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Baggage {
+    type Err = ParseBaggageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl Props for Baggage {
+    fn for_each<'kv, F: FnMut(Str<'kv>, Value<'kv>) -> ControlFlow<()>>(
+        &'kv self,
+        mut for_each: F,
+    ) -> ControlFlow<()> {
+        for (k, v) in &self.entries {
+            // Baggage comes from outside the application, so it's not trusted
+            // to set properties like `span_id` that other components interpret
+            if well_known::is_well_known(k) {
+                continue;
+            }
+
+            for_each(Str::new_ref(k), Value::from(&**v))?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+/**
+An error encountered attempting to parse [`Baggage`].
+*/
+#[derive(Debug)]
+pub struct ParseBaggageError {}
+
+impl fmt::Display for ParseBaggageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the input was not valid baggage")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBaggageError {}
+
+/**
+Read the key-values named by `keys` from the baggage in a set of incoming headers.
+
+The `baggage` header is parsed by [`Baggage::try_from_str`]. If it's missing or invalid then the extracted baggage is empty.
+
+Only key-values that are explicitly named are extracted, so callers can't push arbitrary properties onto the ambient context. This mirrors [`inject_baggage`].
+*/
+pub fn extract_baggage<E: Extractor + ?Sized>(headers: &E, keys: &[&str]) -> Baggage {
+    let mut baggage = headers
+        .get(HEADER_BAGGAGE)
+        .and_then(|baggage| Baggage::try_from_str(baggage).ok())
+        .unwrap_or_default();
+
+    baggage.retain_keys(keys);
+
+    baggage
+}
+
+/**
+Write the properties named by `keys` from an ambient [`Ctxt`] to the `baggage` header of a set of outgoing headers.
+
+Only properties that are explicitly named are propagated, so ambient properties aren't unintentionally leaked to other services. If none of the properties are present then no header is written.
+*/
+pub fn inject_baggage<C: Ctxt, I: Injector + ?Sized>(ctxt: C, keys: &[&str], headers: &mut I) {
+    let baggage = ctxt.with_current(|props| Baggage::from_props(props, keys));
+
+    if !baggage.is_empty() {
+        headers.set(HEADER_BAGGAGE, &baggage.to_string());
+    }
+}
+
+fn trim_ows(s: &str) -> &str {
+    s.trim_matches(|c| c == ' ' || c == '\t')
+}
+
+fn is_token(key: &str) -> bool {
+    !key.is_empty()
+        && key.bytes().all(|b| {
+            b.is_ascii_alphanumeric()
+                || matches!(
+                    b,
+                    b'!' | b'#'
+                        | b'$'
+                        | b'%'
+                        | b'&'
+                        | b'\''
+                        | b'*'
+                        | b'+'
+                        | b'-'
+                        | b'.'
+                        | b'^'
+                        | b'_'
+                        | b'`'
+                        | b'|'
+                        | b'~'
+                )
+        })
+}
+
+fn is_baggage_octet(b: u8) -> bool {
+    // `%` is allowed by the spec, but is always encoded
+    // so it can be unambiguously decoded
+    matches!(b, 0x21 | 0x23..=0x24 | 0x26..=0x2b | 0x2d..=0x3a | 0x3c..=0x5b | 0x5d..=0x7e)
+}
+
+fn percent_encoded_len(value: &str) -> usize {
+    value
+        .bytes()
+        .map(|b| if is_baggage_octet(b) { 1 } else { 3 })
+        .sum()
+}
+
+fn percent_encode(value: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    for b in value.bytes() {
+        if is_baggage_octet(b) {
+            f.write_char(b as char)?;
+        } else {
+            f.write_char('%')?;
+            f.write_char(HEX_ENCODE_TABLE[(b >> 4) as usize].to_ascii_uppercase() as char)?;
+            f.write_char(HEX_ENCODE_TABLE[(b & 0x0f) as usize].to_ascii_uppercase() as char)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn percent_decode(value: &str) -> Result<String, ParseBaggageError> {
+    let value = value.as_bytes();
+    let mut decoded = Vec::with_capacity(value.len());
+
+    let mut i = 0;
+    while i < value.len() {
+        match value[i] {
+            b'%' => {
+                let (h1, h2) = match value.get(i + 1..i + 3) {
+                    Some(&[h1, h2]) => {
+                        (HEX_DECODE_TABLE[h1 as usize], HEX_DECODE_TABLE[h2 as usize])
+                    }
+                    _ => return Err(ParseBaggageError {}),
+                };
+
+                if h1 | h2 == 0xff {
+                    return Err(ParseBaggageError {});
+                }
+
+                decoded.push(SHL4_TABLE[h1 as usize] | h2);
+                i += 3;
+            }
+            b if is_baggage_octet(b) => {
+                decoded.push(b);
+                i += 1;
+            }
+            _ => return Err(ParseBaggageError {}),
+        }
+    }
+
+    // Invalid UTF8 sequences are replaced rather than failing
+    Ok(String::from_utf8_lossy(&decoded).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baggage_roundtrip() {
+        let baggage: Baggage =
+            "tenant_id = acme;ttl=3 , region=ap%20southeast%2C%202,flag=%F0%9F%A6%80"
+                .parse()
+                .unwrap();
+
+        assert_eq!(Some("acme"), baggage.get("tenant_id"));
+        assert_eq!(Some("ap southeast, 2"), baggage.get("region"));
+        assert_eq!(Some("🦀"), baggage.get("flag"));
+
+        assert_eq!(
+            "tenant_id=acme,region=ap%20southeast%2C%202,flag=%F0%9F%A6%80",
+            baggage.to_string()
+        );
+
+        for invalid in ["key", "k y=v", "key=\"v\"", "key=%2", "key=%zz"] {
+            assert!(Baggage::try_from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn baggage_limits() {
+        let members = (0..65).map(|i| format!("k{i}=v")).collect::<Vec<_>>();
+        assert!(Baggage::try_from_str(&members.join(",")).is_err());
+        assert!(Baggage::try_from_str(&members[..64].join(",")).is_ok());
+
+        // Key-values that would overflow the header are skipped
+        let large = "v".repeat(MAX_BAGGAGE_LEN);
+        let baggage = Baggage {
+            entries: vec![
+                ("a".to_owned(), "1".to_owned()),
+                ("b".to_owned(), large),
+                ("c".to_owned(), "3".to_owned()),
+            ],
+        };
+
+        assert_eq!("a=1,c=3", baggage.to_string());
+    }
+
+    #[test]
+    fn extract_baggage_untrusted() {
+        let mut headers = BTreeMap::new();
+        headers.insert(
+            HEADER_BAGGAGE.to_owned(),
+            "tenant_id=acme,span_id=0000000000000001,trace_id=00000000000000000000000000000001,event_kind=span,lvl=error,region=ap".to_owned(),
+        );
+
+        let baggage = extract_baggage(&headers, &["tenant_id", "lvl"]);
+
+        assert_eq!(Some("acme"), baggage.get("tenant_id"));
+        assert_eq!(None, baggage.get("region"));
+        assert_eq!(None, baggage.get("span_id"));
+
+        // Well-known properties are never yielded, even if they're allowed
+        assert_eq!(
+            Some("acme"),
+            Props::get(&baggage, "tenant_id")
+                .map(|v| v.to_string())
+                .as_deref()
+        );
+        assert!(Props::get(&baggage, "lvl").is_none());
+
+        let baggage: Baggage = "span_id=0000000000000001,tenant_id=acme".parse().unwrap();
+
+        assert!(Props::get(&baggage, "span_id").is_none());
+        assert!(Props::get(&baggage, "tenant_id").is_some());
+    }
+}
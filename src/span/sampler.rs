@@ -0,0 +1,476 @@
+/*!
+Samplers for spans.
+
+A [`Sampler`] decides whether a span should be recorded when it starts. Samplers can be given to the span macros through their `when` control parameter:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::span::sampler;
+
+#[emit::span(when: sampler::parent_based(sampler::trace_id_ratio(0.1)), "handle request")]
+fn handle_request() {
+    // Your code goes here
+}
+
+handle_request();
+# }
+```
+
+A plain filter, like [`crate::filter::sample_ratio`], makes an independent decision for each span, so a trace can end up with some of its spans missing. The samplers in this module make decisions that are consistent across a whole trace:
+
+- [`TraceIdRatio`] makes its decision using the bits of the span's [`TraceId`], so every span in the same trace gets the same decision, even across services that use the same ratio.
+- [`ParentBased`] follows the decision made by the span's parent, as signalled by its [`TraceFlags`], and falls back to another sampler for root spans.
+- [`Always`] and [`Never`] keep or discard all spans.
+
+When a span is discarded by a sampler, its trace id and span id are still pushed onto the ambient context, along with [`TraceFlags`] that aren't sampled. Any spans created within it belong to the same trace, and are discarded by a [`ParentBased`] sampler. A traceparent propagated from within it will tell other services the trace wasn't sampled.
+
+Any [`Filter`] can also be used as a sampler, but a span that doesn't match a filter is disabled instead of discarded. Nothing is pushed onto the ambient context for it, so spans within it are parented to the nearest enclosing span instead.
+
+Samplers only consider the `trace_id`, `span_parent`, and `trace_flags` of the span they're given. They replace the filter configured on the runtime, so they won't apply any other filtering to the span.
+
+# Tail sampling
+
+The samplers above make their decision when a span starts, before anything is known about how it will turn out. That means they'll discard traces that fail or run slowly just as readily as any others. With the `std` Cargo feature, a [`TailSampler`] can be used instead to buffer events by their trace, and only decide whether to emit them once the trace has completed:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+# use std::time::Duration;
+# let emitter = emit::Empty;
+use emit::span::sampler::TailSampler;
+
+let sampled = TailSampler::new()
+    .keep_errors(true)
+    .keep_slower_than(Duration::from_secs(1))
+    .keep_ratio(0.01)
+    .wrap_emitter(emitter);
+
+// Report these through an `emit::metric::Reporter`
+let metrics = sampled.metric_source();
+
+let rt = emit::setup()
+    .emit_to(sampled)
+    .init();
+
+rt.blocking_flush(Duration::from_secs(5));
+# }
+```
+
+A trace is kept if any of its events have an error level, if any of its spans took longer than a threshold, or by a ratio on its trace id. A trace is decided when its local root span completes, or once a fixed window has elapsed since its first event. The local root is a span without a parent, or a span whose parent is in another service. Spans with a parent in another service can only be recognized when span begin events are emitted, otherwise their trace is decided when its window elapses. The window isn't checked on a timer, only when later events are emitted, or when the sampler is flushed. Buffered traces are bounded by their number, the number of events across all of them, and the number of events in each one. The [`TailSamplerMetrics`] returned by [`TailSampledEmitter::metric_source`] report how many traces were buffered, kept, discarded, or decided early to stay within these bounds.
+
+Tail sampling only sees the events emitted by the current service, so it can't keep a whole distributed trace consistently. It can be combined with head sampling through [`ParentBased`] so the services a trace calls follow the decision of its caller.
+*/
+
+use emit_core::{
+    event::ToEvent,
+    filter::Filter,
+    props::Props,
+    well_known::{KEY_SPAN_PARENT, KEY_TRACE_FLAGS, KEY_TRACE_ID},
+};
+
+use super::{SpanId, TraceFlags, TraceId};
+
+/**
+A decision made by a [`Sampler`] about whether to record a span.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /**
+    Record the span.
+    */
+    Record,
+    /**
+    Don't record the span, but push its context with [`TraceFlags`] that aren't sampled, so spans within it can follow the decision.
+    */
+    Discard,
+    /**
+    Don't record the span, or push its context.
+    */
+    Disable,
+}
+
+impl Decision {
+    /**
+    Whether the span will be recorded.
+    */
+    pub fn is_record(&self) -> bool {
+        matches!(self, Decision::Record)
+    }
+}
+
+/**
+A sampler that decides whether a span should be recorded when it starts.
+
+Samplers only ever [`Decision::Discard`] spans that aren't recorded. Any [`Filter`] is also a sampler, but one that will [`Decision::Disable`] spans that it doesn't match.
+*/
+pub trait Sampler {
+    /**
+    Decide whether the given span should be recorded.
+    */
+    fn sample<E: ToEvent>(&self, span: E) -> Decision;
+}
+
+impl<F: Filter> Sampler for F {
+    fn sample<E: ToEvent>(&self, span: E) -> Decision {
+        if self.matches(span) {
+            Decision::Record
+        } else {
+            Decision::Disable
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod tail;
+#[cfg(feature = "std")]
+pub use self::tail::*;
+
+/**
+A [`Sampler`] that keeps all spans.
+
+Use [`always`] to create an `Always` sampler.
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Always;
+
+impl Sampler for Always {
+    fn sample<E: ToEvent>(&self, _: E) -> Decision {
+        Decision::Record
+    }
+}
+
+/**
+Create a sampler that keeps all spans.
+*/
+pub const fn always() -> Always {
+    Always
+}
+
+/**
+A [`Sampler`] that discards all spans.
+
+Use [`never`] to create a `Never` sampler.
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Never;
+
+impl Sampler for Never {
+    fn sample<E: ToEvent>(&self, _: E) -> Decision {
+        Decision::Discard
+    }
+}
+
+/**
+Create a sampler that discards all spans.
+*/
+pub const fn never() -> Never {
+    Never
+}
+
+/**
+A [`Sampler`] that keeps a proportion of traces based on their [`TraceId`].
+
+The decision is made by comparing the rightmost 56 bits of the trace id against a threshold derived from the ratio. These are the bits that [W3C Trace Context](https://www.w3.org/TR/trace-context-2/#randomness-of-trace-id) requires to be random. Since the decision only depends on the trace id, every span in the same trace will get the same decision.
+
+Spans without a trace id are kept.
+
+Use [`trace_id_ratio`] to create a `TraceIdRatio` sampler.
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct TraceIdRatio {
+    ratio: f64,
+    threshold: u64,
+}
+
+const TRACE_ID_RANDOM_BITS: u32 = 56;
+const TRACE_ID_RANDOM_MASK: u128 = (1 << TRACE_ID_RANDOM_BITS) - 1;
+
+impl TraceIdRatio {
+    /**
+    Create a sampler that keeps the given `ratio` of traces.
+
+    The `ratio` is between `0.0` (keep none) and `1.0` (keep all). Values outside this range are clamped to it.
+    */
+    pub fn new(ratio: f64) -> Self {
+        let ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+
+        // Trace ids are kept if their random bits are less than the threshold,
+        // so a ratio of `1.0` gives a threshold above any 56 bit value
+        let threshold = (ratio * (1u64 << TRACE_ID_RANDOM_BITS) as f64) as u64;
+
+        TraceIdRatio { ratio, threshold }
+    }
+
+    /**
+    Get the ratio of traces that will be kept.
+    */
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /**
+    Whether a span in the trace with the given `trace_id` should be kept.
+    */
+    pub fn should_sample(&self, trace_id: &TraceId) -> bool {
+        ((trace_id.to_u128() & TRACE_ID_RANDOM_MASK) as u64) < self.threshold
+    }
+}
+
+impl Sampler for TraceIdRatio {
+    fn sample<E: ToEvent>(&self, span: E) -> Decision {
+        let span = span.to_event();
+
+        match span.props().pull::<TraceId, _>(KEY_TRACE_ID) {
+            Some(trace_id) if !self.should_sample(&trace_id) => Decision::Discard,
+            _ => Decision::Record,
+        }
+    }
+}
+
+/**
+Create a sampler that keeps the given `ratio` of traces.
+
+See [`TraceIdRatio`] for details.
+*/
+pub fn trace_id_ratio(ratio: f64) -> TraceIdRatio {
+    TraceIdRatio::new(ratio)
+}
+
+/**
+A [`Sampler`] that follows the sampling decision of a span's parent.
+
+If the span has a parent, then it's kept if the parent's [`TraceFlags`] are sampled. Parents without any trace flags are assumed to be sampled. The trace flags of a parent in another service are propagated through its traceparent header. See [`crate::span::propagation`] for details.
+
+If the span doesn't have a parent, then the decision is made by a root sampler, which will typically be a [`TraceIdRatio`].
+
+Use [`parent_based`] to create a `ParentBased` sampler.
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct ParentBased<R> {
+    root: R,
+}
+
+impl<R> ParentBased<R> {
+    /**
+    Create a sampler that follows the decision of a span's parent, using `root` for spans without one.
+    */
+    pub const fn new(root: R) -> Self {
+        ParentBased { root }
+    }
+
+    /**
+    Get the sampler used for spans without a parent.
+    */
+    pub const fn root(&self) -> &R {
+        &self.root
+    }
+}
+
+impl<R: Sampler> Sampler for ParentBased<R> {
+    fn sample<E: ToEvent>(&self, span: E) -> Decision {
+        let span = span.to_event();
+
+        if span.props().pull::<SpanId, _>(KEY_SPAN_PARENT).is_some() {
+            let sampled = span
+                .props()
+                .pull::<TraceFlags, _>(KEY_TRACE_FLAGS)
+                .map(|trace_flags| trace_flags.is_sampled())
+                .unwrap_or(true);
+
+            if sampled {
+                Decision::Record
+            } else {
+                Decision::Discard
+            }
+        } else {
+            self.root.sample(span)
+        }
+    }
+}
+
+/**
+Create a sampler that follows the decision of a span's parent, using `root` for spans without one.
+
+See [`ParentBased`] for details.
+*/
+pub const fn parent_based<R: Sampler>(root: R) -> ParentBased<R> {
+    ParentBased::new(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        span::{SpanCtxt, SpanEvent},
+        Empty, Path,
+    };
+
+    fn span(ctxt: SpanCtxt) -> SpanEvent<'static, Empty> {
+        SpanEvent::new(
+            Path::new("test"),
+            None::<crate::Extent>,
+            ctxt,
+            "test",
+            Empty,
+        )
+    }
+
+    #[test]
+    fn trace_id_ratio_bounds() {
+        let min = TraceId::from_u128(1).unwrap();
+        let max = TraceId::from_u128(TRACE_ID_RANDOM_MASK).unwrap();
+
+        assert!(!trace_id_ratio(0.0).should_sample(&min));
+        assert!(trace_id_ratio(1.0).should_sample(&max));
+        assert!(trace_id_ratio(2.0).should_sample(&max));
+        assert!(!trace_id_ratio(f64::NAN).should_sample(&max));
+
+        let sampler = trace_id_ratio(0.5);
+
+        assert!(sampler.should_sample(&min));
+        assert!(!sampler.should_sample(&max));
+
+        // Only the rightmost 56 bits are considered
+        let high = TraceId::from_u128(u128::MAX << TRACE_ID_RANDOM_BITS | 1).unwrap();
+        assert!(sampler.should_sample(&high));
+    }
+
+    #[test]
+    fn parent_based_follows_parent() {
+        let trace_id = TraceId::from_u128(TRACE_ID_RANDOM_MASK);
+        let span_parent = SpanId::from_u64(1);
+        let span_id = SpanId::from_u64(2);
+
+        let sampler = parent_based(never());
+
+        assert_eq!(
+            Decision::Discard,
+            sampler.sample(span(SpanCtxt::new(trace_id, None, span_id)))
+        );
+        assert_eq!(
+            Decision::Record,
+            sampler.sample(span(SpanCtxt::new(trace_id, span_parent, span_id)))
+        );
+        assert_eq!(
+            Decision::Record,
+            sampler.sample(span(
+                SpanCtxt::new(trace_id, span_parent, span_id)
+                    .with_trace_flags(Some(TraceFlags::SAMPLED))
+            ))
+        );
+        assert_eq!(
+            Decision::Discard,
+            sampler.sample(span(
+                SpanCtxt::new(trace_id, span_parent, span_id)
+                    .with_trace_flags(Some(TraceFlags::EMPTY))
+            ))
+        );
+
+        // Filters used as the root sampler disable spans instead of discarding them
+        assert_eq!(
+            Decision::Disable,
+            parent_based(crate::filter::from_fn(|_| false))
+                .sample(span(SpanCtxt::new(trace_id, None, span_id)))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parent_unsampled_discards_children() {
+        use crate::{
+            platform::{rand_rng::RandRng, thread_local_ctxt::ThreadLocalCtxt},
+            span::{Span, Traceparent},
+            timer::Timer,
+        };
+
+        let ctxt = ThreadLocalCtxt::new();
+        let rng = RandRng::new();
+
+        let mut parent = Span::sampled_new(
+            |span| never().sample(span),
+            Path::new("test"),
+            Timer::start(Empty),
+            "parent",
+            SpanCtxt::current(ctxt).new_child(rng),
+            Empty,
+            |_| panic!("unsampled spans aren't emitted"),
+        );
+
+        assert!(!parent.is_enabled());
+
+        let mut parent_frame = parent.push_ctxt(&ctxt, Empty);
+        let _parent_guard = parent_frame.enter();
+
+        let parent_ctxt = SpanCtxt::current(ctxt);
+
+        assert!(parent_ctxt.trace_id().is_some());
+        assert!(parent_ctxt.span_id().is_some());
+        assert_eq!(
+            TraceFlags::EMPTY,
+            *Traceparent::current(ctxt).unwrap().trace_flags()
+        );
+
+        // The child follows its unsampled parent instead of making its own decision
+        let mut child = Span::sampled_new(
+            |span| parent_based(always()).sample(span),
+            Path::new("test"),
+            Timer::start(Empty),
+            "child",
+            parent_ctxt.new_child(rng),
+            Empty,
+            |_| panic!("unsampled spans aren't emitted"),
+        );
+
+        assert!(!child.is_enabled());
+
+        let mut child_frame = child.push_ctxt(&ctxt, Empty);
+        let _child_guard = child_frame.enter();
+
+        let child_ctxt = SpanCtxt::current(ctxt);
+
+        assert_eq!(parent_ctxt.trace_id(), child_ctxt.trace_id());
+        assert_eq!(parent_ctxt.span_id(), child_ctxt.span_parent());
+        assert_eq!(Some(&TraceFlags::EMPTY), child_ctxt.trace_flags());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn filter_disables_span() {
+        use crate::{
+            platform::{rand_rng::RandRng, thread_local_ctxt::ThreadLocalCtxt},
+            span::Span,
+            timer::Timer,
+        };
+
+        let ctxt = ThreadLocalCtxt::new();
+        let rng = RandRng::new();
+
+        let mut span = Span::filtered_new(
+            |_| false,
+            Path::new("test"),
+            Timer::start(Empty),
+            "span",
+            SpanCtxt::current(ctxt).new_child(rng),
+            Empty,
+            |_| panic!("disabled spans aren't emitted"),
+        );
+
+        assert!(!span.is_enabled());
+
+        // Nothing is pushed for a span that doesn't match a filter
+        let mut frame = span.push_ctxt(&ctxt, Empty);
+        let _guard = frame.enter();
+
+        let current = SpanCtxt::current(ctxt);
+
+        assert!(current.trace_id().is_none());
+        assert!(current.span_id().is_none());
+        assert!(current.trace_flags().is_none());
+    }
+}
@@ -0,0 +1,665 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    mem,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use emit_core::{
+    emitter::Emitter,
+    event::{OwnedEvent, ToEvent},
+    props::Props,
+    runtime::InternalEmitter,
+    well_known::{KEY_EVENT_KIND, KEY_LVL, KEY_SPAN_ID, KEY_SPAN_PARENT, KEY_TRACE_ID},
+};
+
+use crate::{
+    kind::Kind,
+    level::Level,
+    metric::{sampler::Sampler, Metric, Source},
+    span::{SpanId, TraceId},
+    Empty,
+};
+
+use super::TraceIdRatio;
+
+/**
+A set of policies for sampling whole traces after they've completed.
+
+Use [`TailSampler::wrap_emitter`] to buffer events by their trace before emitting them. See the [`crate::span::sampler`] module for details.
+*/
+#[derive(Debug, Clone)]
+pub struct TailSampler {
+    window: Duration,
+    max_traces: usize,
+    max_events: usize,
+    max_events_per_trace: usize,
+    keep_errors: bool,
+    keep_slower_than: Option<Duration>,
+    keep_ratio: TraceIdRatio,
+}
+
+impl Default for TailSampler {
+    fn default() -> Self {
+        TailSampler::new()
+    }
+}
+
+impl TailSampler {
+    /**
+    Create a new tail sampler.
+
+    By default, traces are buffered for 30 seconds, up to 1024 traces and 8192 events are buffered at a time, each trace has up to 256 events, and only traces with errors are kept.
+    */
+    pub fn new() -> Self {
+        TailSampler {
+            window: Duration::from_secs(30),
+            max_traces: 1024,
+            max_events: 8192,
+            max_events_per_trace: 256,
+            keep_errors: true,
+            keep_slower_than: None,
+            keep_ratio: TraceIdRatio::new(0.0),
+        }
+    }
+
+    /**
+    Set the time to buffer a trace for, starting from its first event, before deciding whether to keep it.
+
+    A trace is decided sooner if its local root span completes within this window. A span whose parent is in another service can only be recognized as the local root when span begin events are emitted, which can be enabled through [`crate::Setup::emit_span_begin`]. Without them, traces with a remote parent always wait for the full window.
+
+    The window isn't checked on a timer. Expired traces are only decided when a later event is emitted through the sampler, or when it's flushed. If no more events are emitted, a trace will stay buffered until the runtime is flushed.
+    */
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /**
+    Set the maximum number of traces to buffer at a time.
+
+    If a new trace arrives when the buffer is full, then the oldest trace in it is decided early.
+    */
+    pub fn max_traces(mut self, max_traces: usize) -> Self {
+        self.max_traces = max_traces.max(1);
+        self
+    }
+
+    /**
+    Set the maximum number of events to buffer at a time, across all traces.
+
+    If a new event arrives when the buffer is full, then the oldest traces in it are decided early until there's room for it.
+    */
+    pub fn max_events(mut self, max_events: usize) -> Self {
+        self.max_events = max_events.max(1);
+        self
+    }
+
+    /**
+    Set the maximum number of events to buffer for a single trace.
+
+    Events beyond this limit are discarded, even if the trace is eventually kept.
+    */
+    pub fn max_events_per_trace(mut self, max_events_per_trace: usize) -> Self {
+        self.max_events_per_trace = max_events_per_trace.max(1);
+        self
+    }
+
+    /**
+    Whether to keep traces that contain any events with an [`Level::Error`] level.
+
+    This policy is enabled by default.
+    */
+    pub fn keep_errors(mut self, keep_errors: bool) -> Self {
+        self.keep_errors = keep_errors;
+        self
+    }
+
+    /**
+    Keep traces with a span that took longer than `threshold` to complete.
+
+    Since a root span covers the execution of all of its children, this will typically be the duration of the root span.
+    */
+    pub fn keep_slower_than(mut self, threshold: Duration) -> Self {
+        self.keep_slower_than = Some(threshold);
+        self
+    }
+
+    /**
+    Keep the given `ratio` of traces that aren't kept by any other policy.
+
+    The decision is made on the trace id the same way as [`TraceIdRatio`], so it's consistent with head sampling in other services that use the same ratio.
+    */
+    pub fn keep_ratio(mut self, ratio: f64) -> Self {
+        self.keep_ratio = TraceIdRatio::new(ratio);
+        self
+    }
+
+    /**
+    Wrap an [`Emitter`], buffering events by their trace and only emitting traces that match a policy.
+    */
+    pub fn wrap_emitter<E>(self, emitter: E) -> TailSampledEmitter<E> {
+        TailSampledEmitter {
+            sampler: self,
+            state: Mutex::new(State::default()),
+            metrics: Arc::new(InternalMetrics::default()),
+            emitter,
+        }
+    }
+}
+
+/**
+An [`Emitter`] that buffers events by their trace, only emitting traces that match the policies of a [`TailSampler`].
+
+Use [`TailSampler::wrap_emitter`] to create a tail sampled emitter.
+
+Events without a [`TraceId`] are emitted immediately. Buffered traces are checked whenever an event is emitted, and when the emitter is flushed. [`Emitter::blocking_flush`] will decide all buffered traces before flushing the wrapped emitter, so any remaining traces are emitted when the runtime is shut down.
+*/
+pub struct TailSampledEmitter<E> {
+    sampler: TailSampler,
+    state: Mutex<State>,
+    metrics: Arc<InternalMetrics>,
+    emitter: E,
+}
+
+#[derive(Default)]
+struct State {
+    pending: HashMap<u128, Trace>,
+    pending_order: VecDeque<(Instant, u128)>,
+    pending_events: usize,
+    decided: HashMap<u128, bool>,
+    decided_order: VecDeque<u128>,
+}
+
+struct Trace {
+    events: Vec<OwnedEvent>,
+    began: HashSet<u64>,
+    keep: bool,
+}
+
+impl<E> TailSampledEmitter<E> {
+    /**
+    Get a [`Source`] for metrics about the traces buffered by this emitter.
+    */
+    pub fn metric_source(&self) -> TailSamplerMetrics {
+        TailSamplerMetrics {
+            metrics: self.metrics.clone(),
+        }
+    }
+}
+
+impl<E: Emitter> TailSampledEmitter<E> {
+    fn decide(&self, state: &mut State, trace_id: u128, trace: Trace, emit: &mut Vec<OwnedEvent>) {
+        state.pending_events -= trace.events.len();
+
+        let keep = trace.keep
+            || self
+                .sampler
+                .keep_ratio
+                .should_sample(&TraceId::from_u128(trace_id).expect("trace ids are non-zero"));
+
+        if keep {
+            self.metrics.trace_kept.increment();
+            emit.extend(trace.events);
+        } else {
+            self.metrics.trace_discarded.increment();
+        }
+
+        if state.decided_order.len() >= self.sampler.max_traces {
+            if let Some(oldest) = state.decided_order.pop_front() {
+                state.decided.remove(&oldest);
+            }
+        }
+
+        state.decided.insert(trace_id, keep);
+        state.decided_order.push_back(trace_id);
+    }
+
+    fn decide_expired(&self, state: &mut State, now: Instant, emit: &mut Vec<OwnedEvent>) {
+        while let Some((started, trace_id)) = state.pending_order.front().copied() {
+            if now.saturating_duration_since(started) < self.sampler.window {
+                break;
+            }
+
+            state.pending_order.pop_front();
+            self.decide_pending(state, trace_id, emit);
+        }
+    }
+
+    fn decide_oldest(&self, state: &mut State, emit: &mut Vec<OwnedEvent>) {
+        if let Some((_, trace_id)) = state.pending_order.pop_front() {
+            self.decide_pending(state, trace_id, emit);
+            self.metrics.trace_evicted.increment();
+        }
+    }
+
+    fn decide_pending(&self, state: &mut State, trace_id: u128, emit: &mut Vec<OwnedEvent>) {
+        let trace = state.pending.remove(&trace_id).expect("trace is pending");
+
+        self.decide(state, trace_id, trace, emit);
+    }
+
+    fn decide_root(&self, state: &mut State, trace_id: u128, emit: &mut Vec<OwnedEvent>) {
+        // Every pending trace has exactly one entry in the pending order,
+        // so it needs to be removed along with the trace
+        if let Some(i) = state
+            .pending_order
+            .iter()
+            .position(|(_, pending)| *pending == trace_id)
+        {
+            state.pending_order.remove(i);
+        }
+
+        self.decide_pending(state, trace_id, emit);
+    }
+
+    fn is_kept(&self, evt: &OwnedEvent) -> bool {
+        let props = evt.props();
+
+        if self.sampler.keep_errors && props.pull::<Level, _>(KEY_LVL) == Some(Level::Error) {
+            return true;
+        }
+
+        if let Some(threshold) = self.sampler.keep_slower_than {
+            if props.pull::<Kind, _>(KEY_EVENT_KIND) == Some(Kind::Span) {
+                if let Some(len) = evt.extent().and_then(|extent| extent.len()) {
+                    return len > threshold;
+                }
+            }
+        }
+
+        false
+    }
+
+    fn emit_all(&self, emit: Vec<OwnedEvent>) {
+        for evt in emit {
+            self.emitter.emit(evt);
+        }
+    }
+}
+
+impl<E: Emitter> Emitter for TailSampledEmitter<E> {
+    fn emit<T: ToEvent>(&self, evt: T) {
+        let evt = evt.to_event();
+
+        let Some(trace_id) = evt.props().pull::<TraceId, _>(KEY_TRACE_ID) else {
+            self.emitter.emit(evt);
+            return;
+        };
+        let trace_id = trace_id.to_u128();
+
+        let evt = evt.to_owned();
+        let now = Instant::now();
+
+        let mut emit = Vec::new();
+
+        {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+            self.decide_expired(&mut state, now, &mut emit);
+
+            // Make room for the event by deciding the oldest traces early
+            while state.pending_events >= self.sampler.max_events && !state.pending.is_empty() {
+                self.decide_oldest(&mut state, &mut emit);
+            }
+
+            // Events for traces that have already been decided follow that decision
+            if let Some(keep) = state.decided.get(&trace_id).copied() {
+                drop(state);
+
+                if keep {
+                    emit.push(evt);
+                } else {
+                    self.metrics.event_discarded.increment();
+                }
+
+                self.emit_all(emit);
+                return;
+            }
+
+            if !state.pending.contains_key(&trace_id) {
+                if state.pending.len() >= self.sampler.max_traces {
+                    self.decide_oldest(&mut state, &mut emit);
+                }
+
+                self.metrics.trace_buffered.increment();
+
+                state.pending.insert(
+                    trace_id,
+                    Trace {
+                        events: Vec::new(),
+                        began: HashSet::new(),
+                        keep: false,
+                    },
+                );
+                state.pending_order.push_back((now, trace_id));
+            }
+
+            let keep = self.is_kept(&evt);
+            let kind = evt.props().pull::<Kind, _>(KEY_EVENT_KIND);
+            let span_id = evt
+                .props()
+                .pull::<SpanId, _>(KEY_SPAN_ID)
+                .map(|span_id| span_id.to_u64());
+            let span_parent = evt
+                .props()
+                .pull::<SpanId, _>(KEY_SPAN_PARENT)
+                .map(|span_parent| span_parent.to_u64());
+
+            let trace = state.pending.get_mut(&trace_id).expect("trace is pending");
+
+            trace.keep |= keep;
+
+            // Spans that began locally are tracked even if their events aren't
+            // buffered, so the local root of the trace can still be found
+            if kind == Some(Kind::SpanBegin) {
+                trace.began.extend(span_id);
+            }
+
+            // A span is the local root of the trace if it doesn't have a parent,
+            // or if it began locally, but its parent didn't. That's the case when
+            // its parent is in another service
+            let is_root = kind == Some(Kind::Span)
+                && match (span_id, span_parent) {
+                    (_, None) => true,
+                    (Some(span_id), Some(span_parent)) => {
+                        trace.began.contains(&span_id) && !trace.began.contains(&span_parent)
+                    }
+                    (None, Some(_)) => false,
+                };
+
+            if trace.events.len() < self.sampler.max_events_per_trace {
+                trace.events.push(evt);
+                state.pending_events += 1;
+            } else {
+                self.metrics.event_discarded.increment();
+            }
+
+            // Once the root span completes the trace is finished
+            if is_root {
+                self.decide_root(&mut state, trace_id, &mut emit);
+            }
+        }
+
+        self.emit_all(emit);
+    }
+
+    fn blocking_flush(&self, timeout: Duration) -> bool {
+        let emit = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+            let mut emit = Vec::new();
+
+            state.pending_order.clear();
+            for (trace_id, trace) in mem::take(&mut state.pending) {
+                self.decide(&mut state, trace_id, trace, &mut emit);
+            }
+
+            emit
+        };
+
+        self.emit_all(emit);
+
+        self.emitter.blocking_flush(timeout)
+    }
+}
+
+impl<E: InternalEmitter> InternalEmitter for TailSampledEmitter<E> {}
+
+/**
+Metrics produced by a [`TailSampledEmitter`].
+
+You can enumerate the metrics using the [`Source`] implementation. See [`crate::metric`] for details.
+*/
+#[derive(Clone)]
+pub struct TailSamplerMetrics {
+    metrics: Arc<InternalMetrics>,
+}
+
+#[derive(Default)]
+struct InternalMetrics {
+    trace_buffered: Counter,
+    trace_kept: Counter,
+    trace_discarded: Counter,
+    trace_evicted: Counter,
+    event_discarded: Counter,
+}
+
+#[derive(Default)]
+struct Counter(AtomicUsize);
+
+impl Counter {
+    fn increment(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn sample(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl TailSamplerMetrics {
+    /**
+    A new trace started buffering.
+    */
+    pub fn trace_buffered(&self) -> usize {
+        self.metrics.trace_buffered.sample()
+    }
+
+    /**
+    A buffered trace matched a policy, so it was emitted.
+    */
+    pub fn trace_kept(&self) -> usize {
+        self.metrics.trace_kept.sample()
+    }
+
+    /**
+    A buffered trace didn't match any policy, so it was discarded.
+    */
+    pub fn trace_discarded(&self) -> usize {
+        self.metrics.trace_discarded.sample()
+    }
+
+    /**
+    Too many traces or events were buffered, so a trace was decided before its window elapsed.
+    */
+    pub fn trace_evicted(&self) -> usize {
+        self.metrics.trace_evicted.sample()
+    }
+
+    /**
+    An event was discarded, either because its trace had too many events buffered, or because its trace was already discarded.
+    */
+    pub fn event_discarded(&self) -> usize {
+        self.metrics.event_discarded.sample()
+    }
+}
+
+impl Source for TailSamplerMetrics {
+    fn sample_metrics<S: Sampler>(&self, sampler: S) {
+        for (name, value) in [
+            ("trace_buffered", self.trace_buffered()),
+            ("trace_kept", self.trace_kept()),
+            ("trace_discarded", self.trace_discarded()),
+            ("trace_evicted", self.trace_evicted()),
+            ("event_discarded", self.event_discarded()),
+        ] {
+            sampler.metric(Metric::new(
+                "emit",
+                Empty,
+                name,
+                emit_core::well_known::METRIC_AGG_COUNT,
+                value,
+                Empty,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        span::{SpanCtxt, SpanEvent},
+        testing::CaptureEmitter,
+        Event, Path, Template, Timestamp,
+    };
+
+    fn span(trace_id: u128, span_parent: Option<u64>, span_id: u64, secs: u64) -> OwnedEvent {
+        let start = Timestamp::from_unix(Duration::from_secs(1)).unwrap();
+        let end = Timestamp::from_unix(Duration::from_secs(1 + secs)).unwrap();
+
+        SpanEvent::new(
+            Path::new("test"),
+            start..end,
+            SpanCtxt::new(
+                TraceId::from_u128(trace_id),
+                span_parent.and_then(SpanId::from_u64),
+                SpanId::from_u64(span_id),
+            ),
+            "test",
+            Empty,
+        )
+        .to_event()
+        .to_owned()
+    }
+
+    fn begin(trace_id: u128, span_parent: Option<u64>, span_id: u64) -> OwnedEvent {
+        let start = Timestamp::from_unix(Duration::from_secs(1)).unwrap();
+
+        Event::new(
+            Path::new("test"),
+            start,
+            Template::literal("test"),
+            SpanCtxt::new(
+                TraceId::from_u128(trace_id),
+                span_parent.and_then(SpanId::from_u64),
+                SpanId::from_u64(span_id),
+            )
+            .and_props((KEY_EVENT_KIND, Kind::SpanBegin)),
+        )
+        .to_owned()
+    }
+
+    #[test]
+    fn keeps_slow_traces() {
+        let captured = CaptureEmitter::new();
+
+        let emitter = TailSampler::new()
+            .keep_slower_than(Duration::from_secs(5))
+            .wrap_emitter(captured.clone());
+
+        // A fast trace
+        emitter.emit(span(1, Some(1), 2, 1));
+        emitter.emit(span(1, None, 1, 2));
+
+        // A slow trace
+        emitter.emit(span(2, Some(1), 2, 1));
+        emitter.emit(span(2, None, 1, 10));
+
+        assert_eq!(2, captured.events().len());
+
+        let metrics = emitter.metric_source();
+
+        assert_eq!(2, metrics.trace_buffered());
+        assert_eq!(1, metrics.trace_kept());
+        assert_eq!(1, metrics.trace_discarded());
+    }
+
+    #[test]
+    fn evicts_oldest_trace() {
+        let captured = CaptureEmitter::new();
+
+        let emitter = TailSampler::new()
+            .max_traces(1)
+            .keep_ratio(1.0)
+            .wrap_emitter(captured.clone());
+
+        emitter.emit(span(1, Some(1), 2, 1));
+        emitter.emit(span(2, Some(1), 2, 1));
+
+        assert_eq!(1, captured.events().len());
+        assert_eq!(1, emitter.metric_source().trace_evicted());
+
+        emitter.blocking_flush(Duration::from_secs(1));
+
+        assert_eq!(2, captured.events().len());
+    }
+
+    #[test]
+    fn decides_on_local_root() {
+        let captured = CaptureEmitter::new();
+
+        let emitter = TailSampler::new()
+            .keep_ratio(1.0)
+            .wrap_emitter(captured.clone());
+
+        // A trace with a parent in another service
+        emitter.emit(begin(1, Some(9), 1));
+        emitter.emit(begin(1, Some(1), 2));
+        emitter.emit(span(1, Some(1), 2, 1));
+
+        assert_eq!(0, captured.events().len());
+
+        emitter.emit(span(1, Some(9), 1, 2));
+
+        assert_eq!(4, captured.events().len());
+
+        // Without begin events, the local root can't be recognized
+        emitter.emit(span(2, Some(9), 1, 2));
+
+        assert_eq!(4, captured.events().len());
+        assert_eq!(1, emitter.metric_source().trace_kept());
+    }
+
+    #[test]
+    fn decided_roots_leave_pending() {
+        let captured = CaptureEmitter::new();
+
+        let emitter = TailSampler::new()
+            .max_traces(2)
+            .keep_ratio(1.0)
+            .wrap_emitter(captured.clone());
+
+        for trace_id in 1..=16 {
+            emitter.emit(span(trace_id, Some(1), 2, 1));
+            emitter.emit(span(trace_id, None, 1, 2));
+        }
+
+        let state = emitter.state.lock().unwrap();
+
+        assert!(state.pending.is_empty());
+        assert!(state.pending_order.is_empty());
+        assert_eq!(0, state.pending_events);
+
+        drop(state);
+
+        assert_eq!(32, captured.events().len());
+        assert_eq!(0, emitter.metric_source().trace_evicted());
+    }
+
+    #[test]
+    fn bounds_total_events() {
+        let captured = CaptureEmitter::new();
+
+        let emitter = TailSampler::new()
+            .max_events(2)
+            .keep_ratio(1.0)
+            .wrap_emitter(captured.clone());
+
+        emitter.emit(span(1, Some(1), 2, 1));
+        emitter.emit(span(1, Some(1), 3, 1));
+        emitter.emit(span(2, Some(1), 2, 1));
+
+        assert_eq!(2, captured.events().len());
+        assert_eq!(1, emitter.metric_source().trace_evicted());
+
+        emitter.blocking_flush(Duration::from_secs(1));
+
+        assert_eq!(3, captured.events().len());
+    }
+}
@@ -0,0 +1,548 @@
+/*!
+Infrastructure for asserting on diagnostics in tests.
+
+This module also contains a [`ManualClock`] and [`SeededRng`] for producing deterministic timestamps and ids.
+
+The [`CaptureEmitter`] is an [`Emitter`] that records owned copies of the events it receives so they can be inspected later. Use [`scope`] to run some code against a [`Runtime`] that captures its events without interfering with the [`crate::runtime::shared`] runtime, or other tests running concurrently:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+let captured = emit::testing::scope(|rt| {
+    emit::warn!(rt: rt, "something went {outcome}", outcome: "wrong");
+});
+
+let events = captured.find_by_tpl("something went {outcome}");
+assert_eq!(1, events.len());
+
+emit::testing::assert_prop(&events[0], emit::well_known::KEY_LVL, emit::Level::Warn);
+emit::testing::assert_prop(&events[0], "outcome", "wrong");
+# }
+```
+
+The same emitter can also be installed in a [`crate::runtime::AmbientSlot`] through [`init_slot`], and passed to macros using the `rt` control parameter:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+static RUNTIME: emit::runtime::AmbientSlot = emit::runtime::AmbientSlot::new();
+
+let captured = emit::testing::init_slot(&RUNTIME);
+
+emit::emit!(rt: RUNTIME.get(), "Hello, {user}", user: "Rust");
+
+assert_eq!("Hello, Rust", captured.events()[0].msg().to_string());
+# }
+```
+
+Spans can be grouped into their traces with [`CaptureEmitter::spans_by_trace_id`]:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::testing::CaptureRuntime;
+
+#[emit::span(rt: rt, "inner")]
+fn inner(rt: &CaptureRuntime) {
+    // Events that aren't spans aren't included
+    emit::info!(rt: rt, "working");
+}
+
+#[emit::span(rt: rt, "outer")]
+fn outer(rt: &CaptureRuntime) {
+    inner(rt);
+}
+
+let captured = emit::testing::scope(|rt| {
+    outer(rt);
+    outer(rt);
+});
+
+let traces = captured.spans_by_trace_id();
+assert_eq!(2, traces.len());
+
+for (_, spans) in traces {
+    assert_eq!(2, spans.len());
+
+    // Spans are emitted when they complete, so children appear before their parents
+    assert_eq!("inner", spans[0].msg().to_string());
+    assert_eq!("outer", spans[1].msg().to_string());
+}
+# }
+```
+*/
+
+use core::{fmt, time::Duration};
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+use emit_core::{
+    clock::Clock,
+    emitter::Emitter,
+    empty::Empty,
+    event::{OwnedEvent, ToEvent},
+    props::Props,
+    rng::Rng,
+    runtime::{AmbientSlot, Runtime},
+    str::ToStr,
+    timestamp::Timestamp,
+    value::ToValue,
+    well_known::{KEY_EVENT_KIND, KEY_TRACE_ID},
+};
+
+use crate::{
+    platform::{rand_rng::RandRng, system_clock::SystemClock, thread_local_ctxt::ThreadLocalCtxt},
+    span::TraceId,
+    Kind, Setup,
+};
+
+/**
+A [`Runtime`] that captures its events in a [`CaptureEmitter`].
+
+Runtimes of this type are created by [`runtime`] and [`scope`]. The [`Clock`] and [`Rng`] can be swapped out through [`Runtime::with_clock`] and [`Runtime::with_rng`].
+*/
+pub type CaptureRuntime<TClock = SystemClock, TRng = RandRng> =
+    Runtime<CaptureEmitter, Empty, ThreadLocalCtxt, TClock, TRng>;
+
+/**
+Create a new [`CaptureRuntime`].
+
+The runtime uses fully isolated ambient context, so it won't see properties pushed by other runtimes.
+*/
+pub fn runtime() -> CaptureRuntime {
+    Runtime::build(
+        CaptureEmitter::new(),
+        Empty,
+        ThreadLocalCtxt::new(),
+        SystemClock::new(),
+        RandRng::new(),
+    )
+}
+
+/**
+Run `scope` against a new [`CaptureRuntime`], returning all events emitted through it.
+
+Pass the runtime to macros using the `rt` control parameter.
+*/
+pub fn scope(scope: impl FnOnce(&CaptureRuntime)) -> CaptureEmitter {
+    let rt = runtime();
+
+    scope(&rt);
+
+    rt.emitter().clone()
+}
+
+/**
+Initialize `slot` with a [`CaptureEmitter`], returning a handle to the events emitted through it.
+
+The remaining components of the runtime use the same defaults as [`crate::setup()`].
+
+This function will panic if `slot` has already been initialized.
+*/
+pub fn init_slot(slot: &'static AmbientSlot) -> CaptureEmitter {
+    let emitter = CaptureEmitter::new();
+
+    let _ = Setup::new().emit_to(emitter.clone()).init_slot(slot);
+
+    emitter
+}
+
+/**
+An [`Emitter`] that records owned copies of all events it receives.
+
+Clones of a capture emitter share the same set of recorded events.
+*/
+#[derive(Clone, Default)]
+pub struct CaptureEmitter {
+    events: Arc<Mutex<Vec<OwnedEvent>>>,
+}
+
+impl CaptureEmitter {
+    /**
+    Create a new emitter without any recorded events.
+    */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+    Get a copy of all events recorded so far, in the order they were emitted.
+    */
+    pub fn events(&self) -> Vec<OwnedEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /**
+    Take all events recorded so far, in the order they were emitted, leaving the emitter empty.
+    */
+    pub fn take(&self) -> Vec<OwnedEvent> {
+        core::mem::take(&mut *self.events.lock().unwrap())
+    }
+
+    /**
+    Discard all events recorded so far.
+    */
+    pub fn clear(&self) {
+        self.events.lock().unwrap().clear();
+    }
+
+    /**
+    Get the number of events recorded so far.
+    */
+    pub fn len(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    /**
+    Whether any events have been recorded.
+    */
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /**
+    Find all events whose template matches `tpl`.
+
+    Templates are compared using their braced form, like `Hello, {user}`.
+    */
+    pub fn find_by_tpl(&self, tpl: &str) -> Vec<OwnedEvent> {
+        self.filter(|evt| evt.tpl().render(Empty).braced().to_string() == tpl)
+    }
+
+    /**
+    Find all events whose rendered message matches `msg`.
+    */
+    pub fn find_by_msg(&self, msg: &str) -> Vec<OwnedEvent> {
+        self.filter(|evt| evt.msg().to_string() == msg)
+    }
+
+    /**
+    Find all events matching the predicate `f`.
+    */
+    pub fn filter(&self, mut f: impl FnMut(&OwnedEvent) -> bool) -> Vec<OwnedEvent> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|evt| f(evt))
+            .cloned()
+            .collect()
+    }
+
+    /**
+    Group all spans by their [`KEY_TRACE_ID`].
+
+    Only events with a [`Kind::Span`] are included, so other events emitted within a span, like logs or metrics, are ignored. Groups are returned in the order their trace ids were first seen. Events within a group are in the order they were emitted. Since spans are emitted when they complete, children will appear before their parents.
+    */
+    pub fn spans_by_trace_id(&self) -> Vec<(TraceId, Vec<OwnedEvent>)> {
+        let mut traces = Vec::<(TraceId, Vec<OwnedEvent>)>::new();
+
+        for evt in self.events.lock().unwrap().iter() {
+            if evt.props().pull::<Kind, _>(KEY_EVENT_KIND) != Some(Kind::Span) {
+                continue;
+            }
+
+            let Some(trace_id) = evt.props().pull::<TraceId, _>(KEY_TRACE_ID) else {
+                continue;
+            };
+
+            match traces.iter_mut().find(|(id, _)| *id == trace_id) {
+                Some((_, trace)) => trace.push(evt.clone()),
+                None => traces.push((trace_id, vec![evt.clone()])),
+            }
+        }
+
+        traces
+    }
+}
+
+impl Emitter for CaptureEmitter {
+    fn emit<E: ToEvent>(&self, evt: E) {
+        let evt = evt.to_event().to_owned();
+
+        self.events.lock().unwrap().push(evt);
+    }
+
+    fn blocking_flush(&self, _: Duration) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for CaptureEmitter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.events.lock().unwrap().iter())
+            .finish()
+    }
+}
+
+/**
+A [`Clock`] that only moves when it's told to.
+
+The time on a manual clock is frozen until it's changed through [`ManualClock::set`] or [`ManualClock::advance`]. Clones of a manual clock share the same time, so it can be plugged into a [`Runtime`] through [`Runtime::with_clock`] and still be controlled by the test:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use std::time::Duration;
+
+let clock = emit::testing::ManualClock::new(emit::Timestamp::from_unix(Duration::from_secs(1)).unwrap());
+
+let timer = emit::Timer::start(&clock);
+
+clock.advance(Duration::from_millis(500));
+
+assert_eq!(Some(Duration::from_millis(500)), timer.elapsed());
+# }
+```
+
+A manual clock also supports [`Clock::monotonic`] readings. These are only moved by [`ManualClock::advance`], so [`ManualClock::set`] can be used to simulate adjustments to the wall-clock time, like an NTP sync:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use std::time::Duration;
+
+let clock = emit::testing::ManualClock::new(emit::Timestamp::from_unix(Duration::from_secs(10)).unwrap());
+
+let timer = emit::Timer::start(&clock);
+
+clock.advance(Duration::from_millis(500));
+clock.set(emit::Timestamp::from_unix(Duration::from_secs(1)).unwrap());
+
+assert_eq!(Some(Duration::from_millis(500)), timer.elapsed());
+assert_eq!(Some(Duration::from_millis(500)), timer.extent().and_then(|extent| extent.len()));
+# }
+```
+*/
+#[derive(Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<ManualClockState>>,
+}
+
+struct ManualClockState {
+    now: Timestamp,
+    monotonic: Duration,
+}
+
+impl ManualClock {
+    /**
+    Create a new clock with its time frozen at `now`.
+    */
+    pub fn new(now: Timestamp) -> Self {
+        ManualClock {
+            now: Arc::new(Mutex::new(ManualClockState {
+                now,
+                monotonic: Duration::ZERO,
+            })),
+        }
+    }
+
+    /**
+    Set the time on the clock to `now`.
+
+    The time may be moved backwards. Setting the time doesn't change [`Clock::monotonic`] readings.
+    */
+    pub fn set(&self, now: Timestamp) {
+        self.now.lock().unwrap().now = now;
+    }
+
+    /**
+    Move the time on the clock forwards by `by`, returning the new time.
+
+    [`Clock::monotonic`] readings are also moved forwards by `by`.
+
+    This method will panic if the new time would be after [`Timestamp::MAX`].
+    */
+    pub fn advance(&self, by: Duration) -> Timestamp {
+        let mut state = self.now.lock().unwrap();
+        state.now += by;
+        state.monotonic += by;
+
+        state.now
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Option<Timestamp> {
+        Some(self.now.lock().unwrap().now)
+    }
+
+    fn monotonic(&self) -> Option<Duration> {
+        Some(self.now.lock().unwrap().monotonic)
+    }
+}
+
+impl fmt::Debug for ManualClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ManualClock")
+            .field(&self.now.lock().unwrap().now)
+            .finish()
+    }
+}
+
+/**
+An [`Rng`] that produces a deterministic sequence of values from a seed.
+
+Values are generated using the [SplitMix64](https://prng.di.unimi.it/splitmix64.c) algorithm. It's not suitable for cryptographic purposes, but two generators with the same seed will produce the same sequence of values, so trace and span ids will be stable across runs:
+
+```
+# #[cfg(not(feature = "std"))] fn main() {}
+# #[cfg(feature = "std")] fn main() {
+use emit::{
+    platform::system_clock::SystemClock,
+    testing::{CaptureRuntime, SeededRng},
+    Props,
+};
+
+#[emit::span(rt: rt, "outer")]
+fn outer(rt: &CaptureRuntime<SystemClock, SeededRng>) {}
+
+let trace_id = |seed| {
+    let rt = emit::testing::runtime().with_rng(SeededRng::new(seed));
+
+    outer(&rt);
+
+    rt.emitter().take()[0]
+        .props()
+        .pull::<emit::span::TraceId, _>(emit::well_known::KEY_TRACE_ID)
+};
+
+assert_eq!(trace_id(42), trace_id(42));
+# }
+```
+
+Seeded generators can also be installed in the shared runtime through [`Setup::with_rng`].
+
+A seeded generator can be shared across threads. The sequence of values it produces is deterministic, but the order they're handed out to different threads depends on the order those threads read them in.
+*/
+#[derive(Debug)]
+pub struct SeededRng {
+    state: AtomicU64,
+}
+
+impl SeededRng {
+    /**
+    Create a new generator from the given `seed`.
+    */
+    pub const fn new(seed: u64) -> Self {
+        SeededRng {
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    fn next(&self) -> u64 {
+        const GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+        let mut z = self
+            .state
+            .fetch_add(GAMMA, Ordering::Relaxed)
+            .wrapping_add(GAMMA);
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Rng for SeededRng {
+    fn fill<A: AsMut<[u8]>>(&self, mut arr: A) -> Option<A> {
+        for chunk in arr.as_mut().chunks_mut(8) {
+            let bytes = self.next().to_le_bytes();
+
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+
+        Some(arr)
+    }
+
+    fn gen_u64(&self) -> Option<u64> {
+        Some(self.next())
+    }
+
+    fn gen_u128(&self) -> Option<u128> {
+        let hi = self.next() as u128;
+        let lo = self.next() as u128;
+
+        Some((hi << 64) | lo)
+    }
+}
+
+/**
+Assert that the event `evt` carries a property `key` that's equal to `expected`.
+
+Values are compared using their [`fmt::Display`] implementations, so `emit::Level::Warn` is equal to `"warn"`.
+
+This function will panic if the property is missing or its value doesn't match.
+*/
+#[track_caller]
+pub fn assert_prop(evt: impl ToEvent, key: impl ToStr, expected: impl ToValue) {
+    let evt = evt.to_event();
+    let key = key.to_str();
+    let expected = expected.to_value().to_string();
+
+    match evt.props().get(key.by_ref()) {
+        Some(actual) => {
+            let actual = actual.to_string();
+
+            assert!(
+                actual == expected,
+                "expected `{}` to be `{}`, but it was `{}`\nevent: {:?}",
+                key.get(),
+                expected,
+                actual,
+                evt,
+            );
+        }
+        None => panic!(
+            "expected `{}` to be `{}`, but it was missing\nevent: {:?}",
+            key.get(),
+            expected,
+            evt,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        span::{SpanCtxt, SpanEvent, SpanId},
+        Event, Path, Template,
+    };
+
+    #[test]
+    fn spans_by_trace_id_ignores_other_events() {
+        let emitter = CaptureEmitter::new();
+
+        let ctxt = SpanCtxt::new(TraceId::from_u128(1), None, SpanId::from_u64(1));
+
+        emitter.emit(Event::new(
+            Path::new("test"),
+            Timestamp::from_unix(Duration::from_secs(1)).unwrap(),
+            Template::literal("working"),
+            ctxt,
+        ));
+        emitter.emit(SpanEvent::new(
+            Path::new("test"),
+            Timestamp::from_unix(Duration::from_secs(1)).unwrap()
+                ..Timestamp::from_unix(Duration::from_secs(2)).unwrap(),
+            ctxt,
+            "span",
+            Empty,
+        ));
+
+        let traces = emitter.spans_by_trace_id();
+
+        assert_eq!(1, traces.len());
+        assert_eq!(TraceId::from_u128(1).unwrap(), traces[0].0);
+        assert_eq!(1, traces[0].1.len());
+        assert_eq!(
+            Some(Kind::Span),
+            traces[0].1[0].props().pull::<Kind, _>(KEY_EVENT_KIND)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 /*!
 The [`Timer`] type.
 
-Timers are a simple mechanism to track the start and end times of some operation. They're based on readings from a [`Clock`], which isn't monotonic. That means timers can give an approximate timespan based on its readings, but are susceptible to clock drift.
+Timers are a simple mechanism to track the start and end times of some operation. They're based on readings from a [`Clock`]. The start of a timer is always a wall-clock [`Timestamp`] from [`Clock::now`]. If the clock also supports [`Clock::monotonic`] readings then the timespan is measured from those, so it isn't affected if the wall-clock time is adjusted while the timer is running. If the clock doesn't support monotonic readings then timers give an approximate timespan based on wall-clock readings, which are susceptible to clock drift.
 
 Timers are used by [`crate::Span`]s to produce the [`Extent`] on their events.
 */
@@ -16,16 +16,20 @@ A timer that measures the point when an operation started and ended.
 #[derive(Clone, Copy)]
 pub struct Timer<C> {
     start: Option<Timestamp>,
+    start_monotonic: Option<Duration>,
     clock: C,
 }
 
 impl<C: Clock> Timer<C> {
     /**
     Start a timer using [`Clock::now`] as its initial reading.
+
+    If the clock supports them, a reading from [`Clock::monotonic`] is also taken to measure the timespan from.
     */
     pub fn start(clock: C) -> Self {
         Timer {
             start: clock.now(),
+            start_monotonic: clock.monotonic(),
             clock,
         }
     }
@@ -42,24 +46,35 @@ impl<C: Clock> Timer<C> {
     /**
     Get the value of the timer as a span [`Extent`], using [`Clock::now`] as its final reading.
 
+    If the underlying [`Clock`] supports monotonic readings then the end of the extent is the start plus the monotonic [`Timer::elapsed`] time, rather than the final reading from [`Clock::now`].
+
     If the underlying [`Clock`] is unable to produce a reading then this method will return `None`.
     */
     pub fn extent(&self) -> Option<Extent> {
-        let end = self.clock.now();
+        let start = self.start?;
 
-        match (self.start, end) {
-            (Some(start), Some(end)) => Some(Extent::span(start..end)),
-            _ => None,
-        }
+        let end = match self.elapsed_monotonic() {
+            Some(elapsed) => Timestamp::from_unix(start.to_unix() + elapsed)?,
+            None => self.clock.now()?,
+        };
+
+        Some(Extent::span(start..end))
     }
 
     /**
     Get the timespan between the initial reading and [`Clock::now`].
 
+    If the underlying [`Clock`] supports monotonic readings then the timespan is measured between the initial and current [`Clock::monotonic`] readings instead.
+
     If the underlying [`Clock`] is unable to produce a reading, or it shifts to before the initial reading, then this method will return `None`.
     */
     pub fn elapsed(&self) -> Option<Duration> {
-        self.extent().and_then(|extent| extent.len())
+        self.elapsed_monotonic()
+            .or_else(|| self.extent().and_then(|extent| extent.len()))
+    }
+
+    fn elapsed_monotonic(&self) -> Option<Duration> {
+        self.clock.monotonic()?.checked_sub(self.start_monotonic?)
     }
 
     /**
@@ -68,6 +83,7 @@ impl<C: Clock> Timer<C> {
     pub fn by_ref(&self) -> Timer<&C> {
         Timer {
             start: self.start,
+            start_monotonic: self.start_monotonic,
             clock: &self.clock,
         }
     }